@@ -0,0 +1,223 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    components::toast::use_toast,
+    utils::{fetch_api, fetch_api_with_method, format_timestamp, i18n::t, ApiResponse},
+};
+
+const ACTIVE_QUERIES_REFRESH_MS: u64 = 5_000;
+/// Failed polls double the wait up to 2^4 refresh intervals
+const MAX_BACKOFF_EXPONENT: u32 = 4;
+const DEFAULT_LONG_RUNNING_S: u64 = 30;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ActiveQuery {
+    pub query_id: String,
+    pub sql: String,
+    /// Unix timestamp in seconds
+    pub started_at: u64,
+    #[serde(default)]
+    pub user: Option<String>,
+}
+
+impl ActiveQuery {
+    fn running_for_s(&self) -> u64 {
+        ((js_sys::Date::now() / 1000.0) as u64).saturating_sub(self.started_at)
+    }
+}
+
+/// Queries currently running on the connected server, refreshed every 5 seconds and less
+/// often while the server keeps failing
+#[component]
+pub fn ActiveQueries(connected_address: ReadSignal<Option<String>>) -> impl IntoView {
+    let toast = use_toast();
+    let (queries, set_queries) = signal(None::<Vec<ActiveQuery>>);
+    let (long_running_s, set_long_running_s) = signal(DEFAULT_LONG_RUNNING_S);
+    // consecutive failed polls and refresh ticks left to skip before the next one
+    let failed_polls = StoredValue::new(0u32);
+    let skipped_ticks = StoredValue::new(0u32);
+
+    let fetch_queries = {
+        let toast = toast.clone();
+        Action::new(move |_: &()| {
+            let address = connected_address.get_untracked();
+            let toast = toast.clone();
+
+            async move {
+                let Some(address) = address else {
+                    return;
+                };
+                match fetch_api::<Vec<ActiveQuery>>(&format!("{address}/active_queries")).await {
+                    Ok(response) => {
+                        failed_polls.set_value(0);
+                        set_queries.set(Some(response));
+                    }
+                    Err(e) => {
+                        // one toast per failure streak
+                        if failed_polls.get_value() == 0 {
+                            toast.show_error(format!("Failed to fetch active queries: {e}"));
+                        }
+                        failed_polls.update_value(|failed| *failed += 1);
+                        let exponent = failed_polls.get_value().min(MAX_BACKOFF_EXPONENT);
+                        skipped_ticks.set_value((1 << exponent) - 1);
+                    }
+                }
+            }
+        })
+    };
+
+    let cancel_query = Action::new(move |query_id: &String| {
+        let address = connected_address.get_untracked();
+        let query_id = query_id.clone();
+        let toast = toast.clone();
+
+        async move {
+            let Some(address) = address else {
+                return;
+            };
+            match fetch_api_with_method::<ApiResponse>(
+                gloo_net::http::Method::DELETE,
+                &format!(
+                    "{address}/active_queries/{}",
+                    urlencoding::encode(&query_id)
+                ),
+            )
+            .await
+            {
+                Ok(response) => {
+                    toast.show_success(response.message);
+                    fetch_queries.dispatch(());
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to cancel query {query_id}: {e}"));
+                }
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        let connected = connected_address.with(Option::is_some);
+        failed_polls.set_value(0);
+        skipped_ticks.set_value(0);
+        set_queries.set(None);
+        if connected {
+            fetch_queries.dispatch(());
+        }
+    });
+
+    let timer = set_interval_with_handle(
+        move || {
+            if connected_address.with_untracked(Option::is_none)
+                || fetch_queries.pending().get_untracked()
+            {
+                return;
+            }
+            if skipped_ticks.get_value() > 0 {
+                skipped_ticks.update_value(|ticks| *ticks -= 1);
+                return;
+            }
+            fetch_queries.dispatch(());
+        },
+        std::time::Duration::from_millis(ACTIVE_QUERIES_REFRESH_MS),
+    )
+    .ok();
+    on_cleanup(move || {
+        if let Some(handle) = timer {
+            handle.clear();
+        }
+    });
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Active queries")}</h2>
+                <label class="flex items-center gap-1 text-xs text-gray-500 dark:text-gray-400">
+                    "Highlight after"
+                    <input
+                        type="number"
+                        min="1"
+                        class="w-16 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900 focus:outline-none focus:border-gray-400"
+                        prop:value=move || long_running_s.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(seconds) = event_target_value(&ev).parse() {
+                                set_long_running_s.set(seconds);
+                            }
+                        }
+                    />
+                    "s"
+                </label>
+            </div>
+            {move || match queries.get() {
+                Some(queries) if queries.is_empty() => {
+                    view! { <div class="text-gray-400 text-xs italic">"No queries running"</div> }
+                        .into_any()
+                }
+                Some(queries) => {
+                    view! {
+                        <table class="w-full text-xs table-fixed">
+                            <thead>
+                                <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                    <th class="py-1 font-normal w-24">"Query ID"</th>
+                                    <th class="py-1 font-normal">"SQL"</th>
+                                    <th class="py-1 font-normal w-20">"User"</th>
+                                    <th class="py-1 font-normal w-20">"Started"</th>
+                                    <th class="py-1 font-normal w-20">"Running"</th>
+                                    <th class="w-16"></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {queries
+                                    .into_iter()
+                                    .map(|query| {
+                                        let running_for_s = query.running_for_s();
+                                        let query_id = query.query_id.clone();
+                                        view! {
+                                            <tr
+                                                class="border-b border-gray-50 dark:border-gray-800"
+                                                class:bg-red-50=move || { running_for_s > long_running_s.get() }
+                                                class:text-red-700=move || { running_for_s > long_running_s.get() }
+                                            >
+                                                <td class="py-1 font-mono truncate" title=query.query_id.clone()>
+                                                    {query.query_id.clone()}
+                                                </td>
+                                                <td class="py-1 font-mono truncate" title=query.sql.clone()>
+                                                    {query.sql.clone()}
+                                                </td>
+                                                <td class="py-1 truncate">
+                                                    {query.user.clone().unwrap_or_else(|| "-".to_string())}
+                                                </td>
+                                                <td class="py-1 font-mono">{format_timestamp(query.started_at)}</td>
+                                                <td class="py-1 font-mono">{format!("{running_for_s}s")}</td>
+                                                <td class="py-1 text-right">
+                                                    <button
+                                                        class="px-2 py-0.5 border border-red-200 rounded text-red-600 hover:bg-red-50 disabled:opacity-50"
+                                                        disabled=move || cancel_query.pending().get()
+                                                        on:click=move |_| {
+                                                            cancel_query.dispatch(query_id.clone());
+                                                        }
+                                                    >
+                                                        {move || t("Cancel")}
+                                                    </button>
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </tbody>
+                        </table>
+                    }
+                        .into_any()
+                }
+                None if connected_address.with(Option::is_none) => {
+                    view! { <div class="text-gray-400 text-xs italic">"Connect to see active queries"</div> }
+                        .into_any()
+                }
+                None => {
+                    view! { <div class="text-gray-400 text-xs italic">"Loading active queries…"</div> }
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,87 @@
+use leptos::prelude::*;
+
+use crate::components::spinner::Spinner;
+use std::time::Duration;
+
+const INTERVAL_OPTIONS_MS: [u64; 5] = [2_000, 5_000, 10_000, 30_000, 60_000];
+
+/// Toggle and interval picker that calls `on_tick` periodically.
+/// A tick is skipped, not queued, while the previous fetch is still `pending`.
+#[component]
+pub fn AutoRefreshControl(
+    interval_ms: RwSignal<u64>,
+    on_tick: Callback<()>,
+    #[prop(into)] pending: Signal<bool>,
+    /// Interval actually used by the timer, e.g. adapted to how fast metrics change.
+    /// Defaults to `interval_ms`.
+    #[prop(optional, into)]
+    effective_interval_ms: Option<Signal<u64>>,
+) -> impl IntoView {
+    let effective_interval_ms = effective_interval_ms.unwrap_or_else(|| interval_ms.into());
+    let (enabled, set_enabled) = signal(false);
+
+    let timer = StoredValue::new(None::<IntervalHandle>);
+    let clear_timer = move || {
+        if let Some(handle) = timer.get_value() {
+            handle.clear();
+            timer.set_value(None);
+        }
+    };
+
+    Effect::new(move || {
+        clear_timer();
+        if !enabled.get() {
+            return;
+        }
+        let handle = set_interval_with_handle(
+            move || {
+                if !pending.get_untracked() {
+                    on_tick.run(());
+                }
+            },
+            Duration::from_millis(effective_interval_ms.get()),
+        );
+        timer.set_value(handle.ok());
+    });
+    on_cleanup(clear_timer);
+
+    view! {
+        <div class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300">
+            <label class="flex items-center gap-1">
+                <input
+                    type="checkbox"
+                    prop:checked=enabled
+                    on:change=move |ev| set_enabled.set(event_target_checked(&ev))
+                />
+                "Auto-refresh"
+            </label>
+            <select
+                class="px-1 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 focus:outline-none"
+                prop:value=move || interval_ms.get().to_string()
+                on:change=move |ev| {
+                    if let Ok(ms) = event_target_value(&ev).parse() {
+                        interval_ms.set(ms);
+                    }
+                }
+            >
+                {INTERVAL_OPTIONS_MS
+                    .iter()
+                    .map(|ms| {
+                        view! { <option value=ms.to_string()>{format!("{}s", ms / 1000)}</option> }
+                    })
+                    .collect_view()}
+            </select>
+            <Show when=move || { enabled.get() && effective_interval_ms.get() != interval_ms.get() }>
+                <span
+                    class="font-mono text-gray-400"
+                    title="Polling faster while metrics are changing"
+                >
+                    {move || format!("{:.1}s", effective_interval_ms.get() as f64 / 1000.0)}
+                </span>
+            </Show>
+            <Show when=move || pending.get()>
+                <Spinner />
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,165 @@
+use leptos::prelude::*;
+
+use crate::utils::{format_bytes, format_number};
+
+const BATCH_SIZES: [u64; 6] = [1024, 2048, 4096, 8192, 16384, 32768];
+
+/// Approximate fixed cost of one Arrow batch (schema, buffer headers, alignment padding)
+const PER_BATCH_OVERHEAD_BYTES: u64 = 512;
+
+#[derive(Clone, Copy)]
+struct BatchEffect {
+    batch_size: u64,
+    batches: u64,
+    /// Wasted bytes as a fraction of the table size
+    fragmentation: f64,
+    /// Bytes read per byte requested for a single-row lookup
+    io_amplification: f64,
+}
+
+fn batch_effect(rows: u64, bytes_per_row: u64, batch_size: u64) -> BatchEffect {
+    let batches = rows.div_ceil(batch_size);
+    // in f64, user-entered row counts and widths can overflow u64 when multiplied
+    let data_bytes = (rows as f64 * bytes_per_row as f64).max(1.0);
+    // the last batch is allocated for a full batch_size but only partially filled
+    let padding_rows = batches.saturating_mul(batch_size).saturating_sub(rows);
+    let padding_bytes = padding_rows as f64 * bytes_per_row as f64;
+    let overhead_bytes = padding_bytes + batches as f64 * PER_BATCH_OVERHEAD_BYTES as f64;
+    BatchEffect {
+        batch_size,
+        batches,
+        fragmentation: overhead_bytes / data_bytes,
+        io_amplification: batch_size.min(rows.max(1)) as f64,
+    }
+}
+
+#[component]
+fn EffectBars(
+    title: &'static str,
+    effects: Vec<BatchEffect>,
+    current_batch_size: Option<u64>,
+    value: fn(&BatchEffect) -> f64,
+    label: fn(&BatchEffect) -> String,
+) -> impl IntoView {
+    let max = effects
+        .iter()
+        .map(value)
+        .fold(0.0, f64::max)
+        .max(f64::MIN_POSITIVE);
+
+    view! {
+        <div>
+            <div class="text-gray-500 dark:text-gray-400 mb-1">{title}</div>
+            <div class="space-y-0.5">
+                {effects
+                    .iter()
+                    .map(|effect| {
+                        let is_current = current_batch_size == Some(effect.batch_size);
+                        let width = value(effect) / max * 100.0;
+                        view! {
+                            <div class="flex items-center gap-2">
+                                <span class=if is_current {
+                                    "w-12 text-right font-mono font-medium text-blue-700"
+                                } else {
+                                    "w-12 text-right font-mono text-gray-600 dark:text-gray-300"
+                                }>{effect.batch_size}</span>
+                                <div class="flex-1 h-2 bg-gray-50 dark:bg-gray-800 rounded overflow-hidden">
+                                    <div
+                                        class=if is_current { "h-full bg-blue-500" } else { "h-full bg-gray-300" }
+                                        style=format!("width: {width:.2}%")
+                                    ></div>
+                                </div>
+                                <span class="w-16 font-mono text-gray-700 dark:text-gray-200">{label(effect)}</span>
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+}
+
+/// How the cache `batch_size` affects a hypothetical table
+#[component]
+pub fn BatchSizeVis(#[prop(into)] current_batch_size: Signal<Option<u64>>) -> impl IntoView {
+    let (rows, set_rows) = signal(10_000_000u64);
+    let (bytes_per_row, set_bytes_per_row) = signal(100u64);
+
+    let effects = move || {
+        let rows = rows.get().max(1);
+        let bytes_per_row = bytes_per_row.get().max(1);
+        BATCH_SIZES
+            .iter()
+            .map(|batch_size| batch_effect(rows, bytes_per_row, *batch_size))
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="text-xs space-y-3">
+            <div class="flex items-center gap-2">
+                <label class="flex items-center gap-1 text-gray-500 dark:text-gray-400">
+                    "Rows"
+                    <input
+                        type="number"
+                        min="1"
+                        class="w-28 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                        prop:value=move || rows.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(value) = event_target_value(&ev).parse() {
+                                set_rows.set(value);
+                            }
+                        }
+                    />
+                </label>
+                <label class="flex items-center gap-1 text-gray-500 dark:text-gray-400">
+                    "Bytes/row"
+                    <input
+                        type="number"
+                        min="1"
+                        class="w-20 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                        prop:value=move || bytes_per_row.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(value) = event_target_value(&ev).parse() {
+                                set_bytes_per_row.set(value);
+                            }
+                        }
+                    />
+                </label>
+                <span class="text-gray-400">
+                    {move || {
+                        let rows = rows.get().max(1);
+                        let bytes_per_row = bytes_per_row.get().max(1);
+                        format_bytes(rows.saturating_mul(bytes_per_row))
+                    }}
+                </span>
+            </div>
+            {move || {
+                let effects = effects();
+                let current_batch_size = current_batch_size.get();
+                view! {
+                    <EffectBars
+                        title="Arrow batches"
+                        effects=effects.clone()
+                        current_batch_size=current_batch_size
+                        value=|e| e.batches as f64
+                        label=|e| format_number(&e.batches.to_string())
+                    />
+                    <EffectBars
+                        title="Memory fragmentation overhead"
+                        effects=effects.clone()
+                        current_batch_size=current_batch_size
+                        value=|e| e.fragmentation
+                        label=|e| format!("{:.3}%", e.fragmentation * 100.0)
+                    />
+                    <EffectBars
+                        title="Single-row lookup I/O amplification"
+                        effects=effects
+                        current_batch_size=current_batch_size
+                        value=|e| e.io_amplification
+                        label=|e| format!("{:.0}×", e.io_amplification)
+                    />
+                }
+            }}
+        </div>
+    }
+}
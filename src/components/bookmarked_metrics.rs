@@ -0,0 +1,80 @@
+use leptos::prelude::*;
+
+use crate::components::settings::use_settings;
+use crate::models::execution_plan::ExecutionPlanWithStats;
+use crate::utils::format_metric_value;
+
+/// Values of all bookmarked node metrics in the current plan
+#[component]
+pub fn BookmarkedMetrics(plan: ExecutionPlanWithStats) -> impl IntoView {
+    let settings = use_settings();
+
+    let rows = move || {
+        let bookmarks = settings.bookmarks.get();
+        plan.nodes_with_paths()
+            .into_iter()
+            .flat_map(|(path, node)| {
+                node.metrics
+                    .iter()
+                    .filter(|metric| {
+                        bookmarks
+                            .iter()
+                            .any(|bookmark| bookmark.matches(&node.name, &metric.name))
+                    })
+                    .map(|metric| {
+                        (
+                            path.clone(),
+                            node.name.clone(),
+                            metric.name.clone(),
+                            format_metric_value(&metric.name, &metric.value),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <Show when=move || settings.bookmarks.with(|bookmarks| !bookmarks.is_empty())>
+            <div class="mb-4 border border-yellow-100 bg-yellow-50 rounded p-3 text-xs">
+                <div class="font-medium text-gray-700 dark:text-gray-200 mb-2">"★ Bookmarked metrics"</div>
+                {
+                    let rows = rows.clone();
+                    move || {
+                        let rows = rows();
+                        if rows.is_empty() {
+                            view! {
+                                <div class="text-gray-400 italic">
+                                    "No bookmarked metrics in this plan"
+                                </div>
+                            }
+                                .into_any()
+                        } else {
+                            view! {
+                                <div class="grid grid-cols-4 gap-2">
+                                    {rows
+                                        .into_iter()
+                                        .map(|(path, node_name, metric_name, value)| {
+                                            view! {
+                                                <div
+                                                    class="bg-white dark:bg-gray-900 rounded p-2 border border-yellow-100"
+                                                    title=format!("Node {path}")
+                                                >
+                                                    <div class="text-gray-500 dark:text-gray-400 truncate">
+                                                        {format!("{node_name}.{metric_name}")}
+                                                    </div>
+                                                    <div class="font-mono text-gray-800 dark:text-gray-100">{value}</div>
+                                                </div>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </div>
+                            }
+                                .into_any()
+                        }
+                    }
+                }
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,366 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::{
+    components::{row_group_inspector::RowGroupInspector, toast::use_toast},
+    utils::{fetch_api, format_bytes, format_timestamp, i18n::t},
+};
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CacheFileEntry {
+    pub path: String,
+    pub size_bytes: u64,
+    /// Number of reads served from this file since server start
+    pub access_count: u64,
+    /// Unix timestamp of the last read
+    pub last_accessed_at: u64,
+    /// Hex SHA-256 or MD5 digest recorded when the file was cached
+    #[serde(default)]
+    pub file_hash: Option<String>,
+}
+
+/// Hash recomputed by the server from the file on disk
+#[derive(Deserialize, Clone, Debug)]
+struct VerifyResponse {
+    file_hash: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Column {
+    Path,
+    Size,
+    AccessCount,
+    LastAccessed,
+}
+
+impl Column {
+    fn label(&self) -> &'static str {
+        match self {
+            Column::Path => "Path",
+            Column::Size => "Size",
+            Column::AccessCount => "Access count",
+            Column::LastAccessed => "Last accessed",
+        }
+    }
+
+    /// Direction when the column becomes a sort key, largest and newest first
+    fn default_direction(&self) -> Direction {
+        match self {
+            Column::Path => Direction::Ascending,
+            _ => Direction::Descending,
+        }
+    }
+
+    fn compare(&self, a: &CacheFileEntry, b: &CacheFileEntry) -> Ordering {
+        match self {
+            Column::Path => a.path.cmp(&b.path),
+            Column::Size => a.size_bytes.cmp(&b.size_bytes),
+            Column::AccessCount => a.access_count.cmp(&b.access_count),
+            Column::LastAccessed => a.last_accessed_at.cmp(&b.last_accessed_at),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction {
+    Ascending,
+    Descending,
+}
+
+impl Direction {
+    fn reversed(&self) -> Self {
+        match self {
+            Direction::Ascending => Direction::Descending,
+            Direction::Descending => Direction::Ascending,
+        }
+    }
+
+    fn arrow(&self) -> &'static str {
+        match self {
+            Direction::Ascending => "▲",
+            Direction::Descending => "▼",
+        }
+    }
+}
+
+/// Sort keys, the primary first and at most one secondary
+#[derive(Clone, Debug, PartialEq)]
+struct SortCriteria(Vec<(Column, Direction)>);
+
+impl Default for SortCriteria {
+    fn default() -> Self {
+        Self(vec![(Column::AccessCount, Direction::Descending)])
+    }
+}
+
+impl SortCriteria {
+    fn sort(&self, files: &mut [CacheFileEntry]) {
+        files.sort_by(|a, b| {
+            self.0
+                .iter()
+                .map(|(column, direction)| match direction {
+                    Direction::Ascending => column.compare(a, b),
+                    Direction::Descending => column.compare(b, a),
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        });
+    }
+
+    /// Position (0 = primary) and direction of the column among the sort keys
+    fn position(&self, column: Column) -> Option<(usize, Direction)> {
+        self.0
+            .iter()
+            .position(|(key, _)| *key == column)
+            .map(|index| (index, self.0[index].1))
+    }
+
+    /// The primary column reverses its direction, the secondary becomes primary
+    /// and any other column becomes the secondary
+    fn click(&mut self, column: Column) {
+        match self.position(column) {
+            Some((0, direction)) => self.0[0].1 = direction.reversed(),
+            Some(_) => self.0.swap(0, 1),
+            None => {
+                self.0.truncate(1);
+                self.0.push((column, column.default_direction()));
+            }
+        }
+    }
+}
+
+#[component]
+pub fn CacheBrowser(server_address: ReadSignal<String>) -> impl IntoView {
+    let toast = use_toast();
+    let (files, set_files) = signal(None::<Vec<CacheFileEntry>>);
+    let sort_criteria = RwSignal::new(SortCriteria::default());
+    let (inspected_path, set_inspected_path) = signal(None::<String>);
+    // path -> whether the recomputed hash matches the stored one
+    let verified = RwSignal::new(HashMap::<String, bool>::new());
+
+    let verify_file = {
+        let toast = toast.clone();
+        Action::new(move |(path, expected_hash): &(String, String)| {
+            let address = server_address.get();
+            let path = path.clone();
+            let expected_hash = expected_hash.clone();
+            let toast = toast.clone();
+
+            async move {
+                match fetch_api::<VerifyResponse>(&format!(
+                    "{address}/cache_files/verify?path={}",
+                    urlencoding::encode(&path)
+                ))
+                .await
+                {
+                    Ok(response) => {
+                        let is_valid = response.file_hash.eq_ignore_ascii_case(&expected_hash);
+                        verified.update(|verified| {
+                            verified.insert(path, is_valid);
+                        });
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to verify {path}: {e}"));
+                    }
+                }
+            }
+        })
+    };
+
+    let fetch_files = Action::new(move |_: &()| {
+        let address = server_address.get();
+        let toast = toast.clone();
+
+        async move {
+            match fetch_api::<Vec<CacheFileEntry>>(&format!("{address}/cache_files")).await {
+                Ok(response) => {
+                    set_files.set(Some(response));
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to fetch cache files: {e}"));
+                }
+            }
+        }
+    });
+
+    let sorted_files = move || {
+        files.get().map(|mut files| {
+            sort_criteria.with(|criteria| criteria.sort(&mut files));
+            files
+        })
+    };
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Cache Files")}</h2>
+                <div class="flex items-center gap-2">
+                    <button
+                        class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                        on:click=move |_| {
+                            fetch_files.dispatch(());
+                        }
+                    >
+                        {move || t("Refresh")}
+                    </button>
+                </div>
+            </div>
+            {move || match sorted_files() {
+                Some(files) => {
+                    view! {
+                        <div class="max-h-64 overflow-y-auto">
+                            <table class="w-full text-xs">
+                                <thead>
+                                    <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                        {[Column::Path, Column::Size, Column::AccessCount, Column::LastAccessed]
+                                            .map(|column| {
+                                                view! {
+                                                    <th
+                                                        class="py-1 font-normal cursor-pointer select-none hover:text-gray-700 dark:hover:text-gray-200"
+                                                        title="Click to sort, click another column to add it as secondary sort"
+                                                        on:click=move |_| sort_criteria.update(|criteria| criteria.click(column))
+                                                    >
+                                                        {column.label()}
+                                                        {move || {
+                                                            sort_criteria
+                                                                .with(|criteria| criteria.position(column))
+                                                                .map(|(index, direction)| {
+                                                                    view! {
+                                                                        <span class="ml-1">{direction.arrow()}</span>
+                                                                        {(index == 1)
+                                                                            .then(|| {
+                                                                                view! {
+                                                                                    <span class="ml-0.5 px-1 rounded bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300">
+                                                                                        "2"
+                                                                                    </span>
+                                                                                }
+                                                                            })}
+                                                                    }
+                                                                })
+                                                        }}
+                                                    </th>
+                                                }
+                                            })}
+                                        <th class="py-1 font-normal">"Hash"</th>
+                                        <th></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {files
+                                        .into_iter()
+                                        .map(|file| {
+                                            let is_parquet = file.path.ends_with(".parquet");
+                                            let path = file.path.clone();
+                                            let verify_path = file.path.clone();
+                                            let badge_path = file.path.clone();
+                                            view! {
+                                                <tr class="border-b border-gray-50 dark:border-gray-800">
+                                                    <td
+                                                        class="py-1 text-gray-800 dark:text-gray-100 truncate max-w-xs"
+                                                        title=file.path.clone()
+                                                    >
+                                                        {file.path.clone()}
+                                                    </td>
+                                                    <td class="py-1 text-gray-800 dark:text-gray-100">
+                                                        {format_bytes(file.size_bytes)}
+                                                    </td>
+                                                    <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                                        {file.access_count}
+                                                    </td>
+                                                    <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                                        {format_timestamp(file.last_accessed_at)}
+                                                    </td>
+                                                    <td class="py-1 font-mono text-gray-500 dark:text-gray-400">
+                                                        {match file.file_hash.clone() {
+                                                            Some(hash) => {
+                                                                view! {
+                                                                    <div class="flex items-center gap-1">
+                                                                        <span title=hash.clone()>
+                                                                            {hash.chars().take(8).collect::<String>()}
+                                                                        </span>
+                                                                        {move || {
+                                                                            verified
+                                                                                .with(|verified| verified.get(&badge_path).copied())
+                                                                                .map(|is_valid| {
+                                                                                    if is_valid {
+                                                                                        view! {
+                                                                                            <span class="px-1 rounded text-green-700 bg-green-50">
+                                                                                                "✓ Valid"
+                                                                                            </span>
+                                                                                        }
+                                                                                    } else {
+                                                                                        view! {
+                                                                                            <span class="px-1 rounded text-red-700 bg-red-50">
+                                                                                                "✗ Corrupted"
+                                                                                            </span>
+                                                                                        }
+                                                                                    }
+                                                                                })
+                                                                        }}
+                                                                        <button
+                                                                            class="text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                                            on:click=move |_| {
+                                                                                verify_file.dispatch((verify_path.clone(), hash.clone()));
+                                                                            }
+                                                                        >
+                                                                            "Verify"
+                                                                        </button>
+                                                                    </div>
+                                                                }
+                                                                    .into_any()
+                                                            }
+                                                            None => view! { <span class="text-gray-300">"—"</span> }.into_any(),
+                                                        }}
+                                                    </td>
+                                                    <td class="py-1 text-right">
+                                                        {is_parquet
+                                                            .then(|| {
+                                                                view! {
+                                                                    <button
+                                                                        class="text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                                        on:click=move |_| {
+                                                                            set_inspected_path.set(Some(path.clone()))
+                                                                        }
+                                                                    >
+                                                                        "Inspect"
+                                                                    </button>
+                                                                }
+                                                            })}
+                                                    </td>
+                                                </tr>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <div class="text-gray-400 text-xs italic">
+                            "Refresh to browse cached files"
+                        </div>
+                    }
+                        .into_any()
+                }
+            }}
+            {move || {
+                inspected_path
+                    .get()
+                    .map(|path| {
+                        view! {
+                            <RowGroupInspector
+                                server_address=server_address
+                                path=path
+                                on_close=Callback::new(move |_| set_inspected_path.set(None))
+                            />
+                        }
+                    })
+            }}
+        </div>
+    }
+}
@@ -2,23 +2,275 @@ use leptos::prelude::*;
 use serde::Deserialize;
 
 use crate::{
-    components::toast::use_toast,
-    utils::{fetch_api, format_bytes, ApiResponse},
+    components::{
+        batch_size_vis::BatchSizeVis,
+        cache_ops::CacheOpsLog,
+        charts::DonutChart,
+        dialog::ConfirmDialog,
+        plan_cache_stats::PlanCacheStats,
+        progress::UsageBar,
+        spinner::Spinner,
+        tier_planning::{CacheTier, TierPlanning},
+        toast::use_toast,
+    },
+    models::{
+        presets::{CachePreset, PRESETS},
+        server_info::ParquetCacheUsage,
+    },
+    utils::{fetch_api, format_bytes_with_precision, i18n::t, ApiResponse},
 };
 
-#[derive(Deserialize, Clone)]
-pub struct ParquetCacheUsage {
-    pub directory: String,
-    pub file_count: usize,
-    pub total_size_bytes: u64,
-}
-
 #[derive(Deserialize, Clone, Debug)]
 pub struct CacheInfo {
     pub batch_size: usize,
     pub max_cache_bytes: u64,
     pub memory_usage_bytes: u64,
     pub disk_usage_bytes: u64,
+    /// Storage tiers from fastest to slowest, empty for servers that don't report them
+    #[serde(default)]
+    pub tiers: Vec<CacheTier>,
+    /// Share of reads served from the cache, between 0 and 1
+    #[serde(default)]
+    pub hit_rate: Option<f64>,
+    /// Free space left on the disk holding the cache directory
+    #[serde(default)]
+    pub disk_free_bytes: Option<u64>,
+    /// Read operations per second since server start
+    #[serde(default)]
+    pub read_iops: u64,
+    /// Write operations per second since server start
+    #[serde(default)]
+    pub write_iops: u64,
+    /// Reads served from the cache since server start
+    #[serde(default)]
+    pub cache_hits: u64,
+    /// Reads that missed the cache since server start
+    #[serde(default)]
+    pub cache_misses: u64,
+}
+
+/// Destructive action waiting for confirmation
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ConfirmType {
+    ResetCache,
+    ShutdownServer,
+}
+
+impl ConfirmType {
+    fn title(&self) -> &'static str {
+        match self {
+            ConfirmType::ResetCache => "Reset cache?",
+            ConfirmType::ShutdownServer => "Shut down server?",
+        }
+    }
+
+    fn confirm_label(&self) -> &'static str {
+        match self {
+            ConfirmType::ResetCache => "Reset cache",
+            ConfirmType::ShutdownServer => "Shut down",
+        }
+    }
+
+    fn message(&self) -> &'static str {
+        match self {
+            ConfirmType::ResetCache => {
+                "All cached data is dropped and has to be read from storage again."
+            }
+            ConfirmType::ShutdownServer => {
+                "The server stops and has to be restarted by hand before it can serve queries again."
+            }
+        }
+    }
+}
+
+/// Samples kept for the cache info chart
+const CACHE_HISTORY_LEN: usize = 60;
+
+/// One cache info poll, plotted by `CacheInfoChart`
+#[derive(Clone, Copy, Debug)]
+struct CacheSample {
+    memory_usage_bytes: u64,
+    disk_usage_bytes: u64,
+    read_iops: u64,
+    write_iops: u64,
+}
+
+impl From<&CacheInfo> for CacheSample {
+    fn from(info: &CacheInfo) -> Self {
+        Self {
+            memory_usage_bytes: info.memory_usage_bytes,
+            disk_usage_bytes: info.disk_usage_bytes,
+            read_iops: info.read_iops,
+            write_iops: info.write_iops,
+        }
+    }
+}
+
+type SampleValue = fn(&CacheSample) -> u64;
+
+/// Memory and disk usage scaled to their maximum, IOPS scaled to the saturation threshold
+#[component]
+fn CacheInfoChart(history: ReadSignal<Vec<CacheSample>>, iops_saturation: u64) -> impl IntoView {
+    const WIDTH: f64 = 300.0;
+    const HEIGHT: f64 = 60.0;
+
+    let points = move |value: SampleValue, scale_to: Option<u64>| {
+        history.with(|history| {
+            let max = scale_to
+                .into_iter()
+                .chain(history.iter().map(value))
+                .max()
+                .unwrap_or(0)
+                .max(1) as f64;
+            let step = WIDTH / (CACHE_HISTORY_LEN - 1) as f64;
+            history
+                .iter()
+                .enumerate()
+                .map(|(i, sample)| {
+                    format!(
+                        "{:.1},{:.1}",
+                        i as f64 * step,
+                        HEIGHT - value(sample) as f64 / max * HEIGHT
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+    };
+    let iops_scale = move || {
+        history.with(|history| {
+            history
+                .iter()
+                .flat_map(|sample| [sample.read_iops, sample.write_iops])
+                .chain([iops_saturation])
+                .max()
+                .unwrap_or(1)
+                .max(1)
+        })
+    };
+    let series: [(&str, &str, SampleValue, bool); 4] = [
+        ("Memory", "stroke-blue-500", |s| s.memory_usage_bytes, false),
+        ("Disk", "stroke-gray-400", |s| s.disk_usage_bytes, false),
+        ("Read IOPS", "stroke-green-500", |s| s.read_iops, true),
+        ("Write IOPS", "stroke-orange-500", |s| s.write_iops, true),
+    ];
+
+    view! {
+        <Show when=move || history.with(|history| history.len() > 1)>
+            <svg
+                class="w-full h-16"
+                viewBox=format!("0 0 {WIDTH} {HEIGHT}")
+                preserveAspectRatio="none"
+            >
+                <line
+                    x1="0"
+                    x2=WIDTH
+                    y1=move || HEIGHT - iops_saturation as f64 / iops_scale() as f64 * HEIGHT
+                    y2=move || HEIGHT - iops_saturation as f64 / iops_scale() as f64 * HEIGHT
+                    class="stroke-red-300"
+                    stroke-dasharray="4 2"
+                    stroke-width="1"
+                ></line>
+                {series
+                    .map(|(_, color, value, is_iops)| {
+                        view! {
+                            <polyline
+                                class=color
+                                fill="none"
+                                stroke-width="1.5"
+                                vector-effect="non-scaling-stroke"
+                                points=move || points(value, is_iops.then(iops_scale))
+                            />
+                        }
+                    })
+                    .collect_view()}
+            </svg>
+            <div class="flex gap-3 text-xs text-gray-500 dark:text-gray-400">
+                {series
+                    .map(|(label, color, _, _)| {
+                        view! {
+                            <span class="flex items-center gap-1">
+                                <svg class="w-3 h-2">
+                                    <line x1="0" x2="12" y1="4" y2="4" class=color stroke-width="2"></line>
+                                </svg>
+                                {label}
+                            </span>
+                        }
+                    })
+                    .collect_view()}
+                <span class="text-red-400">"- - IOPS saturation"</span>
+            </div>
+        </Show>
+    }
+}
+
+impl CacheInfo {
+    /// Memory used relative to the allowed cache size, between 0 and 1
+    pub fn utilization(&self) -> f64 {
+        if self.max_cache_bytes == 0 {
+            0.0
+        } else {
+            (self.memory_usage_bytes as f64 / self.max_cache_bytes as f64).min(1.0)
+        }
+    }
+
+    /// Memory and disk used together relative to the allowed cache size, in percent
+    fn usage_pct(&self) -> f64 {
+        if self.max_cache_bytes == 0 {
+            0.0
+        } else {
+            let used = self.memory_usage_bytes + self.disk_usage_bytes;
+            (used as f64 * 100.0 / self.max_cache_bytes as f64).min(100.0)
+        }
+    }
+
+    /// Memory, disk and remaining share of the allowed cache size
+    fn usage_segments(&self) -> Vec<(String, u64, &'static str)> {
+        let available = self
+            .max_cache_bytes
+            .saturating_sub(self.memory_usage_bytes + self.disk_usage_bytes);
+        vec![
+            ("Memory".to_string(), self.memory_usage_bytes, "#3b82f6"),
+            ("Disk".to_string(), self.disk_usage_bytes, "#9ca3af"),
+            ("Available".to_string(), available, "#bbf7d0"),
+        ]
+    }
+}
+
+/// The three metrics shown in compact mode
+#[component]
+fn CompactCacheInfo(info: CacheInfo, byte_decimal_places: u8) -> impl IntoView {
+    let metrics = [
+        ("Utilization", format!("{:.1}%", info.utilization() * 100.0)),
+        (
+            "Hit rate",
+            info.hit_rate
+                .map(|rate| format!("{:.1}%", rate * 100.0))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+        (
+            "Disk free",
+            info.disk_free_bytes
+                .map(|bytes| format_bytes_with_precision(bytes, byte_decimal_places))
+                .unwrap_or_else(|| "-".to_string()),
+        ),
+    ];
+
+    view! {
+        <div class="grid grid-cols-3 gap-3">
+            {metrics
+                .into_iter()
+                .map(|(label, value)| {
+                    view! {
+                        <div>
+                            <div class="text-xs text-gray-500 dark:text-gray-400">{label}</div>
+                            <div class="text-sm font-medium text-gray-800 dark:text-gray-100">{value}</div>
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
 }
 
 type RefreshCallback = Box<dyn Fn() + 'static>;
@@ -29,8 +281,30 @@ pub fn CacheInfo(
     cache_usage: ReadSignal<Option<ParquetCacheUsage>>,
     on_refresh: RefreshCallback,
     server_address: ReadSignal<String>,
+    /// Address of the last Connect, the live operations feed polls it
+    connected_address: ReadSignal<Option<String>>,
+    /// Whether a refresh is in flight, the stale data stays visible but dimmed
+    #[prop(into)]
+    loading: Signal<bool>,
+    /// Fractional digits of byte sizes
+    #[prop(default = 2)]
+    byte_decimal_places: u8,
+    /// IOPS above which the disk is considered close to saturation
+    #[prop(default = 10_000)]
+    iops_saturation: u64,
 ) -> impl IntoView {
     let toast = use_toast();
+    let (history, set_history) = signal(Vec::<CacheSample>::new());
+    Effect::new(move || {
+        if let Some(info) = cache_info.get() {
+            set_history.update(|history| {
+                history.push(CacheSample::from(&info));
+                if history.len() > CACHE_HISTORY_LEN {
+                    history.remove(0);
+                }
+            });
+        }
+    });
     let reset_cache = {
         let toast = toast.clone();
         Action::new(move |_: &()| {
@@ -43,7 +317,7 @@ pub fn CacheInfo(
                         toast.show_success(response.message);
                     }
                     Err(e) => {
-                        toast.show_error(format!("Failed to reset cache: {e}"));
+                        toast.show_critical(format!("Failed to reset cache: {e}"));
                     }
                 }
             }
@@ -62,48 +336,188 @@ pub fn CacheInfo(
                         toast.show_success(response.message);
                     }
                     Err(e) => {
-                        toast.show_error(format!("Failed to shutdown server: {e}"));
+                        toast.show_critical(format!("Failed to shutdown server: {e}"));
                     }
                 }
             }
         })
     };
 
+    let show_confirm_type = RwSignal::new(None::<ConfirmType>);
+    let on_confirm = Callback::new(move |_| {
+        match show_confirm_type.get_untracked() {
+            Some(ConfirmType::ResetCache) => {
+                reset_cache.dispatch(());
+            }
+            Some(ConfirmType::ShutdownServer) => {
+                shutdown_server.dispatch(());
+            }
+            None => {}
+        }
+        show_confirm_type.set(None);
+    });
+    let on_cancel = Callback::new(move |_| show_confirm_type.set(None));
+
+    let (show_presets, set_show_presets) = signal(false);
+    let (selected_preset, set_selected_preset) = signal(None::<CachePreset>);
+    let (show_batch_size_vis, set_show_batch_size_vis) = signal(false);
+    let (show_cache_ops, set_show_cache_ops) = signal(false);
+    let (compact, set_compact) = signal(false);
+
     view! {
-        <div class="border border-gray-200 rounded-lg bg-white p-4">
-            <div class="flex justify-between items-center mb-3">
-                <h2 class="text-base font-medium text-gray-700">"Cache"</h2>
-                <button
-                    class="text-xs text-gray-500 hover:text-gray-700 px-2 py-1 rounded hover:bg-gray-50"
-                    on:click=move |_| on_refresh()
-                >
-                    "Refresh"
-                </button>
+        <div
+            class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4 transition-opacity"
+            class:py-2=compact
+            class:opacity-75=loading
+            class:pointer-events-none=loading
+        >
+            <div class="flex justify-between items-center" class:mb-3=move || !compact.get()>
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Cache")}</h2>
+                <div class="flex items-center gap-1">
+                    <button
+                        class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                        on:click=move |_| set_compact.update(|c| *c = !*c)
+                    >
+                        {move || if compact.get() { t("Expand") } else { t("Compact") }}
+                    </button>
+                    <div class="relative" class:hidden=compact>
+                        <button
+                            class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                            on:click=move |_| set_show_presets.update(|s| *s = !*s)
+                        >
+                            {move || t("Presets")}
+                        </button>
+                        <Show when=move || show_presets.get()>
+                            <div class="absolute right-0 mt-1 w-56 bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded shadow-sm z-10 py-1">
+                                {PRESETS
+                                    .iter()
+                                    .map(|preset| {
+                                        let preset = *preset;
+                                        view! {
+                                            <button
+                                                class="block w-full text-left px-3 py-1 text-xs text-gray-700 dark:text-gray-200 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                on:click=move |_| {
+                                                    set_selected_preset.set(Some(preset));
+                                                    set_show_presets.set(false);
+                                                }
+                                            >
+                                                {preset.label()}
+                                            </button>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </div>
+                        </Show>
+                    </div>
+                    <button
+                        class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                        on:click=move |_| on_refresh()
+                    >
+                        {move || {
+                            if loading.get() {
+                                view! { <Spinner /> }.into_any()
+                            } else {
+                                t("Refresh").into_any()
+                            }
+                        }}
+                    </button>
+                </div>
             </div>
-            <div class="space-y-3">
+            <Show when=move || compact.get()>
+                {move || match cache_info.get() {
+                    Some(info) => view! { <CompactCacheInfo info=info byte_decimal_places=byte_decimal_places /> }.into_any(),
+                    None => {
+                        view! {
+                            <div class="text-gray-400 text-xs italic">
+                                {move || t("Connect to view cache configuration")}
+                            </div>
+                        }
+                            .into_any()
+                    }
+                }}
+            </Show>
+            <div class="space-y-3" class:hidden=compact>
                 {move || match cache_info.get() {
                     Some(info) => {
                         view! {
                             <div class="text-sm">
                                 <div class="grid grid-cols-4 gap-y-1 text-xs">
-                                    <span class="text-gray-500">"Batch Size"</span>
-                                    <span class="text-gray-800">{info.batch_size}</span>
+                                    <span class="text-gray-500 dark:text-gray-400">"Batch Size"</span>
+                                    <span class="text-gray-800 dark:text-gray-100">{info.batch_size}</span>
+
+                                    <span class="text-gray-500 dark:text-gray-400">"Cache allowed"</span>
+                                    <span class="text-gray-800 dark:text-gray-100">
+                                        {format_bytes_with_precision(info.max_cache_bytes, byte_decimal_places)}
+                                    </span>
 
-                                    <span class="text-gray-500">"Cache allowed"</span>
-                                    <span class="text-gray-800">
-                                        {format_bytes(info.max_cache_bytes)}
+                                    <span class="text-gray-500 dark:text-gray-400">"Cache used"</span>
+                                    <span class="text-gray-800 dark:text-gray-100">
+                                        {format_bytes_with_precision(info.memory_usage_bytes, byte_decimal_places)}
                                     </span>
 
-                                    <span class="text-gray-500">"Cache used"</span>
-                                    <span class="text-gray-800">
-                                        {format_bytes(info.memory_usage_bytes)}
+                                    <span class="text-gray-500 dark:text-gray-400">"Disk used"</span>
+                                    <span class="text-gray-800 dark:text-gray-100">
+                                        {format_bytes_with_precision(info.disk_usage_bytes, byte_decimal_places)}
                                     </span>
 
-                                    <span class="text-gray-500">"Disk used"</span>
-                                    <span class="text-gray-800">
-                                        {format_bytes(info.disk_usage_bytes)}
+                                    <span class="text-gray-500 dark:text-gray-400">"Read IOPS"</span>
+                                    <span
+                                        class="text-gray-800 dark:text-gray-100"
+                                        class:text-red-600={ info.read_iops > iops_saturation }
+                                        title=format!("Saturation threshold: {iops_saturation} IOPS")
+                                    >
+                                        {info.read_iops}
                                     </span>
+
+                                    <span class="text-gray-500 dark:text-gray-400">"Write IOPS"</span>
+                                    <span
+                                        class="text-gray-800 dark:text-gray-100"
+                                        class:text-red-600={ info.write_iops > iops_saturation }
+                                        title=format!("Saturation threshold: {iops_saturation} IOPS")
+                                    >
+                                        {info.write_iops}
+                                    </span>
+                                </div>
+                                <div class="grid grid-cols-[4rem_1fr] items-center gap-x-2 gap-y-1 mt-3 text-xs">
+                                    <span class="text-gray-500 dark:text-gray-400">"Memory"</span>
+                                    <UsageBar usage=info.memory_usage_bytes max=info.max_cache_bytes />
+                                    <span class="text-gray-500 dark:text-gray-400">"Disk"</span>
+                                    <UsageBar usage=info.disk_usage_bytes max=info.max_cache_bytes />
                                 </div>
+                                <div class="flex items-center gap-4 mt-3 text-xs">
+                                    <DonutChart
+                                        segments=info.usage_segments()
+                                        center_text=format!("{:.0}%", info.usage_pct())
+                                    />
+                                    <div class="space-y-1 text-gray-600 dark:text-gray-300">
+                                        {info
+                                            .usage_segments()
+                                            .into_iter()
+                                            .map(|(label, _, color)| {
+                                                view! {
+                                                    <div class="flex items-center gap-1">
+                                                        <span
+                                                            class="inline-block w-3 h-3 rounded-sm"
+                                                            style:background-color=color
+                                                        ></span>
+                                                        {label}
+                                                    </div>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </div>
+                                </div>
+                                <div class="mt-3">
+                                    <CacheInfoChart history=history iops_saturation=iops_saturation />
+                                </div>
+                                {(!info.tiers.is_empty())
+                                    .then(|| {
+                                        view! {
+                                            <div class="mt-3">
+                                                <TierPlanning tiers=info.tiers.clone() />
+                                            </div>
+                                        }
+                                    })}
                             </div>
                         }
                             .into_any()
@@ -111,31 +525,75 @@ pub fn CacheInfo(
                     None => {
                         view! {
                             <div class="text-gray-400 text-xs italic">
-                                "Connect to view cache configuration"
+                                {move || t("Connect to view cache configuration")}
                             </div>
                         }
                             .into_any()
                     }
                 }}
+                {move || {
+                    selected_preset
+                        .get()
+                        .map(|preset| {
+                            view! {
+                                <div class="text-sm border-t border-gray-100 dark:border-gray-800 pt-3">
+                                    <div class="flex justify-between items-center mb-1">
+                                        <span class="text-xs font-medium text-gray-600 dark:text-gray-300">
+                                            {format!("{} preset", preset.name)}
+                                        </span>
+                                        <button
+                                            class="text-xs text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                                            on:click=move |_| set_selected_preset.set(None)
+                                        >
+                                            {move || t("Clear")}
+                                        </button>
+                                    </div>
+                                    <div class="grid grid-cols-4 gap-y-1 text-xs">
+                                        <span class="text-gray-500 dark:text-gray-400">"Batch Size"</span>
+                                        <span class="text-gray-800 dark:text-gray-100">{preset.batch_size}</span>
+
+                                        <span class="text-gray-500 dark:text-gray-400">"Cache allowed"</span>
+                                        <span class="text-gray-800 dark:text-gray-100">
+                                            {format_bytes_with_precision(preset.max_cache_bytes, byte_decimal_places)}
+                                        </span>
+
+                                        <span class="text-gray-500 dark:text-gray-400">"Disk allowed"</span>
+                                        <span class="text-gray-800 dark:text-gray-100">
+                                            {format_bytes_with_precision(preset.max_disk_bytes, byte_decimal_places)}
+                                        </span>
+
+                                        <span class="text-gray-500 dark:text-gray-400">"Tier ratio"</span>
+                                        <span class="text-gray-800 dark:text-gray-100">
+                                            {format!(
+                                                "{:.0}% mem / {:.0}% disk",
+                                                preset.memory_tier_ratio() * 100.0,
+                                                preset.disk_tier_ratio() * 100.0,
+                                            )}
+                                        </span>
+                                    </div>
+                                </div>
+                            }
+                        })
+                }}
                 {move || match cache_usage.get() {
                     Some(usage) => {
                         view! {
-                            <div class="text-sm border-t border-gray-100 pt-3">
+                            <div class="text-sm border-t border-gray-100 dark:border-gray-800 pt-3">
                                 <div class="grid grid-cols-2 gap-y-1 gap-x-3 text-xs">
-                                    <span class="text-gray-500">"Directory"</span>
+                                    <span class="text-gray-500 dark:text-gray-400">"Directory"</span>
                                     <span
-                                        class="text-gray-800 truncate"
+                                        class="text-gray-800 dark:text-gray-100 truncate"
                                         title=usage.directory.clone()
                                     >
                                         {usage.directory.clone()}
                                     </span>
 
-                                    <span class="text-gray-500">"File Count"</span>
-                                    <span class="text-gray-800">{usage.file_count}</span>
+                                    <span class="text-gray-500 dark:text-gray-400">"File Count"</span>
+                                    <span class="text-gray-800 dark:text-gray-100">{usage.file_count}</span>
 
-                                    <span class="text-gray-500">"Total Size"</span>
-                                    <span class="text-gray-800">
-                                        {format_bytes(usage.total_size_bytes)}
+                                    <span class="text-gray-500 dark:text-gray-400">"Total Size"</span>
+                                    <span class="text-gray-800 dark:text-gray-100">
+                                        {format_bytes_with_precision(usage.total_size_bytes, byte_decimal_places)}
                                     </span>
                                 </div>
                             </div>
@@ -144,32 +602,70 @@ pub fn CacheInfo(
                     }
                     None => {
                         view! {
-                            <div class="text-gray-400 text-xs italic border-t border-gray-100 pt-3">
-                                "Connect to view cache usage"
+                            <div class="text-gray-400 text-xs italic border-t border-gray-100 dark:border-gray-800 pt-3">
+                                {move || t("Connect to view cache usage")}
                             </div>
                         }
                             .into_any()
                     }
                 }}
+                <PlanCacheStats server_address=server_address cache_info=cache_info />
+                <div class="border-t border-gray-100 dark:border-gray-800 pt-3">
+                    <button
+                        class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300 font-medium mb-2"
+                        on:click=move |_| set_show_batch_size_vis.update(|s| *s = !*s)
+                    >
+                        {move || if show_batch_size_vis.get() { "▾" } else { "▸" }}
+                        "Batch size effect"
+                    </button>
+                    <Show when=move || show_batch_size_vis.get()>
+                        <BatchSizeVis current_batch_size=Signal::derive(move || {
+                            cache_info.get().map(|info| info.batch_size as u64)
+                        }) />
+                    </Show>
+                </div>
+                <div class="border-t border-gray-100 dark:border-gray-800 pt-3">
+                    <button
+                        class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300 font-medium mb-2"
+                        on:click=move |_| set_show_cache_ops.update(|s| *s = !*s)
+                    >
+                        {move || if show_cache_ops.get() { "▾" } else { "▸" }}
+                        "Live operations"
+                    </button>
+                    <Show when=move || show_cache_ops.get()>
+                        <CacheOpsLog connected_address=connected_address />
+                    </Show>
+                </div>
             </div>
-            <div class="flex gap-2 mt-3 pt-3 border-t border-gray-100">
+            <div class="flex gap-2 mt-3 pt-3 border-t border-gray-100 dark:border-gray-800" class:hidden=compact>
                 <button
-                    class="px-2 py-1 border border-gray-200 rounded text-gray-600 hover:bg-gray-50 transition-colors text-xs"
-                    on:click=move |_| {
-                        reset_cache.dispatch(());
-                    }
+                    class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs"
+                    on:click=move |_| show_confirm_type.set(Some(ConfirmType::ResetCache))
                 >
-                    "Reset Cache"
+                    {move || t("Reset Cache")}
                 </button>
                 <button
                     class="px-2 py-1 border border-red-100 rounded text-red-500 hover:bg-red-50 transition-colors text-xs"
-                    on:click=move |_| {
-                        shutdown_server.dispatch(());
-                    }
+                    on:click=move |_| show_confirm_type.set(Some(ConfirmType::ShutdownServer))
                 >
-                    "Shutdown Server"
+                    {move || t("Shutdown Server")}
                 </button>
             </div>
+            {move || {
+                show_confirm_type
+                    .get()
+                    .map(|confirm_type| {
+                        view! {
+                            <ConfirmDialog
+                                title=confirm_type.title()
+                                message=confirm_type.message()
+                                confirm_label=confirm_type.confirm_label()
+                                on_confirm=on_confirm
+                                on_cancel=on_cancel
+                            />
+                        }
+                    })
+            }}
         </div>
     }
 }
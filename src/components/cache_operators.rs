@@ -0,0 +1,72 @@
+use leptos::prelude::*;
+
+use crate::models::execution_plan::{is_cache_operator, ExecutionPlanWithStats};
+use crate::utils::format_bytes;
+
+/// Hit rate and bytes read of the nodes that go through the cache
+#[component]
+pub fn CacheOperatorSummary(plan: ExecutionPlanWithStats) -> impl IntoView {
+    let nodes = plan.nodes_with_paths();
+    let other_count = nodes
+        .iter()
+        .filter(|(_, node)| !is_cache_operator(&node.name))
+        .count();
+    let rows = nodes
+        .into_iter()
+        .filter(|(_, node)| is_cache_operator(&node.name))
+        .map(|(path, node)| {
+            (
+                path,
+                node.name.clone(),
+                node.cache_hit_rate()
+                    .map(|rate| format!("{:.1}%", rate * 100.0))
+                    .unwrap_or_else(|| "-".to_string()),
+                node.bytes_read()
+                    .map(|bytes| format_bytes(bytes as u64))
+                    .unwrap_or_else(|| "-".to_string()),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="mb-4 border border-gray-100 dark:border-gray-800 rounded p-3 text-xs">
+            <div class="font-medium text-gray-700 dark:text-gray-200 mb-2">"Cache operators"</div>
+            {if rows.is_empty() {
+                view! { <div class="text-gray-400 italic">"No cache operators in this plan"</div> }
+                    .into_any()
+            } else {
+                view! {
+                    <table class="w-full">
+                        <thead>
+                            <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                <th class="py-1 font-normal">"Operator"</th>
+                                <th class="py-1 font-normal">"Hit rate"</th>
+                                <th class="py-1 font-normal">"Bytes read"</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {rows
+                                .into_iter()
+                                .map(|(path, name, hit_rate, bytes_read)| {
+                                    view! {
+                                        <tr class="border-b border-gray-50 dark:border-gray-800">
+                                            <td class="py-1 text-gray-800 dark:text-gray-100" title=format!("Node {path}")>
+                                                {name}
+                                            </td>
+                                            <td class="py-1 font-mono text-gray-800 dark:text-gray-100">{hit_rate}</td>
+                                            <td class="py-1 font-mono text-gray-800 dark:text-gray-100">{bytes_read}</td>
+                                        </tr>
+                                    }
+                                })
+                                .collect_view()}
+                        </tbody>
+                    </table>
+                }
+                    .into_any()
+            }}
+            <div class="mt-2 text-gray-500 dark:text-gray-400">
+                {format!("Other operators: {other_count}")}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,150 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    components::toast::use_toast,
+    utils::{fetch_api, format_bytes, format_timestamp},
+};
+
+const CACHE_OPS_REFRESH_MS: u64 = 2_000;
+const CACHE_OPS_FETCH_LIMIT: usize = 20;
+/// Operations kept in the feed, older ones scroll out
+const MAX_CACHE_OPS: usize = 200;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CacheOp {
+    pub id: u64,
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+    /// e.g. "GET hit", "GET miss", "PUT", "DELETE" or "EVICT"
+    pub operation: String,
+    pub path: String,
+    pub size_bytes: u64,
+}
+
+impl CacheOp {
+    fn color_class(&self) -> &'static str {
+        let operation = self.operation.to_ascii_lowercase();
+        if operation.contains("hit") {
+            "text-green-600 dark:text-green-400"
+        } else if operation.contains("miss") {
+            "text-red-500 dark:text-red-400"
+        } else if operation.starts_with("put") {
+            "text-blue-600 dark:text-blue-400"
+        } else {
+            "text-gray-500 dark:text-gray-400"
+        }
+    }
+}
+
+/// Feed of the most recent cache operations of the connected server, newest first,
+/// polled every 2 seconds while mounted
+#[component]
+pub fn CacheOpsLog(connected_address: ReadSignal<Option<String>>) -> impl IntoView {
+    let toast = use_toast();
+    let (ops, set_ops) = signal(Vec::<CacheOp>::new());
+    let last_id = StoredValue::new(None::<u64>);
+    // only the first failure of a streak is reported
+    let failing = StoredValue::new(false);
+
+    let fetch_ops = Action::new(move |_: &()| {
+        let address = connected_address.get_untracked();
+        let toast = toast.clone();
+        let since = last_id
+            .get_value()
+            .map_or(String::new(), |id| format!("&since={id}"));
+
+        async move {
+            let Some(address) = address else {
+                return;
+            };
+            match fetch_api::<Vec<CacheOp>>(&format!(
+                "{address}/cache_ops?limit={CACHE_OPS_FETCH_LIMIT}{since}"
+            ))
+            .await
+            {
+                Ok(mut response) => {
+                    failing.set_value(false);
+                    response.retain(|op| last_id.get_value().is_none_or(|last| op.id > last));
+                    if let Some(newest) = response.iter().map(|op| op.id).max() {
+                        last_id.set_value(Some(newest));
+                    }
+                    response.sort_by_key(|op| std::cmp::Reverse(op.id));
+                    set_ops.update(|ops| {
+                        ops.splice(0..0, response);
+                        ops.truncate(MAX_CACHE_OPS);
+                    });
+                }
+                Err(e) => {
+                    if !failing.get_value() {
+                        toast.show_error(format!("Failed to fetch cache operations: {e}"));
+                    }
+                    failing.set_value(true);
+                }
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        let connected = connected_address.with(Option::is_some);
+        last_id.set_value(None);
+        failing.set_value(false);
+        set_ops.set(Vec::new());
+        if connected {
+            fetch_ops.dispatch(());
+        }
+    });
+
+    let timer = set_interval_with_handle(
+        move || {
+            if connected_address.with_untracked(Option::is_some)
+                && !fetch_ops.pending().get_untracked()
+            {
+                fetch_ops.dispatch(());
+            }
+        },
+        std::time::Duration::from_millis(CACHE_OPS_REFRESH_MS),
+    )
+    .ok();
+    on_cleanup(move || {
+        if let Some(handle) = timer {
+            handle.clear();
+        }
+    });
+
+    view! {
+        <div class="max-h-48 overflow-y-auto text-xs font-mono">
+            <Show
+                when=move || !ops.with(Vec::is_empty)
+                fallback=|| {
+                    view! {
+                        <div class="text-gray-400 italic font-sans">"No cache operations yet"</div>
+                    }
+                }
+            >
+                <For
+                    each=move || ops.get()
+                    key=|op| op.id
+                    children=move |op| {
+                        view! {
+                            <div class="flex gap-2 py-0.5 border-b border-gray-50 dark:border-gray-800">
+                                <span class="w-16 shrink-0 text-gray-400">
+                                    {format_timestamp(op.timestamp)}
+                                </span>
+                                <span class=format!("w-16 shrink-0 {}", op.color_class())>
+                                    {op.operation.clone()}
+                                </span>
+                                <span class="flex-1 truncate text-gray-700 dark:text-gray-200" title=op.path.clone()>
+                                    {op.path.clone()}
+                                </span>
+                                <span class="shrink-0 text-gray-500 dark:text-gray-400">
+                                    {format_bytes(op.size_bytes)}
+                                </span>
+                            </div>
+                        }
+                    }
+                />
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,214 @@
+use leptos::prelude::*;
+use std::f64::consts::PI;
+
+use crate::utils::format_bytes;
+
+/// Inner radius as a fraction of the outer radius
+const DONUT_HOLE: f64 = 0.6;
+const GAUGE_SIZE: f64 = 80.0;
+const GAUGE_STROKE: f64 = 8.0;
+
+/// Point on a circle of `radius` around `center`, angle 0 at the top, clockwise
+fn polar_point(center: f64, radius: f64, angle: f64) -> (f64, f64) {
+    (center + radius * angle.sin(), center - radius * angle.cos())
+}
+
+/// SVG path of a ring segment between two angles
+fn arc_path(center: f64, outer: f64, inner: f64, start: f64, end: f64) -> String {
+    // A full circle arc has identical end points and would not render
+    let end = end.min(start + 2.0 * PI - 1e-4);
+    let large_arc = u8::from(end - start > PI);
+    let (ox0, oy0) = polar_point(center, outer, start);
+    let (ox1, oy1) = polar_point(center, outer, end);
+    let (ix1, iy1) = polar_point(center, inner, end);
+    let (ix0, iy0) = polar_point(center, inner, start);
+    format!(
+        "M {ox0:.2} {oy0:.2} A {outer:.2} {outer:.2} 0 {large_arc} 1 {ox1:.2} {oy1:.2} \
+         L {ix1:.2} {iy1:.2} A {inner:.2} {inner:.2} 0 {large_arc} 0 {ix0:.2} {iy0:.2} Z"
+    )
+}
+
+/// Ring chart of byte values, segments are `(label, bytes, color)`
+#[component]
+pub fn DonutChart(
+    segments: Vec<(String, u64, &'static str)>,
+    /// Width and height in pixels
+    #[prop(default = 120)]
+    size: u32,
+    /// Text in the center of the ring
+    #[prop(optional, into)]
+    center_text: Option<String>,
+) -> impl IntoView {
+    let hovered = RwSignal::new(None::<usize>);
+    let total = segments.iter().map(|(_, value, _)| *value).sum::<u64>();
+    let center = size as f64 / 2.0;
+    let outer = center - 1.0;
+    let inner = outer * DONUT_HOLE;
+
+    let mut start = 0.0;
+    let paths = segments
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, value, _))| *value > 0)
+        .map(|(index, (_, value, color))| {
+            let end = start + 2.0 * PI * *value as f64 / total as f64;
+            let path = arc_path(center, outer, inner, start, end);
+            start = end;
+            view! {
+                <path
+                    d=path
+                    fill=*color
+                    class="cursor-pointer transition-opacity"
+                    class:opacity-60=move || hovered.get().is_some_and(|hovered| hovered != index)
+                    on:mouseenter=move |_| hovered.set(Some(index))
+                    on:mouseleave=move |_| hovered.set(None)
+                />
+            }
+        })
+        .collect_view();
+
+    let tooltip = move || {
+        hovered.get().and_then(|index| segments.get(index).cloned()).map(|(label, value, _)| {
+            view! {
+                <div class="absolute left-1/2 -top-7 -translate-x-1/2 whitespace-nowrap px-2 py-0.5 rounded bg-gray-800 text-white text-xs font-mono shadow">
+                    {format!("{label}: {}", format_bytes(value))}
+                </div>
+            }
+        })
+    };
+
+    view! {
+        <div class="relative inline-block" style:width=format!("{size}px") style:height=format!("{size}px")>
+            <svg width=size height=size viewBox=format!("0 0 {size} {size}")>
+                <circle
+                    cx=center
+                    cy=center
+                    r=(outer + inner) / 2.0
+                    fill="none"
+                    stroke-width=outer - inner
+                    class="stroke-gray-100 dark:stroke-gray-800"
+                />
+                {paths}
+                {center_text
+                    .map(|text| {
+                        view! {
+                            <text
+                                x=center
+                                y=center
+                                text-anchor="middle"
+                                dominant-baseline="middle"
+                                font-size="14"
+                                class="font-mono fill-gray-800 dark:fill-gray-100"
+                            >
+                                {text}
+                            </text>
+                        }
+                    })}
+            </svg>
+            {tooltip}
+        </div>
+    }
+}
+
+/// Circular gauge of a percentage, gray with "–" when there is no value
+#[component]
+pub fn GaugeChart(value: Option<f64>, label: &'static str) -> impl IntoView {
+    let radius = (GAUGE_SIZE - GAUGE_STROKE) / 2.0;
+    let circumference = 2.0 * PI * radius;
+    let value = value
+        .filter(|value| value.is_finite())
+        .map(|value| value.clamp(0.0, 100.0));
+    let color = match value {
+        Some(value) if value >= 90.0 => "stroke-red-500",
+        Some(value) if value >= 70.0 => "stroke-yellow-400",
+        Some(_) => "stroke-green-500",
+        None => "stroke-gray-300 dark:stroke-gray-600",
+    };
+    // the filled share of the circle is drawn as one dash, the rest as the gap
+    let filled = value.map_or(0.0, |value| circumference * value / 100.0);
+
+    view! {
+        <div class="flex flex-col items-center text-xs text-gray-500 dark:text-gray-400">
+            <svg
+                width=GAUGE_SIZE
+                height=GAUGE_SIZE
+                viewBox=format!("0 0 {GAUGE_SIZE} {GAUGE_SIZE}")
+            >
+                <circle
+                    cx=GAUGE_SIZE / 2.0
+                    cy=GAUGE_SIZE / 2.0
+                    r=radius
+                    fill="none"
+                    stroke-width=GAUGE_STROKE
+                    class="stroke-gray-100 dark:stroke-gray-800"
+                />
+                <circle
+                    cx=GAUGE_SIZE / 2.0
+                    cy=GAUGE_SIZE / 2.0
+                    r=radius
+                    fill="none"
+                    stroke-width=GAUGE_STROKE
+                    stroke-linecap="round"
+                    stroke-dasharray=format!("{filled:.2} {circumference:.2}")
+                    transform=format!("rotate(-90 {0} {0})", GAUGE_SIZE / 2.0)
+                    class=format!("{color} transition-all duration-500")
+                />
+                <text
+                    x=GAUGE_SIZE / 2.0
+                    y=GAUGE_SIZE / 2.0
+                    text-anchor="middle"
+                    dominant-baseline="middle"
+                    font-size="14"
+                    class="font-mono fill-gray-800 dark:fill-gray-100"
+                >
+                    {value.map_or("–".to_string(), |value| format!("{value:.0}%"))}
+                </text>
+            </svg>
+            <span title=value.is_none().then_some("No data from the server")>{label}</span>
+        </div>
+    }
+}
+
+/// Tiny line chart of `data` scaled to its own min/max, nothing with fewer than two points
+#[component]
+pub fn Sparkline(
+    data: Vec<f64>,
+    #[prop(default = 60)] width: u32,
+    #[prop(default = 16)] height: u32,
+) -> impl IntoView {
+    if data.len() < 2 {
+        return ().into_any();
+    }
+    let min = data.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = data.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let step = width as f64 / (data.len() - 1) as f64;
+    // one pixel of margin keeps the stroke inside the box
+    let usable_height = height as f64 - 2.0;
+    let points = data
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            // a flat series is drawn through the middle
+            let fraction = if max > min {
+                (value - min) / (max - min)
+            } else {
+                0.5
+            };
+            let y = 1.0 + usable_height * (1.0 - fraction);
+            format!("{:.1},{y:.1}", index as f64 * step)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    view! {
+        <svg width=width height=height viewBox=format!("0 0 {width} {height}") class="inline-block">
+            <polyline
+                points=points
+                fill="none"
+                stroke-width="1.5"
+                class="stroke-blue-500"
+            />
+        </svg>
+    }
+    .into_any()
+}
@@ -0,0 +1,164 @@
+use leptos::prelude::*;
+
+use crate::components::cache_info::CacheInfo;
+use crate::models::server_info::ParquetCacheUsage;
+use crate::utils::{format_bytes, i18n::t};
+
+/// Cache figures of one server, or the sum over all servers
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ServerCacheStats {
+    pub server: String,
+    pub memory_usage_bytes: u64,
+    pub disk_usage_bytes: u64,
+    pub max_cache_bytes: u64,
+    pub file_count: u64,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
+
+impl ServerCacheStats {
+    pub fn new(server: String, info: &CacheInfo, usage: Option<&ParquetCacheUsage>) -> Self {
+        Self {
+            server,
+            memory_usage_bytes: info.memory_usage_bytes,
+            disk_usage_bytes: info.disk_usage_bytes,
+            max_cache_bytes: info.max_cache_bytes,
+            file_count: usage.map_or(0, |usage| usage.file_count as u64),
+            cache_hits: info.cache_hits,
+            cache_misses: info.cache_misses,
+        }
+    }
+
+    fn total(servers: &[ServerCacheStats]) -> Self {
+        servers.iter().fold(
+            Self {
+                server: "Cluster total".to_string(),
+                ..Self::default()
+            },
+            |total, server| Self {
+                memory_usage_bytes: total.memory_usage_bytes + server.memory_usage_bytes,
+                disk_usage_bytes: total.disk_usage_bytes + server.disk_usage_bytes,
+                max_cache_bytes: total.max_cache_bytes + server.max_cache_bytes,
+                file_count: total.file_count + server.file_count,
+                cache_hits: total.cache_hits + server.cache_hits,
+                cache_misses: total.cache_misses + server.cache_misses,
+                ..total
+            },
+        )
+    }
+
+    /// Every figure divided by the number of servers
+    fn per_server(&self, servers: u64) -> Self {
+        let servers = servers.max(1);
+        Self {
+            server: "Per-server average".to_string(),
+            memory_usage_bytes: self.memory_usage_bytes / servers,
+            disk_usage_bytes: self.disk_usage_bytes / servers,
+            max_cache_bytes: self.max_cache_bytes / servers,
+            file_count: self.file_count / servers,
+            cache_hits: self.cache_hits / servers,
+            cache_misses: self.cache_misses / servers,
+        }
+    }
+
+    fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.cache_hits + self.cache_misses;
+        (lookups > 0).then(|| self.cache_hits as f64 / lookups as f64)
+    }
+
+    /// Memory used relative to the allowed cache size, like `CacheInfo::utilization`
+    fn utilization(&self) -> Option<f64> {
+        (self.max_cache_bytes > 0)
+            .then(|| self.memory_usage_bytes as f64 / self.max_cache_bytes as f64)
+    }
+}
+
+#[component]
+fn ServerCacheRow(stats: ServerCacheStats, #[prop(default = false)] total: bool) -> impl IntoView {
+    let percent = |value: Option<f64>| {
+        value.map_or("-".to_string(), |value| format!("{:.1}%", value * 100.0))
+    };
+
+    view! {
+        <tr class=if total {
+            "font-medium bg-gray-50 dark:bg-gray-800"
+        } else {
+            "border-b border-gray-50 dark:border-gray-800"
+        }>
+            <td class="py-1 truncate">{stats.server.clone()}</td>
+            <td class="py-1 font-mono">{format_bytes(stats.memory_usage_bytes)}</td>
+            <td class="py-1 font-mono">{format_bytes(stats.disk_usage_bytes)}</td>
+            <td class="py-1 font-mono">{format_bytes(stats.max_cache_bytes)}</td>
+            <td class="py-1 font-mono">{percent(stats.utilization())}</td>
+            <td class="py-1 font-mono">{stats.file_count}</td>
+            <td class="py-1 font-mono">{stats.cache_hits}</td>
+            <td class="py-1 font-mono">{stats.cache_misses}</td>
+            <td class="py-1 font-mono">{percent(stats.hit_rate())}</td>
+        </tr>
+    }
+}
+
+/// Cache figures of every connected server with a cluster total row
+#[component]
+pub fn ClusterSummary(#[prop(into)] servers: Signal<Vec<ServerCacheStats>>) -> impl IntoView {
+    let (normalize, set_normalize) = signal(false);
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4 mb-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("All servers")}</h2>
+                <label
+                    class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300"
+                    title="Show the per-server average instead of the cluster total"
+                >
+                    <input
+                        type="checkbox"
+                        prop:checked=normalize
+                        on:change=move |ev| set_normalize.set(event_target_checked(&ev))
+                    />
+                    "Normalize by server"
+                </label>
+            </div>
+            {move || {
+                let servers = servers.get();
+                if servers.is_empty() {
+                    return view! {
+                        <div class="text-gray-400 text-xs italic">"Connect to servers to compare their caches"</div>
+                    }
+                        .into_any();
+                }
+                let total = ServerCacheStats::total(&servers);
+                let total = if normalize.get() {
+                    total.per_server(servers.len() as u64)
+                } else {
+                    total
+                };
+                view! {
+                    <table class="w-full text-xs table-fixed">
+                        <thead>
+                            <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                <th class="py-1 font-normal w-40">"Server"</th>
+                                <th class="py-1 font-normal">"Memory"</th>
+                                <th class="py-1 font-normal">"Disk"</th>
+                                <th class="py-1 font-normal">"Allowed"</th>
+                                <th class="py-1 font-normal">"Utilization"</th>
+                                <th class="py-1 font-normal">"Files"</th>
+                                <th class="py-1 font-normal">"Hits"</th>
+                                <th class="py-1 font-normal">"Misses"</th>
+                                <th class="py-1 font-normal">"Hit rate"</th>
+                            </tr>
+                        </thead>
+                        <tbody class="text-gray-800 dark:text-gray-100">
+                            {servers
+                                .into_iter()
+                                .map(|stats| view! { <ServerCacheRow stats=stats /> })
+                                .collect_view()}
+                            <ServerCacheRow stats=total total=true />
+                        </tbody>
+                    </table>
+                }
+                    .into_any()
+            }}
+        </div>
+    }
+}
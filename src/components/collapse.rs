@@ -0,0 +1,64 @@
+use leptos::prelude::*;
+
+use crate::utils::i18n::t;
+
+/// Expansion of every collapsible section in a plan tree, set by "Expand All" / "Collapse All"
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CollapseMode {
+    /// Expand all sections
+    All,
+    /// Collapse all sections
+    None,
+    /// Each section uses its own default
+    #[default]
+    Default,
+}
+
+pub fn provide_collapse_mode() -> RwSignal<CollapseMode> {
+    let mode = RwSignal::new(CollapseMode::Default);
+    provide_context(mode);
+    mode
+}
+
+/// Expanded state of a section following the shared `CollapseMode`.
+/// Toggling the section overrides the mode until it is set again.
+pub fn use_collapsible(default_expanded: bool) -> (Signal<bool>, impl Fn() + Copy) {
+    let mode = use_context::<RwSignal<CollapseMode>>();
+    let local_override = RwSignal::new(None::<bool>);
+
+    Effect::new(move |_| {
+        if let Some(mode) = mode {
+            mode.track();
+        }
+        local_override.set(None);
+    });
+
+    let expanded = Signal::derive(move || {
+        local_override.get().unwrap_or_else(|| {
+            match mode.map(|mode| mode.get()).unwrap_or_default() {
+                CollapseMode::All => true,
+                CollapseMode::None => false,
+                CollapseMode::Default => default_expanded,
+            }
+        })
+    });
+    let toggle = move || local_override.set(Some(!expanded.get_untracked()));
+
+    (expanded, toggle)
+}
+
+/// One-line placeholder of a collapsed dashboard panel, click to expand it
+#[component]
+pub fn CollapsedPanel(title: &'static str, expanded: RwSignal<bool>) -> impl IntoView {
+    view! {
+        <Show when=move || !expanded.get()>
+            <button
+                class="w-full flex justify-between items-center border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 px-4 py-2 text-sm text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                on:click=move |_| expanded.set(true)
+            >
+                {move || t(title)}
+                <span class="text-xs text-gray-400">{move || format!("▸ {}", t("Expand"))}</span>
+            </button>
+        </Show>
+    }
+}
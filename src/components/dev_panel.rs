@@ -0,0 +1,100 @@
+use leptos::prelude::*;
+
+/// Rendering statistics of the plan tree, collected for the dev panel
+#[derive(Clone, Copy)]
+pub struct DevStats {
+    render_ms: RwSignal<Option<f64>>,
+    dom_nodes: RwSignal<usize>,
+    /// Plan node cards in the rendered tree
+    plan_nodes: RwSignal<usize>,
+}
+
+/// Sub-millisecond timestamp from `performance.now()`, falling back to `Date.now()`
+pub fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or_else(js_sys::Date::now)
+}
+
+impl DevStats {
+    /// Start timing a plan tree render, returns the start timestamp
+    pub fn begin_render(&self) -> f64 {
+        now_ms()
+    }
+
+    /// Finish timing a plan tree render started at `start` and count its DOM and plan nodes
+    pub fn finish_render(&self, start: f64, container: &web_sys::Element) {
+        self.render_ms.set(Some(now_ms() - start));
+        let count = |selector: &str| {
+            container
+                .query_selector_all(selector)
+                .map(|nodes| nodes.length() as usize)
+                .unwrap_or(0)
+        };
+        self.dom_nodes.set(count("*"));
+        self.plan_nodes.set(count("[data-node-path]"));
+    }
+}
+
+pub fn provide_dev_stats() -> DevStats {
+    let dev_stats = DevStats {
+        render_ms: RwSignal::new(None),
+        dom_nodes: RwSignal::new(0),
+        plan_nodes: RwSignal::new(0),
+    };
+    provide_context(dev_stats);
+    dev_stats
+}
+
+/// Dev stats are optional so plan components can render outside the dashboard
+pub fn use_dev_stats() -> Option<DevStats> {
+    use_context::<DevStats>()
+}
+
+/// Hidden panel for profiling the admin UI itself, toggled with Ctrl+Shift+D
+#[component]
+pub fn DevPanel() -> impl IntoView {
+    let dev_stats = use_dev_stats().expect("DevStats must be provided");
+    let (visible, set_visible) = signal(false);
+
+    let handle = window_event_listener(leptos::ev::keydown, move |ev| {
+        if ev.ctrl_key() && ev.shift_key() && ev.key().eq_ignore_ascii_case("d") {
+            ev.prevent_default();
+            set_visible.update(|v| *v = !*v);
+        }
+    });
+    on_cleanup(move || handle.remove());
+
+    view! {
+        <Show when=move || visible.get()>
+            <div class="fixed bottom-4 left-4 z-50 w-64 bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded-lg shadow-sm p-3 text-xs">
+                <div class="flex justify-between items-center mb-2">
+                    <span class="font-medium text-gray-700 dark:text-gray-200">"Dev panel"</span>
+                    <button
+                        class="text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                        on:click=move |_| set_visible.set(false)
+                    >
+                        "✕"
+                    </button>
+                </div>
+                <div class="grid grid-cols-2 gap-y-1">
+                    <span class="text-gray-500 dark:text-gray-400">"Plan render"</span>
+                    <span class="font-mono text-gray-800 dark:text-gray-100">
+                        {move || {
+                            dev_stats
+                                .render_ms
+                                .get()
+                                .map(|ms| format!("{ms:.2}ms"))
+                                .unwrap_or_else(|| "-".to_string())
+                        }}
+                    </span>
+                    <span class="text-gray-500 dark:text-gray-400">"DOM nodes"</span>
+                    <span class="font-mono text-gray-800 dark:text-gray-100">{move || dev_stats.dom_nodes.get()}</span>
+                    <span class="text-gray-500 dark:text-gray-400">"Plan nodes"</span>
+                    <span class="font-mono text-gray-800 dark:text-gray-100">{move || dev_stats.plan_nodes.get()}</span>
+                </div>
+            </div>
+        </Show>
+    }
+}
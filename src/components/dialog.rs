@@ -0,0 +1,65 @@
+use leptos::prelude::*;
+
+use crate::utils::i18n::t;
+use crate::utils::shortcuts::on_close_modals;
+
+/// Centered modal asking to confirm a destructive action, Escape or a click outside cancels.
+/// Cancel takes focus when the dialog opens, so Enter does not confirm by accident.
+#[component]
+pub fn ConfirmDialog(
+    title: &'static str,
+    message: &'static str,
+    on_confirm: Callback<()>,
+    on_cancel: Callback<()>,
+    /// Label of the confirm button
+    #[prop(default = "Confirm")]
+    confirm_label: &'static str,
+) -> impl IntoView {
+    let cancel_ref = NodeRef::<leptos::html::Button>::new();
+    Effect::new(move || {
+        if let Some(button) = cancel_ref.get() {
+            let _ = button.focus();
+        }
+    });
+    on_close_modals(move || on_cancel.run(()));
+
+    view! {
+        <div
+            class="fixed inset-0 z-50 flex items-center justify-center bg-black/20"
+            on:click=move |_| on_cancel.run(())
+        >
+            <div
+                role="alertdialog"
+                aria-modal="true"
+                tabindex="-1"
+                class="w-96 p-4 bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded shadow-lg space-y-3"
+                on:click=|ev| ev.stop_propagation()
+                on:keydown=move |ev| {
+                    if ev.key() == "Escape" {
+                        // handled here, the global shortcut would cancel a second time
+                        ev.stop_propagation();
+                        on_cancel.run(());
+                    }
+                }
+            >
+                <h3 class="text-sm font-medium text-gray-800 dark:text-gray-100">{title}</h3>
+                <p class="text-sm text-gray-600 dark:text-gray-300">{message}</p>
+                <div class="flex justify-end gap-2">
+                    <button
+                        node_ref=cancel_ref
+                        class="px-3 py-1 border border-gray-200 dark:border-gray-700 rounded text-sm text-gray-600 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                        on:click=move |_| on_cancel.run(())
+                    >
+                        {move || t("Cancel")}
+                    </button>
+                    <button
+                        class="px-3 py-1 border border-red-200 rounded text-sm text-red-600 hover:bg-red-50 transition-colors"
+                        on:click=move |_| on_confirm.run(())
+                    >
+                        {confirm_label}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,260 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::{
+    components::toast::use_toast,
+    utils::{fetch_api, format_timestamp, i18n::t},
+};
+
+const SEVERITIES: [&str; 4] = ["Error", "Warn", "Info", "Debug"];
+const CATEGORIES: [&str; 3] = ["Cache", "Execution", "Network"];
+
+/// Rows are rendered with fixed heights so only the visible window is in the DOM
+const ROW_HEIGHT_PX: f64 = 28.0;
+const DETAIL_HEIGHT_PX: f64 = 160.0;
+const VIEWPORT_HEIGHT_PX: f64 = 320.0;
+const OVERSCAN_PX: f64 = 10.0 * ROW_HEIGHT_PX;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ServerEvent {
+    pub id: u64,
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+    /// "Error", "Warn", "Info" or "Debug"
+    pub severity: String,
+    /// "Cache", "Execution" or "Network"
+    pub category: String,
+    pub message: String,
+    /// Additional event fields, shown when the row is expanded
+    #[serde(default)]
+    pub context: Option<serde_json::Value>,
+}
+
+fn severity_class(severity: &str) -> &'static str {
+    match severity.to_ascii_lowercase().as_str() {
+        "error" => "text-red-700 bg-red-50",
+        "warn" => "text-yellow-700 bg-yellow-50",
+        "info" => "text-blue-700 bg-blue-50",
+        _ => "text-gray-600 dark:text-gray-300 bg-gray-50 dark:bg-gray-800",
+    }
+}
+
+/// Parse the value of a datetime-local input into a unix timestamp in seconds
+fn parse_datetime_local(value: &str) -> Option<u64> {
+    let ms = js_sys::Date::parse(value);
+    (!ms.is_nan() && ms >= 0.0).then(|| (ms / 1000.0) as u64)
+}
+
+#[component]
+pub fn EventLog(server_address: ReadSignal<String>) -> impl IntoView {
+    let toast = use_toast();
+    let (events, set_events) = signal(None::<Vec<ServerEvent>>);
+    let (severity, set_severity) = signal(String::new());
+    let (category, set_category) = signal(String::new());
+    let (from_timestamp, set_from_timestamp) = signal(None::<u64>);
+    let (to_timestamp, set_to_timestamp) = signal(None::<u64>);
+    let (expanded_id, set_expanded_id) = signal(None::<u64>);
+    let (scroll_top, set_scroll_top) = signal(0.0);
+
+    let fetch_events = Action::new(move |_: &()| {
+        let address = server_address.get();
+        let toast = toast.clone();
+
+        async move {
+            match fetch_api::<Vec<ServerEvent>>(&format!("{address}/events")).await {
+                Ok(response) => {
+                    set_events.set(Some(response));
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to fetch events: {e}"));
+                }
+            }
+        }
+    });
+
+    let filtered_events = move || {
+        let severity = severity.get();
+        let category = category.get();
+        let from = from_timestamp.get();
+        let to = to_timestamp.get();
+        events.get().map(|events| {
+            events
+                .into_iter()
+                .filter(|event| {
+                    severity.is_empty() || event.severity.eq_ignore_ascii_case(&severity)
+                })
+                .filter(|event| {
+                    category.is_empty() || event.category.eq_ignore_ascii_case(&category)
+                })
+                .filter(|event| from.is_none_or(|from| event.timestamp >= from))
+                .filter(|event| to.is_none_or(|to| event.timestamp <= to))
+                .collect::<Vec<_>>()
+        })
+    };
+
+    let filter_select_class = "px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 focus:outline-none";
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Event Log")}</h2>
+                <button
+                    class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                    on:click=move |_| {
+                        fetch_events.dispatch(());
+                    }
+                >
+                    {move || t("Refresh")}
+                </button>
+            </div>
+            <div class="flex flex-wrap items-center gap-2 mb-3 text-xs text-gray-500 dark:text-gray-400">
+                <select
+                    class=filter_select_class
+                    on:change=move |ev| set_severity.set(event_target_value(&ev))
+                >
+                    <option value="">"All severities"</option>
+                    {SEVERITIES.map(|s| view! { <option value=s>{s}</option> })}
+                </select>
+                <select
+                    class=filter_select_class
+                    on:change=move |ev| set_category.set(event_target_value(&ev))
+                >
+                    <option value="">"All categories"</option>
+                    {CATEGORIES.map(|c| view! { <option value=c>{c}</option> })}
+                </select>
+                <label class="flex items-center gap-1">
+                    "From"
+                    <input
+                        type="datetime-local"
+                        class=filter_select_class
+                        on:change=move |ev| {
+                            set_from_timestamp.set(parse_datetime_local(&event_target_value(&ev)))
+                        }
+                    />
+                </label>
+                <label class="flex items-center gap-1">
+                    "To"
+                    <input
+                        type="datetime-local"
+                        class=filter_select_class
+                        on:change=move |ev| {
+                            set_to_timestamp.set(parse_datetime_local(&event_target_value(&ev)))
+                        }
+                    />
+                </label>
+            </div>
+            {move || match filtered_events() {
+                Some(events) => {
+                    // expanding a row only re-renders the visible window, the scroll container
+                    // and its position stay
+                    let events = Arc::new(events);
+                    let total_height = {
+                        let events = events.clone();
+                        move || {
+                            let expanded_id = expanded_id.get();
+                            events.len() as f64 * ROW_HEIGHT_PX
+                                + if events.iter().any(|event| Some(event.id) == expanded_id) {
+                                    DETAIL_HEIGHT_PX
+                                } else {
+                                    0.0
+                                }
+                        }
+                    };
+                    let total_events = events.len();
+                    view! {
+                        <div class="text-xs text-gray-500 dark:text-gray-400 mb-1">
+                            {format!("{total_events} events")}
+                        </div>
+                        <div
+                            class="overflow-y-auto border border-gray-100 dark:border-gray-800 rounded"
+                            style=format!("height: {VIEWPORT_HEIGHT_PX}px")
+                            on:scroll=move |ev| {
+                                set_scroll_top.set(event_target::<web_sys::Element>(&ev).scroll_top() as f64)
+                            }
+                        >
+                            <div class="relative" style=move || format!("height: {}px", total_height())>
+                                {move || {
+                                    let expanded_id = expanded_id.get();
+                                    let window_start = scroll_top.get() - OVERSCAN_PX;
+                                    let window_end = scroll_top.get() + VIEWPORT_HEIGHT_PX + OVERSCAN_PX;
+                                    let mut top = 0.0;
+                                    let mut first_visible_top = None;
+                                    let mut visible = Vec::new();
+                                    for event in events.iter() {
+                                        let height = ROW_HEIGHT_PX
+                                            + if Some(event.id) == expanded_id { DETAIL_HEIGHT_PX } else { 0.0 };
+                                        if top + height >= window_start && top <= window_end {
+                                            first_visible_top.get_or_insert(top);
+                                            visible.push(event.clone());
+                                        }
+                                        top += height;
+                                    }
+                                    view! {
+                                        <div
+                                            class="absolute inset-x-0"
+                                            style=format!("top: {}px", first_visible_top.unwrap_or(0.0))
+                                        >
+                                            {visible
+                                                .into_iter()
+                                                .map(|event| view! { <EventRow event=event expanded_id=expanded_id set_expanded_id=set_expanded_id /> })
+                                                .collect_view()}
+                                        </div>
+                                    }
+                                }}
+                            </div>
+                        </div>
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <div class="text-gray-400 text-xs italic">"Refresh to load server events"</div>
+                    }
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn EventRow(
+    event: ServerEvent,
+    expanded_id: Option<u64>,
+    set_expanded_id: WriteSignal<Option<u64>>,
+) -> impl IntoView {
+    let id = event.id;
+    let is_expanded = expanded_id == Some(id);
+    let context = serde_json::to_string_pretty(&event.context.unwrap_or(serde_json::Value::Null))
+        .unwrap_or_default();
+
+    view! {
+        <div
+            class="flex items-center gap-2 px-2 text-xs border-b border-gray-50 dark:border-gray-800 cursor-pointer hover:bg-gray-50 dark:hover:bg-gray-800"
+            style=format!("height: {ROW_HEIGHT_PX}px")
+            on:click=move |_| set_expanded_id.set(if is_expanded { None } else { Some(id) })
+        >
+            <span class="w-16 font-mono text-gray-500 dark:text-gray-400">{format_timestamp(event.timestamp)}</span>
+            <span class=format!(
+                "w-12 text-center rounded px-1 {}",
+                severity_class(&event.severity),
+            )>{event.severity.clone()}</span>
+            <span class="w-20 text-gray-600 dark:text-gray-300">{event.category.clone()}</span>
+            <span class="flex-1 text-gray-800 dark:text-gray-100 truncate" title=event.message.clone()>
+                {event.message.clone()}
+            </span>
+        </div>
+        {is_expanded
+            .then(|| {
+                view! {
+                    <pre
+                        class="px-2 py-1 text-xs font-mono text-gray-700 dark:text-gray-200 bg-gray-50 dark:bg-gray-800 border-b border-gray-100 dark:border-gray-800 overflow-auto"
+                        style=format!("height: {DETAIL_HEIGHT_PX}px")
+                    >
+                        {context}
+                    </pre>
+                }
+            })}
+    }
+}
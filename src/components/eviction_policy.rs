@@ -0,0 +1,204 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::{
+    components::toast::use_toast,
+    utils::{fetch_api, fetch_api_with_method, i18n::t, ApiResponse},
+};
+
+/// Policies the server can be switched to
+const POLICIES: [&str; 3] = ["LRU", "LFU", "ARC"];
+/// Entries of the LRU queue drawn, the rest are summarized as a count
+const MAX_QUEUE_ENTRIES: usize = 8;
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EvictionPolicyInfo {
+    /// Policy name, e.g. "LRU", "LFU" or "ARC"
+    pub policy: String,
+    /// Policy specific parameters, sorted by key for display
+    #[serde(default)]
+    pub params: BTreeMap<String, String>,
+    /// Keys of the LRU queue, most recently used first, only reported by LRU
+    #[serde(default)]
+    pub lru_queue: Option<Vec<String>>,
+}
+
+/// The LRU queue from the most recently used entry to the next one to be evicted
+#[component]
+fn LruQueueDiagram(queue: Vec<String>) -> impl IntoView {
+    let hidden = queue.len().saturating_sub(MAX_QUEUE_ENTRIES);
+    view! {
+        <div class="flex items-center gap-2 text-xs mt-3">
+            <span class="shrink-0 text-gray-500 dark:text-gray-400">"Most recent"</span>
+            <div class="flex flex-1 min-w-0 gap-1">
+                {if queue.is_empty() {
+                    view! { <span class="text-gray-400 italic">"Queue is empty"</span> }.into_any()
+                } else {
+                    queue
+                        .into_iter()
+                        .take(MAX_QUEUE_ENTRIES)
+                        .map(|key| {
+                            view! {
+                                <span
+                                    class="flex-1 min-w-0 truncate px-1 py-0.5 rounded bg-blue-50 dark:bg-blue-950 text-blue-700 dark:text-blue-300 font-mono"
+                                    title=key.clone()
+                                >
+                                    {key.clone()}
+                                </span>
+                            }
+                        })
+                        .collect_view()
+                        .into_any()
+                }}
+                {(hidden > 0)
+                    .then(|| {
+                        view! {
+                            <span class="shrink-0 text-gray-400">{format!("+{hidden} more")}</span>
+                        }
+                    })}
+            </div>
+            <span class="shrink-0 text-gray-500 dark:text-gray-400">"Least recent → evicted"</span>
+        </div>
+    }
+}
+
+/// Eviction policy of the connected server, with buttons to switch it
+#[component]
+pub fn EvictionPolicy(connected_address: ReadSignal<Option<String>>) -> impl IntoView {
+    let toast = use_toast();
+    let (info, set_info) = signal(None::<EvictionPolicyInfo>);
+
+    let fetch_policy = {
+        let toast = toast.clone();
+        Action::new(move |_: &()| {
+            let address = connected_address.get_untracked();
+            let toast = toast.clone();
+
+            async move {
+                let Some(address) = address else {
+                    return;
+                };
+                match fetch_api::<EvictionPolicyInfo>(&format!("{address}/eviction_policy")).await {
+                    Ok(response) => {
+                        set_info.set(Some(response));
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to fetch eviction policy: {e}"));
+                    }
+                }
+            }
+        })
+    };
+
+    let set_policy = Action::new(move |policy: &&'static str| {
+        let address = connected_address.get_untracked();
+        let policy = *policy;
+        let toast = toast.clone();
+
+        async move {
+            let Some(address) = address else {
+                return;
+            };
+            match fetch_api_with_method::<ApiResponse>(
+                gloo_net::http::Method::PUT,
+                &format!("{address}/eviction_policy?policy={policy}"),
+            )
+            .await
+            {
+                Ok(response) => {
+                    toast.show_success(response.message);
+                    fetch_policy.dispatch(());
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to switch eviction policy to {policy}: {e}"));
+                }
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        let connected = connected_address.with(Option::is_some);
+        set_info.set(None);
+        if connected {
+            fetch_policy.dispatch(());
+        }
+    });
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Eviction policy")}</h2>
+                <button
+                    class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                    on:click=move |_| {
+                        fetch_policy.dispatch(());
+                    }
+                >
+                    {move || t("Refresh")}
+                </button>
+            </div>
+            {move || match info.get() {
+                Some(info) => {
+                    let lru_queue = info
+                        .lru_queue
+                        .filter(|_| info.policy.eq_ignore_ascii_case("LRU"));
+                    view! {
+                        <div class="text-2xl font-mono text-gray-800 dark:text-gray-100 mb-2">
+                            {info.policy.clone()}
+                        </div>
+                        <div class="grid grid-cols-4 gap-y-1 text-xs">
+                            {info
+                                .params
+                                .into_iter()
+                                .map(|(key, value)| {
+                                    view! {
+                                        <span class="text-gray-500 dark:text-gray-400">{key}</span>
+                                        <span class="text-gray-800 dark:text-gray-100 font-mono truncate">
+                                            {value}
+                                        </span>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                        {lru_queue.map(|queue| view! { <LruQueueDiagram queue=queue /> })}
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <div class="text-gray-400 text-xs italic">"Eviction policy not loaded"</div>
+                    }
+                        .into_any()
+                }
+            }}
+            <div class="flex items-center gap-2 mt-3 text-xs">
+                <span class="text-gray-500 dark:text-gray-400">"Switch to"</span>
+                {POLICIES
+                    .into_iter()
+                    .map(|policy| {
+                        let is_current = move || {
+                            info.with(|info| {
+                                info.as_ref().is_some_and(|info| info.policy.eq_ignore_ascii_case(policy))
+                            })
+                        };
+                        view! {
+                            <button
+                                class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors disabled:opacity-50 disabled:cursor-default"
+                                disabled=move || {
+                                    is_current() || set_policy.pending().get()
+                                        || connected_address.with(Option::is_none)
+                                }
+                                on:click=move |_| {
+                                    set_policy.dispatch(policy);
+                                }
+                            >
+                                {policy}
+                            </button>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+}
@@ -1,77 +1,969 @@
-use leptos::prelude::*;
+use leptos::{logging, prelude::*};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::components::bookmarked_metrics::BookmarkedMetrics;
+use crate::components::cache_operators::CacheOperatorSummary;
+use crate::components::charts::Sparkline;
+use crate::components::collapse::{provide_collapse_mode, use_collapsible, CollapseMode};
+use crate::components::dev_panel::use_dev_stats;
 use crate::components::flamegraph::Flamegraph;
+use crate::components::memory_layout::MemoryLayout;
+use crate::components::metric_diff::MetricDiffBadge;
+use crate::components::node_detail_drawer::{
+    provide_selected_node, use_selected_node, NodeDetailDrawer,
+};
+use crate::components::plan_playback::PlanPlayback;
+use crate::components::radar_chart::{radar_comparable, RadarChart};
+use crate::components::settings::{use_settings, PlanSortOrder};
+use crate::components::spinner::Spinner;
 use crate::components::statistics::StatisticsComponent;
-use crate::models::execution_plan::{ExecutionPlanWithStats, ExecutionStatsWithPlan};
-use crate::utils::{format_bytes, format_duration, format_number, format_timestamp};
+use crate::components::time_breakdown::TimeBreakdownBar;
+use crate::components::toast::use_toast;
+use crate::components::tree_diff::PlanTreeDiff;
+use crate::components::type_histogram::TypeHistogram;
+use crate::components::waterfall_compare::WaterfallCompare;
+use crate::components::zoomable_plan_view::{use_plan_view_pointer, ZoomablePlanView};
+use crate::models::diff::{CompareNode, DiffMetric};
+use crate::models::execution_plan::{
+    check_cardinality_consistency, check_union_compatibility, compute_relative_times,
+    find_critical_path, is_partitioned_operator, node_id, percentile_in_class,
+    schema_width_history, ColumnStatistics, ExecutionPlanWithStats,
+    ExecutionStats as ExecutionStatsData, ExecutionStatsWithPlan, PlanInfo,
+};
+use crate::models::metric_history::{use_metric_history, ANOMALY_ZSCORE};
+use crate::models::traffic_history::use_traffic_history;
+use crate::utils::shortcuts::{scoped_id, PLAN_SELECTOR_ID};
+use crate::utils::{
+    copy_to_clipboard, download_text_file, format_bytes, format_metric_value, format_relative_time,
+    format_timestamp, group_by_prefix,
+    highlight::highlight_sql,
+    i18n::t,
+    influx_export::to_influx_line_protocol,
+    parse_metric_value,
+    plan_export::{render_plan_dot, render_plan_mermaid},
+    plan_text::render_plan_text,
+    plan_traversal::{identify_clusters, NodeCluster},
+    scale_metric_value,
+    sql_format::{extract_clauses, CLAUSES},
+    stats::is_significant_change,
+    storage, suggest_dictionary_encoding, OTHER_PLAN_GROUP,
+};
 
 type RefreshCallback = Box<dyn Fn() + 'static>;
+
+/// Downloadable snapshot of one plan and its execution
+#[derive(Serialize)]
+struct PlanExport<'a> {
+    created_at: u64,
+    display_name: &'a str,
+    user_sql: &'a str,
+    network_traffic_bytes: u64,
+    execution_time_ms: u64,
+    plan: &'a ExecutionPlanWithStats,
+}
+
+fn export_plan_json(stats: &ExecutionStatsData, plan_info: &PlanInfo) {
+    let export = PlanExport {
+        created_at: plan_info.created_at,
+        display_name: &stats.display_name,
+        user_sql: &stats.user_sql,
+        network_traffic_bytes: stats.network_traffic_bytes,
+        execution_time_ms: stats.execution_time_ms,
+        plan: &plan_info.plan,
+    };
+    match serde_json::to_string_pretty(&export) {
+        Ok(json) => download_text_file(
+            &format!("plan-{}.json", plan_info.id),
+            &json,
+            "application/json",
+        ),
+        Err(e) => logging::error!("Failed to serialize plan {}: {}", plan_info.id, e),
+    }
+}
+
+/// User SQL split into collapsible clauses, or as a single block if it can't be split
+#[component]
+fn SqlViewer(sql: String) -> impl IntoView {
+    let clauses = extract_clauses(&sql);
+    if clauses.is_empty() {
+        return view! {
+            <div class="bg-gray-50 dark:bg-gray-800 rounded p-3 border max-h-48 overflow-y-auto">
+                <pre
+                    class="text-xs font-mono text-gray-800 dark:text-gray-100 whitespace-pre-wrap overflow-x-auto"
+                    inner_html=highlight_sql(&sql)
+                ></pre>
+            </div>
+        }
+        .into_any();
+    }
+
+    view! {
+        <div class="bg-gray-50 dark:bg-gray-800 rounded border max-h-64 overflow-y-auto divide-y divide-gray-100">
+            {CLAUSES
+                .iter()
+                .filter_map(|clause| clauses.get(clause).map(|body| (*clause, body.to_string())))
+                .map(|(clause, body)| {
+                    view! {
+                        <details open=true class="px-3 py-1">
+                            <summary class="text-xs font-mono font-medium text-blue-700 cursor-pointer select-none">
+                                {clause}
+                            </summary>
+                            <pre
+                                class="pl-4 text-xs font-mono text-gray-800 dark:text-gray-100 whitespace-pre-wrap overflow-x-auto"
+                                inner_html=highlight_sql(&body)
+                            ></pre>
+                        </details>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+        .into_any()
+}
+
+/// How long the checkmark replaces the clipboard icon after copying
+const COPIED_ICON_MS: u32 = 1_500;
+
+/// Clipboard icon in the top-right corner of the SQL viewer
+#[component]
+fn SqlCopyButton(sql: String) -> impl IntoView {
+    let toast = use_toast();
+    let (copied, set_copied) = signal(false);
+    let copy = move |_| {
+        let sql = sql.clone();
+        let toast = toast.clone();
+        leptos::task::spawn_local(async move {
+            match copy_to_clipboard(&sql).await {
+                Ok(()) => {
+                    toast.show_success("SQL copied to clipboard".to_string());
+                    set_copied.set(true);
+                    gloo_timers::future::TimeoutFuture::new(COPIED_ICON_MS).await;
+                    set_copied.set(false);
+                }
+                Err(e) => toast.show_error(format!("Failed to copy SQL: {e}")),
+            }
+        });
+    };
+
+    view! {
+        <button
+            class="absolute top-2 right-2 p-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+            title="Copy SQL"
+            aria-label="Copy SQL"
+            on:click=copy
+        >
+            <svg class="w-3 h-3" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                {move || {
+                    if copied.get() {
+                        view! {
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                stroke-width="2"
+                                d="M5 13l4 4L19 7"
+                            ></path>
+                        }
+                            .into_any()
+                    } else {
+                        view! {
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                stroke-width="2"
+                                d="M8 16H6a2 2 0 01-2-2V6a2 2 0 012-2h8a2 2 0 012 2v2m-6 12h8a2 2 0 002-2v-8a2 2 0 00-2-2h-8a2 2 0 00-2 2v8a2 2 0 002 2z"
+                            ></path>
+                        }
+                            .into_any()
+                    }
+                }}
+            </svg>
+        </button>
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PlanView {
+    Visual,
+    Text,
+}
+
+/// EXPLAIN-style text of a plan with a copy button
+#[component]
+fn PlanTextView(text: String) -> impl IntoView {
+    let toast = use_toast();
+    let copy = {
+        let text = text.clone();
+        move |_| {
+            let text = text.clone();
+            let toast = toast.clone();
+            leptos::task::spawn_local(async move {
+                match copy_to_clipboard(&text).await {
+                    Ok(()) => toast.show_success("Plan copied to clipboard".to_string()),
+                    Err(e) => toast.show_error(format!("Failed to copy plan: {e}")),
+                }
+            });
+        }
+    };
+
+    view! {
+        <div class="relative">
+            <button
+                class="absolute top-2 right-2 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                on:click=copy
+            >
+                "Copy to clipboard"
+            </button>
+            <pre class="bg-gray-50 dark:bg-gray-800 rounded p-3 border max-h-96 overflow-auto text-xs font-mono text-gray-800 dark:text-gray-100">
+                {text}
+            </pre>
+        </div>
+    }
+}
+
+/// Display name of the plan selected last, pre-selected after a page refresh
+pub const LAST_PLAN_ID_KEY: &str = "liquid_cache_admin.last_plan_id";
+
+/// Seconds between updates of "n minutes ago" labels
+const RELATIVE_TIME_REFRESH_S: u64 = 30;
+
+/// Seconds between taking a snapshot and refreshing, time to re-run the tuned query
+const SNAPSHOT_REFRESH_DELAY_S: u32 = 5;
+
+/// "Snapshot + Compare" workflow: keep the current execution, refresh after a delay and
+/// compare the fresh plan against the snapshot. Outlives the plan list, which is
+/// re-created on every refresh.
+#[derive(Clone, Copy)]
+pub struct SnapshotCompare {
+    snapshot: RwSignal<Option<ExecutionStatsWithPlan>>,
+    /// Seconds until the refresh, None once it was triggered
+    countdown: RwSignal<Option<u32>>,
+    /// Refresh triggered, the next plan list enters compare mode
+    waiting_for_plans: RwSignal<bool>,
+    refresh: Action<(), ()>,
+}
+
+impl SnapshotCompare {
+    fn start(&self, stats: ExecutionStatsWithPlan) {
+        self.snapshot.set(Some(stats));
+        self.countdown.set(Some(SNAPSHOT_REFRESH_DELAY_S));
+        let this = *self;
+        let timer = StoredValue::new(None::<IntervalHandle>);
+        let handle = set_interval_with_handle(
+            move || match this.countdown.get_untracked() {
+                Some(seconds) if seconds > 1 => this.countdown.set(Some(seconds - 1)),
+                _ => {
+                    this.countdown.set(None);
+                    this.waiting_for_plans.set(true);
+                    this.refresh.dispatch(());
+                    if let Some(handle) = timer.get_value() {
+                        handle.clear();
+                    }
+                }
+            },
+            std::time::Duration::from_secs(1),
+        );
+        timer.set_value(handle.ok());
+    }
+
+    fn is_waiting(&self) -> bool {
+        self.countdown.with(Option::is_some)
+            || (self.waiting_for_plans.get() && self.refresh.pending().get())
+    }
+
+    /// The snapshot to compare against, if a refresh was just triggered for it
+    fn take_pending(&self) -> Option<ExecutionStatsWithPlan> {
+        if !self.waiting_for_plans.get_untracked() {
+            return None;
+        }
+        self.waiting_for_plans.set(false);
+        self.snapshot.get_untracked()
+    }
+}
+
+/// `refresh` reloads the execution plans
+pub fn provide_snapshot_compare(refresh: Action<(), ()>) -> SnapshotCompare {
+    let snapshot_compare = SnapshotCompare {
+        snapshot: RwSignal::new(None),
+        countdown: RwSignal::new(None),
+        waiting_for_plans: RwSignal::new(false),
+        refresh,
+    };
+    provide_context(snapshot_compare);
+    snapshot_compare
+}
+
+/// All executions in the selector, used to rank metrics within an operator class
+#[derive(Clone)]
+struct LoadedExecutions(Arc<Vec<ExecutionStatsWithPlan>>);
+
+/// Display name of the execution being rendered, scopes metric annotations
+#[derive(Clone)]
+struct PlanDisplayName(String);
+
+/// Address of the server the rendered executions came from
+#[derive(Clone, Copy)]
+pub struct PlanServerAddress(pub Signal<String>);
+
+/// "1st", "2nd", "3rd", "11th", ...
+fn ordinal(n: u32) -> String {
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+#[component]
+fn MetricCell(
+    node_name: String,
+    label: String,
+    raw: String,
+    raw_value: Option<f64>,
+    execution_count: u32,
+    per_execution: ReadSignal<bool>,
+    plan_id: String,
+    node_path: String,
+    plan_ids: Arc<Vec<String>>,
+    /// Difference to the same metric of the compared plan
+    #[prop(default = None)]
+    diff: Option<DiffMetric>,
+    /// Whether `diff` is significant, None when untested
+    #[prop(default = None)]
+    significant: Option<bool>,
+) -> impl IntoView {
+    let metric_history = use_metric_history();
+    let settings = use_settings();
+    let percentile_badge = use_context::<LoadedExecutions>()
+        .filter(|loaded| loaded.0.len() > 1)
+        .zip(raw_value)
+        .map(|(loaded, value)| {
+            let percentile = percentile_in_class(&loaded.0, &node_name, &label, value);
+            format!(
+                "{} pct for {node_name}.{label}",
+                ordinal(percentile.round() as u32)
+            )
+        });
+
+    let annotation_key = use_context::<PlanDisplayName>()
+        .map(|name| format!("annotation:{}:{node_path}:{label}", name.0));
+    let annotation = RwSignal::new(annotation_key.as_deref().and_then(storage::get::<String>));
+    let (editing, set_editing) = signal(false);
+    let (draft, set_draft) = signal(String::new());
+    let annotation_input = NodeRef::<leptos::html::Input>::new();
+    Effect::new(move || {
+        if let Some(input) = annotation_input.get() {
+            let _ = input.focus();
+        }
+    });
+    let can_annotate = annotation_key.is_some();
+    let start_editing = move |_| {
+        if can_annotate && !editing.get_untracked() {
+            set_draft.set(annotation.get_untracked().unwrap_or_default());
+            set_editing.set(true);
+        }
+    };
+    let on_annotation_keydown = move |ev: web_sys::KeyboardEvent| match ev.key().as_str() {
+        "Enter" => {
+            let Some(key) = annotation_key.as_deref() else {
+                return;
+            };
+            let text = draft.get_untracked().trim().to_string();
+            if text.is_empty() {
+                storage::remove(key);
+                annotation.set(None);
+            } else {
+                storage::set(key, &text);
+                annotation.set(Some(text));
+            }
+            set_editing.set(false);
+        }
+        "Escape" => set_editing.set(false),
+        _ => {}
+    };
+
+    let is_bookmarked = {
+        let node_name = node_name.clone();
+        let label = label.clone();
+        move || settings.is_bookmarked(&node_name, &label)
+    };
+    let toggle_bookmark = {
+        let label = label.clone();
+        move |ev: web_sys::MouseEvent| {
+            // don't open the annotation editor of the cell
+            ev.stop_propagation();
+            settings.toggle_bookmark(&node_name, &label)
+        }
+    };
+
+    let value = {
+        let label = label.clone();
+        move || {
+            if per_execution.get() && label != "count" {
+                format_metric_value(&label, &scale_metric_value(&raw, execution_count))
+            } else {
+                format_metric_value(&label, &raw)
+            }
+        }
+    };
+    let is_anomaly = {
+        let label = label.clone();
+        move || {
+            raw_value
+                .and_then(|current| {
+                    metric_history.zscore(&plan_ids, &plan_id, &node_path, &label, current)
+                })
+                .is_some_and(|z| z.abs() > ANOMALY_ZSCORE)
+        }
+    };
+
+    view! {
+        <div
+            class="bg-gray-50 dark:bg-gray-800 rounded p-2 cursor-text"
+            title="Click to annotate"
+            on:click=start_editing
+        >
+            <div class="flex items-start justify-between gap-1">
+                <div class="text-xs text-gray-500 dark:text-gray-400">{label}</div>
+                <button
+                    class=move || {
+                        if is_bookmarked() {
+                            "text-xs text-yellow-500"
+                        } else {
+                            "text-xs text-gray-300 hover:text-yellow-500"
+                        }
+                    }
+                    title="Bookmark this metric for all nodes of this type"
+                    on:click=toggle_bookmark
+                >
+                    {
+                        let is_bookmarked = is_bookmarked.clone();
+                        move || if is_bookmarked() { "★" } else { "☆" }
+                    }
+                </button>
+            </div>
+            <Show when=is_anomaly>
+                <span
+                    class="text-xs text-amber-700 bg-amber-50 rounded px-1"
+                    title="More than 2σ from the mean of other plan versions"
+                >
+                    "⚠ Anomaly"
+                </span>
+            </Show>
+            <div class="text-xs font-mono text-gray-800 dark:text-gray-100 truncate" title=value.clone()>
+                {value.clone()}
+            </div>
+            {diff.map(|diff| view! { <MetricDiffBadge diff=diff significant=significant /> })}
+            {percentile_badge
+                .map(|badge| {
+                    view! {
+                        <div
+                            class="text-xs text-gray-500 dark:text-gray-400 truncate"
+                            title=format!("{badge} across all loaded plans")
+                        >
+                            {badge.clone()}
+                        </div>
+                    }
+                })}
+            {move || {
+                if editing.get() {
+                    view! {
+                        <input
+                            type="text"
+                            node_ref=annotation_input
+                            placeholder="Annotation, Enter to save"
+                            class="w-full mt-1 px-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900 focus:outline-none focus:border-gray-400"
+                            prop:value=draft
+                            on:input=move |ev| set_draft.set(event_target_value(&ev))
+                            on:keydown=on_annotation_keydown.clone()
+                            on:blur=move |_| set_editing.set(false)
+                        />
+                    }
+                        .into_any()
+                } else {
+                    annotation
+                        .get()
+                        .map(|text| {
+                            view! {
+                                <div class="text-xs italic text-gray-500 dark:text-gray-400 truncate" title=text.clone()>
+                                    {text.clone()}
+                                </div>
+                            }
+                        })
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+/// Schema field count across plan versions, green when columns get pruned over time
+#[component]
+fn SchemaWidthSparkline(history: Vec<(u64, usize)>) -> impl IntoView {
+    const WIDTH: f64 = 40.0;
+    const HEIGHT: f64 = 10.0;
+
+    if history.len() < 2 {
+        return ().into_any();
+    }
+    let (first, last) = (history[0].1, history[history.len() - 1].1);
+    let max = history
+        .iter()
+        .map(|(_, width)| *width)
+        .max()
+        .unwrap_or(1)
+        .max(1) as f64;
+    let step = WIDTH / (history.len() - 1) as f64;
+    let points = history
+        .iter()
+        .enumerate()
+        .map(|(i, (_, width))| {
+            format!(
+                "{:.1},{:.1}",
+                i as f64 * step,
+                HEIGHT - *width as f64 / max * HEIGHT
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let color = if last < first {
+        "stroke-green-600"
+    } else if last > first {
+        "stroke-red-500"
+    } else {
+        "stroke-gray-400"
+    };
+
+    view! {
+        <svg
+            class="ml-1"
+            width=WIDTH
+            height=HEIGHT
+            viewBox=format!("0 0 {WIDTH} {HEIGHT}")
+            preserveAspectRatio="none"
+        >
+            <title>{format!("Schema width over plan versions: {first} → {last} fields")}</title>
+            <polyline class=color fill="none" stroke-width="1" points=points />
+        </svg>
+    }
+    .into_any()
+}
+
+/// (card background, label) classes of plan clusters, cycled by cluster index
+const CLUSTER_COLORS: [(&str, &str); 4] = [
+    (
+        "bg-purple-50 dark:bg-purple-950",
+        "bg-purple-100 text-purple-700 dark:bg-purple-900 dark:text-purple-200",
+    ),
+    (
+        "bg-teal-50 dark:bg-teal-950",
+        "bg-teal-100 text-teal-700 dark:bg-teal-900 dark:text-teal-200",
+    ),
+    (
+        "bg-pink-50 dark:bg-pink-950",
+        "bg-pink-100 text-pink-700 dark:bg-pink-900 dark:text-pink-200",
+    ),
+    (
+        "bg-sky-50 dark:bg-sky-950",
+        "bg-sky-100 text-sky-700 dark:bg-sky-900 dark:text-sky-200",
+    ),
+];
+
+/// Border color of a node by its share of the plan's total elapsed time
+fn relative_time_border(relative_time: Option<f64>) -> &'static str {
+    match relative_time {
+        None => "border-gray-200 dark:border-gray-700",
+        Some(share) if share < 0.1 => "border-green-300",
+        Some(share) if share < 0.25 => "border-yellow-300",
+        Some(share) if share < 0.5 => "border-orange-400",
+        Some(_) => "border-red-500",
+    }
+}
+
+/// Legend of the node border colors
+#[component]
+fn RelativeTimeLegend() -> impl IntoView {
+    view! {
+        <div class="flex items-center gap-2 text-xs text-gray-500 dark:text-gray-400 mb-3">
+            <span>"Share of elapsed time:"</span>
+            {[
+                ("border-green-300", "< 10%"),
+                ("border-yellow-300", "< 25%"),
+                ("border-orange-400", "< 50%"),
+                ("border-red-500", "≥ 50%"),
+            ]
+                .map(|(border, label)| {
+                    view! {
+                        <span class="flex items-center gap-1">
+                            <span class=format!("w-3 h-3 rounded border-2 {border}")></span>
+                            {label}
+                        </span>
+                    }
+                })}
+        </div>
+    }
+}
+
+/// Number of children rendered before the "more children" pill
+const INITIAL_VISIBLE_CHILDREN: usize = 5;
+
+/// Width of a node card showing `metrics_count` metrics
+fn card_width_class(metrics_count: usize) -> &'static str {
+    match metrics_count {
+        0..=4 => "min-w-48 max-w-64",
+        5..=8 => "min-w-64 max-w-80",
+        _ => "min-w-96 max-w-[32rem]",
+    }
+}
+
 #[component]
-fn ExecutionPlanNodeComponent(node: ExecutionPlanWithStats) -> impl IntoView {
-    let (expand_schema, set_expanded) = signal(true);
+fn ExecutionPlanNodeComponent(
+    node: ExecutionPlanWithStats,
+    /// Position of the node in the tree, e.g. "0.1.0"
+    node_path: String,
+    /// ID of the plan this node belongs to
+    plan_id: String,
+    /// IDs of all plan versions of the same execution
+    plan_ids: Arc<Vec<String>>,
+    /// All plan versions of the same execution
+    plan_versions: Arc<Vec<PlanInfo>>,
+    /// Whether metrics are divided by the execution count
+    per_execution: ReadSignal<bool>,
+    /// Two metric columns instead of four
+    compact_cards: ReadSignal<bool>,
+    /// Case-insensitive node name filter, non-matching nodes are dimmed
+    filter_text: ReadSignal<String>,
+    /// Column statistics of the parent node, empty for the root
+    parent_column_statistics: Vec<ColumnStatistics>,
+    /// Names of the nodes from the root down to the parent, empty for the root
+    ancestors: Vec<String>,
+    /// Share of the plan's total elapsed time spent in this node
+    relative_time: Option<f64>,
+    /// Relative times of all nodes of the plan, keyed by node path
+    relative_times: Arc<HashMap<String, f64>>,
+    /// Node IDs of the slowest root-to-leaf path
+    critical_path: Arc<Vec<String>>,
+    /// Groups of related operators, highlighted with a shared background
+    clusters: Arc<Vec<NodeCluster>>,
+    /// Node of the other plan in compare mode, aligned by name
+    #[prop(default = None)]
+    compare_node: Option<CompareNode>,
+) -> impl IntoView {
+    let (expand_schema, toggle_schema) = use_collapsible(true);
+    let schema_width_history = schema_width_history(&plan_versions, &node_path);
 
     let has_children = !node.children.is_empty();
+    let children_count = node.children.len();
+    let children = StoredValue::new(node.children.clone());
+    let cardinality_warnings = StoredValue::new(
+        node.children
+            .iter()
+            .map(|child| check_cardinality_consistency(&node, child))
+            .collect::<Vec<_>>(),
+    );
+    let child_compare_nodes = StoredValue::new(
+        compare_node
+            .as_ref()
+            .map(|compare_node| compare_node.align_children(&node.children))
+            .unwrap_or_default(),
+    );
+    let metric_history = use_metric_history();
+    let selected_node = use_selected_node();
+    let plan_view_pointer = use_plan_view_pointer();
+    // Wide plans (e.g. large UNION ALL) only render the first few children until expanded
+    let (visible_children_count, set_visible_children_count) =
+        signal(children_count.min(INITIAL_VISIBLE_CHILDREN));
+    let visible_children_count: Signal<usize> = visible_children_count.into();
+    let hidden_children_count = move || children_count - visible_children_count.get();
+    let fan_out = node.fan_out();
+
+    let execution_count = node.execution_count.unwrap_or(1);
 
     // Display all metrics from the backend
-    let mut all_metrics: Vec<(String, String)> = node
+    let mut all_metrics: Vec<(String, String, Option<f64>)> = node
         .metrics
         .iter()
         .map(|metric| {
-            let key = &metric.name;
-            let value = &metric.value;
-            let formatted_value = if key.contains("time") || key.contains("elapsed") {
-                format_duration(value)
-            } else if key.contains("bytes") {
-                format_bytes(value.parse::<u64>().unwrap_or(0))
-            } else if key.contains("rows") {
-                format_number(value)
-            } else {
-                value.clone()
-            };
-            (key.clone(), formatted_value)
+            (
+                metric.name.clone(),
+                metric.value.clone(),
+                parse_metric_value(&metric.value),
+            )
         })
         .collect();
     all_metrics.sort_by(|a, b| a.0.cmp(&b.0));
 
+    let settings = use_settings();
+    let (show_hidden_metrics, set_show_hidden_metrics) = signal(false);
+    let hidden_metrics_count = {
+        let all_metrics = all_metrics.clone();
+        Memo::new(move |_| {
+            all_metrics
+                .iter()
+                .filter(|(label, _, _)| settings.is_metric_ignored(label))
+                .count()
+        })
+    };
+    // cards grow with the number of metrics they show
+    let metrics_count = all_metrics.len();
+    let card_width = move || {
+        let shown = if show_hidden_metrics.get() {
+            metrics_count
+        } else {
+            metrics_count - hidden_metrics_count.get()
+        };
+        card_width_class(shown)
+    };
+
     let stats = node.statistics.clone();
+    let column_statistics = node.statistics.column_statistics.clone();
+    let mut chain = ancestors;
+    chain.push(node.name.clone());
+    let ancestor_chain = chain.join(" → ");
+    let on_critical_path = critical_path.contains(&node_id(&node.name, &node_path));
+    let cluster = clusters
+        .iter()
+        .enumerate()
+        .find(|(_, cluster)| cluster.node_paths.contains(&node_path))
+        .map(|(index, cluster)| {
+            (
+                CLUSTER_COLORS[index % CLUSTER_COLORS.len()],
+                cluster.name.clone(),
+            )
+        });
+    let background = cluster
+        .as_ref()
+        .map(|((background, _), _)| *background)
+        .unwrap_or("bg-white dark:bg-gray-900");
+    // every union input is checked against the first one
+    let union_problems = if node.name.starts_with("UnionExec") {
+        node.children
+            .split_first()
+            .map(|(first, rest)| {
+                rest.iter()
+                    .flat_map(|child| check_union_compatibility(first, child))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let border = if on_critical_path {
+        "border-blue-500"
+    } else {
+        relative_time_border(relative_time)
+    };
+    // None while the filter is empty
+    let matches_filter = {
+        let name = node.name.to_lowercase();
+        Memo::new(move |_| {
+            filter_text.with(|filter| {
+                let filter = filter.trim().to_lowercase();
+                (!filter.is_empty()).then(|| name.contains(&filter))
+            })
+        })
+    };
 
     view! {
         <div class="flex flex-col items-center">
             // Node Card
-            <div class="relative bg-white border-2 border-gray-200 rounded-lg p-4 shadow-sm hover:shadow-md transition-shadow min-w-64 max-w-80">
+            <div
+                class=move || {
+                    format!(
+                        "relative {background} border-2 {border} rounded-lg p-4 shadow-sm hover:shadow-md transition cursor-pointer {}",
+                        card_width(),
+                    )
+                }
+                class:opacity-30=move || matches_filter.get() == Some(false)
+                class:ring-2=move || matches_filter.get() == Some(true)
+                class:ring-yellow-400=move || matches_filter.get() == Some(true)
+                title=ancestor_chain
+                data-node-path=node_path.clone()
+                on:click={
+                    let node_path = node_path.clone();
+                    move |ev| {
+                        // controls keep their own behavior and the click ending a drag-pan selects nothing
+                        if !plan_view_pointer.is_click(&ev) {
+                            return;
+                        }
+                        if let Some(selected_node) = selected_node {
+                            selected_node.0.set(Some(node_path.clone()));
+                        }
+                    }
+                }
+            >
+                {cluster
+                    .map(|((_, label_class), name)| {
+                        view! {
+                            <span
+                                class=format!("absolute -top-2.5 left-3 px-1.5 rounded text-xs {label_class}")
+                                title="Operators that work together as one step"
+                            >
+                                {name}
+                            </span>
+                        }
+                    })}
                 // Node Header
                 <div class="flex items-center justify-between mb-3">
                     <div class="flex items-center gap-2">
-                        <h4 class="font-semibold text-gray-800 text-sm">{node.name.clone()}</h4>
+                        <h4 class="font-semibold text-gray-800 dark:text-gray-100 text-sm">{node.name.clone()}</h4>
+                        {on_critical_path
+                            .then(|| {
+                                view! {
+                                    <span
+                                        class="text-xs"
+                                        title="On the critical path: the slowest chain of operators"
+                                    >
+                                        "🔥"
+                                    </span>
+                                }
+                            })}
+                        {is_partitioned_operator(&node.name)
+                            .then(|| node.skew_ratio())
+                            .flatten()
+                            .map(|skew| {
+                                let color = if skew > 5.0 {
+                                    "text-red-700 bg-red-50"
+                                } else if skew > 2.0 {
+                                    "text-amber-700 bg-amber-50"
+                                } else {
+                                    "text-gray-600 dark:text-gray-300 bg-gray-50 dark:bg-gray-800"
+                                };
+                                view! {
+                                    <span
+                                        class=format!("text-xs font-mono rounded px-1 {color}")
+                                        title="Slowest partition time over the average. High skew indicates data hot spots: a few partitions hold most of the work."
+                                    >
+                                        {format!("Skew: {skew:.1}×")}
+                                    </span>
+                                }
+                            })}
+                        {(execution_count > 1)
+                            .then(|| {
+                                view! {
+                                    <span
+                                        class="text-xs font-mono text-purple-700 bg-purple-50 rounded px-1"
+                                        title="Number of times this operator executed"
+                                    >
+                                        {format!("×{execution_count}")}
+                                    </span>
+                                }
+                            })}
                     </div>
                 </div>
 
-                <div class="grid grid-cols-4 gap-2 mb-3">
-                    {all_metrics
-                        .into_iter()
-                        .map(|(label, value)| {
+                {(!union_problems.is_empty())
+                    .then(|| {
+                        view! {
+                            <div class="mb-3 px-2 py-1 text-xs text-red-700 bg-red-50 border border-red-200 rounded">
+                                <div class="font-medium">"Incompatible union inputs"</div>
+                                <ul class="list-disc pl-4">
+                                    {union_problems
+                                        .into_iter()
+                                        .map(|problem| view! { <li>{problem}</li> })
+                                        .collect_view()}
+                                </ul>
+                            </div>
+                        }
+                    })}
+
+                <div
+                    class="grid gap-2 mb-3"
+                    class=("grid-cols-4", move || !compact_cards.get())
+                    class=("grid-cols-2", move || compact_cards.get())
+                >
+                    {
+                        let plan_id = plan_id.clone();
+                        let node_path = node_path.clone();
+                        let plan_ids = plan_ids.clone();
+                        let all_metrics = all_metrics.clone();
+                        let node_name = node.name.clone();
+                        let compare_node = compare_node.clone();
+                        move || {
+                            all_metrics
+                                .iter()
+                                .filter(|(label, _, _)| {
+                                    show_hidden_metrics.get() || !settings.is_metric_ignored(label)
+                                })
+                                .map(|(label, raw, raw_value)| {
+                                    let diff = compare_node
+                                        .as_ref()
+                                        .and_then(|compare_node| {
+                                            let other = compare_node
+                                                .node
+                                                .metrics
+                                                .iter()
+                                                .find(|metric| &metric.name == label)?;
+                                            DiffMetric::new(label, &other.value, raw)
+                                        });
+                                    let significant = match (&diff, &compare_node) {
+                                        (Some(_), Some(compare_node)) => {
+                                            is_significant_change(
+                                                &metric_history
+                                                    .samples(
+                                                        &compare_node.plan_id,
+                                                        &compare_node.node_path,
+                                                        label,
+                                                    ),
+                                                &metric_history.samples(&plan_id, &node_path, label),
+                                                settings.significance_level.get(),
+                                            )
+                                        }
+                                        _ => None,
+                                    };
+                                    view! {
+                                        <MetricCell
+                                            node_name=node_name.clone()
+                                            label=label.clone()
+                                            raw=raw.clone()
+                                            raw_value=*raw_value
+                                            execution_count=execution_count
+                                            per_execution=per_execution
+                                            plan_id=plan_id.clone()
+                                            node_path=node_path.clone()
+                                            plan_ids=plan_ids.clone()
+                                            diff=diff
+                                            significant=significant
+                                        />
+                                    }
+                                })
+                                .collect_view()
+                        }
+                    }
+                </div>
+                {move || {
+                    let hidden_count = hidden_metrics_count.get();
+                    (hidden_count > 0)
+                        .then(|| {
                             view! {
-                                <div class="bg-gray-50 rounded p-2">
-                                    <div class="text-xs text-gray-500">{label}</div>
-                                    <div
-                                        class="text-xs font-mono text-gray-800 truncate"
-                                        title=value.clone()
-                                    >
-                                        {value.clone()}
-                                    </div>
-                                </div>
+                                <button
+                                    class="text-xs text-gray-400 hover:text-gray-600 dark:hover:text-gray-200 underline mb-3"
+                                    on:click=move |_| set_show_hidden_metrics.update(|s| *s = !*s)
+                                >
+                                    {move || {
+                                        if show_hidden_metrics.get() {
+                                            format!("Hide {hidden_count} ignored metrics")
+                                        } else {
+                                            format!("{hidden_count} metrics hidden")
+                                        }
+                                    }}
+                                </button>
                             }
                         })
-                        .collect_view()}
-                </div>
+                }}
+
+                <MemoryLayout metrics=node.metrics.clone() />
 
-                <StatisticsComponent stats=stats />
+                <StatisticsComponent stats=stats plan_id=plan_id.clone() />
 
                 <div>
                     <button
-                        class="flex items-center gap-1 text-xs text-gray-600"
-                        on:click=move |_| set_expanded.update(|e| *e = !*e)
+                        class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300"
+                        on:click=move |_| toggle_schema()
                     >
                         <svg class="w-3 h-3" fill="currentColor" viewBox="0 0 20 20">
                             <path
@@ -86,7 +978,8 @@ fn ExecutionPlanNodeComponent(node: ExecutionPlanWithStats) -> impl IntoView {
                                 clip-rule="evenodd"
                             />
                         </svg>
-                        "Schema"
+                        {move || t("Schema")}
+                        <SchemaWidthSparkline history=schema_width_history />
                     </button>
                     <Show when=move || expand_schema.get()>
                         <div class="mt-2 grid grid-cols-3 gap-1">
@@ -95,12 +988,26 @@ fn ExecutionPlanNodeComponent(node: ExecutionPlanWithStats) -> impl IntoView {
                                 .clone()
                                 .into_iter()
                                 .map(|field| {
+                                    let suggest_dictionary = parent_column_statistics
+                                        .iter()
+                                        .any(|col| suggest_dictionary_encoding(col, &field));
                                     view! {
-                                        <div class="text-xs bg-white border border-gray-100 rounded p-1">
-                                            <div class="text-gray-700 truncate font-medium">
-                                                {field.name}
+                                        <div class="text-xs bg-white dark:bg-gray-900 border border-gray-100 dark:border-gray-800 rounded p-1">
+                                            <div class="flex items-center gap-1 text-gray-700 dark:text-gray-200 font-medium">
+                                                <span class="truncate">{field.name}</span>
+                                                {suggest_dictionary
+                                                    .then(|| {
+                                                        view! {
+                                                            <span
+                                                                class="text-blue-500 cursor-help"
+                                                                title="Consider Dictionary: fewer than 10 distinct values in the parent, dictionary encoding would save memory"
+                                                            >
+                                                                "💡"
+                                                            </span>
+                                                        }
+                                                    })}
                                             </div>
-                                            <div class="text-gray-500 font-mono text-xs truncate">
+                                            <div class="text-gray-500 dark:text-gray-400 font-mono text-xs truncate">
                                                 {field.data_type}
                                             </div>
                                         </div>
@@ -118,13 +1025,36 @@ fn ExecutionPlanNodeComponent(node: ExecutionPlanWithStats) -> impl IntoView {
                     <div class="flex flex-col items-center">
                         // Vertical line down
                         <div class="w-0.5 h-8 bg-gray-300"></div>
+                        {fan_out
+                            .map(|fan_out| {
+                                let color = if fan_out > 10.0 {
+                                    "text-red-700 bg-red-50 border-red-200"
+                                } else if fan_out < 0.1 {
+                                    "text-blue-700 bg-blue-50 border-blue-200"
+                                } else {
+                                    "text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 border-gray-200 dark:border-gray-700"
+                                };
+                                view! {
+                                    <span
+                                        class=format!("text-xs font-mono border rounded px-1 {color}")
+                                        title="Fan-out: output rows per input row of the largest child"
+                                    >
+                                        {format!("×{fan_out:.2}")}
+                                    </span>
+                                    <div class="w-0.5 h-4 bg-gray-300"></div>
+                                }
+                            })}
 
                         // Children container
                         <div class="flex flex-col gap-8">
-                            {node
-                                .children
-                                .into_iter()
-                                .map(|child| {
+                            {move || {
+                                children
+                                    .get_value()
+                                    .iter()
+                                    .take(visible_children_count.get())
+                                    .cloned()
+                                    .enumerate()
+                                    .map(|(index, child)| {
                                     view! {
                                         <div class="flex flex-col items-center">
                                             // Horizontal line to child
@@ -133,14 +1063,51 @@ fn ExecutionPlanNodeComponent(node: ExecutionPlanWithStats) -> impl IntoView {
                                                 <div class="w-2 h-2 bg-gray-300 rounded-full"></div>
                                                 <div class="w-8 h-0.5 bg-gray-300"></div>
                                             </div>
+                                            {cardinality_warnings
+                                                .with_value(|warnings| warnings.get(index).cloned().flatten())
+                                                .map(|warning| {
+                                                    view! {
+                                                        <div class="mt-1 max-w-64 px-2 py-0.5 text-xs text-yellow-800 bg-yellow-50 border border-yellow-200 rounded">
+                                                            {format!("⚠ {}", warning.message())}
+                                                        </div>
+                                                    }
+                                                })}
                                             // Child node
                                             <div class="mt-2">
-                                                <ExecutionPlanNodeComponent node=child />
+                                                <ExecutionPlanNodeComponent
+                                                    node=child
+                                                    node_path=format!("{node_path}.{index}")
+                                                    plan_id=plan_id.clone()
+                                                    plan_ids=plan_ids.clone()
+                                                    plan_versions=plan_versions.clone()
+                                                    per_execution=per_execution
+                                                    compact_cards=compact_cards
+                                                    filter_text=filter_text
+                                                    parent_column_statistics=column_statistics.clone()
+                                                    ancestors=chain.clone()
+                                                    relative_time=relative_times
+                                                        .get(&format!("{node_path}.{index}"))
+                                                        .copied()
+                                                    relative_times=relative_times.clone()
+                                                    critical_path=critical_path.clone()
+                                                    clusters=clusters.clone()
+                                                    compare_node=child_compare_nodes
+                                                        .with_value(|nodes| nodes.get(index).cloned().flatten())
+                                                />
                                             </div>
                                         </div>
                                     }
                                 })
-                                .collect_view()}
+                                .collect_view()
+                            }}
+                            <Show when=move || { hidden_children_count() > 0 }>
+                                <button
+                                    class="self-center px-2 py-0.5 text-xs text-gray-600 dark:text-gray-300 bg-gray-50 dark:bg-gray-800 border border-gray-200 dark:border-gray-700 rounded-full hover:bg-gray-100 dark:hover:bg-gray-700"
+                                    on:click=move |_| set_visible_children_count.set(children_count)
+                                >
+                                    {move || format!("+ {} more children", hidden_children_count())}
+                                </button>
+                            </Show>
                         </div>
                     </div>
                 }
@@ -153,55 +1120,219 @@ fn ExecutionPlanNodeComponent(node: ExecutionPlanWithStats) -> impl IntoView {
 }
 
 #[component]
-fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
+pub fn OneExecutionStat(
+    stats: ExecutionStatsWithPlan,
+    /// Execution shown next to this one in compare mode
+    #[prop(default = None)]
+    compare_to: Option<ExecutionStatsWithPlan>,
+) -> impl IntoView {
     let plans = stats.plans.clone();
+    let plan_ids = Arc::new(plans.iter().map(|plan| plan.id.clone()).collect::<Vec<_>>());
+    let plan_versions = Arc::new(plans.clone());
     let execution_stats = stats.execution_stats.clone();
+    provide_context(PlanDisplayName(execution_stats.display_name.clone()));
+
+    // Relative times go stale, re-render them without re-fetching
+    let relative_time_tick = Trigger::new();
+    let relative_time_timer = set_interval_with_handle(
+        move || relative_time_tick.notify(),
+        std::time::Duration::from_secs(RELATIVE_TIME_REFRESH_S),
+    )
+    .ok();
+    on_cleanup(move || {
+        if let Some(handle) = relative_time_timer {
+            handle.clear();
+        }
+    });
+    let network_efficiency = stats.network_efficiency();
+    let snapshot_stats = stats.clone();
+    let influx_stats = stats.clone();
+    let server_address = use_context::<PlanServerAddress>();
+    let snapshot_compare = use_context::<SnapshotCompare>();
+    let settings = use_settings();
     let (selected_plan_index, set_selected_plan_index) = signal(0);
+    let (filter_text, set_filter_text) = signal(String::new());
+    let (per_execution, set_per_execution) = signal(false);
+    let (compact_cards, set_compact_cards) = signal(false);
+    let (plan_view, set_plan_view) = signal(PlanView::Visual);
+    let (show_schema_overview, set_show_schema_overview) = signal(false);
+    let collapse_mode = provide_collapse_mode();
+    let selected_node = provide_selected_node();
+    let dev_stats = use_dev_stats();
+    let tree_ref = NodeRef::<leptos::html::Div>::new();
+    let metric_history = use_metric_history();
+    let traffic_history = use_traffic_history();
+    // recorded version of the selected plan shown instead of the live one
+    let version_id = RwSignal::new(None::<u64>);
+    // looked up in the shown plan, so the drawer follows version scrubbing
+    let selected_plan_node = Signal::derive({
+        let plans = plans.clone();
+        move || {
+            let path = selected_node.0.get()?;
+            let plan_info = plans.get(selected_plan_index.get())?;
+            let version = version_id.get().and_then(|id| {
+                untrack(|| metric_history.plan_versions(&plan_info.id))
+                    .into_iter()
+                    .find(|version| version.id == id)
+            });
+            match version {
+                Some(version) => version.plan.node_at_path(&path).cloned(),
+                None => plan_info.plan.node_at_path(&path).cloned(),
+            }
+        }
+    });
+    // segments of the time breakdown jump to their node in the tree
+    let scroll_to_node = Callback::new(move |node_path: String| {
+        if let Some(node) = tree_ref.get_untracked().and_then(|tree| {
+            tree.query_selector(&format!("[data-node-path='{node_path}']"))
+                .ok()
+                .flatten()
+        }) {
+            node.scroll_into_view();
+        }
+    });
 
     view! {
-        <div class="border border-gray-200 rounded-lg bg-white">
-            <div class="p-4 border-b border-gray-100">
-                <h3 class="text-sm font-medium text-gray-800 mb-2">
-                    {execution_stats.display_name}
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900">
+            {
+                let execution_stats = execution_stats.clone();
+                move || {
+                    settings
+                        .is_slow_query(&execution_stats)
+                        .then(|| {
+                            view! {
+                                <div class="px-4 py-2 rounded-t-lg border-b border-red-200 bg-red-50 text-sm text-red-700">
+                                    {format!(
+                                        "🐢 Slow query: took {} ms, over the {} ms threshold",
+                                        execution_stats.execution_time_ms,
+                                        settings.slow_query_threshold_ms.get(),
+                                    )}
+                                </div>
+                            }
+                        })
+                }
+            }
+            <div class="p-4 border-b border-gray-100 dark:border-gray-800">
+                <h3 class="flex items-center gap-2 text-sm font-medium text-gray-800 dark:text-gray-100 mb-2">
+                    {execution_stats.display_name.clone()}
+                    {execution_stats
+                        .short_fingerprint()
+                        .map(|fingerprint| {
+                            view! {
+                                <span
+                                    class="px-1.5 py-0.5 rounded bg-gray-100 dark:bg-gray-800 text-xs font-mono font-normal text-gray-600 dark:text-gray-300"
+                                    title=format!(
+                                        "Query fingerprint {}, shared by runs of the same query with different parameters",
+                                        execution_stats.query_fingerprint.clone().unwrap_or_default(),
+                                    )
+                                >
+                                    {fingerprint.to_string()}
+                                </span>
+                            }
+                        })}
                 </h3>
-                <div class="grid grid-cols-4 gap-4 text-xs">
-                    <div class="bg-gray-50 p-2 rounded">
-                        <div class="text-gray-500">"Execution Time"</div>
-                        <div class="font-mono text-gray-800">
+                <div class="grid grid-cols-5 gap-4 text-xs">
+                    <div class="bg-gray-50 dark:bg-gray-800 p-2 rounded">
+                        <div class="text-gray-500 dark:text-gray-400">"Execution Time"</div>
+                        <div class="font-mono text-gray-800 dark:text-gray-100">
                             {format!("{}ms", execution_stats.execution_time_ms)}
                         </div>
+                        {compare_to
+                            .as_ref()
+                            .and_then(|other| {
+                                DiffMetric::new(
+                                    "execution_time_ms",
+                                    &other.execution_stats.execution_time_ms.to_string(),
+                                    &execution_stats.execution_time_ms.to_string(),
+                                )
+                            })
+                            .map(|diff| view! { <MetricDiffBadge diff=diff /> })}
                     </div>
-                    <div class="bg-gray-50 p-2 rounded">
-                        <div class="text-gray-500">"Network Traffic"</div>
-                        <div class="font-mono text-gray-800">
+                    <div class="bg-gray-50 dark:bg-gray-800 p-2 rounded">
+                        <div class="flex items-center justify-between gap-1">
+                            <span class="text-gray-500 dark:text-gray-400">"Network Traffic"</span>
+                            {
+                                let display_name = execution_stats.display_name.clone();
+                                move || {
+                                    traffic_history
+                                        .map(|history| {
+                                            let data = history
+                                                .samples(&display_name)
+                                                .into_iter()
+                                                .map(|bytes| bytes as f64)
+                                                .collect::<Vec<_>>();
+                                            view! {
+                                                <span title="Network traffic over the last refreshes">
+                                                    <Sparkline data=data />
+                                                </span>
+                                            }
+                                        })
+                                }
+                            }
+                        </div>
+                        <div class="font-mono text-gray-800 dark:text-gray-100">
                             {format_bytes(execution_stats.network_traffic_bytes)}
                         </div>
+                        {compare_to
+                            .as_ref()
+                            .and_then(|other| {
+                                DiffMetric::new(
+                                    "network_traffic_bytes",
+                                    &other.execution_stats.network_traffic_bytes.to_string(),
+                                    &execution_stats.network_traffic_bytes.to_string(),
+                                )
+                            })
+                            .map(|diff| view! { <MetricDiffBadge diff=diff /> })}
                     </div>
-                    <div class="bg-gray-50 p-2 rounded">
-                        <div class="text-gray-500">"Plan Count"</div>
-                        <div class="font-mono text-gray-800">{plans.len()}</div>
+                    <div
+                        class="bg-gray-50 dark:bg-gray-800 p-2 rounded"
+                        title="Bytes output by the cache divided by bytes sent over the network. Above 1 the cache delivers more data than it transmits (compression works well), below 1 it transmits more than it produces."
+                    >
+                        <div class="text-gray-500 dark:text-gray-400">"Network Efficiency"</div>
+                        {match network_efficiency {
+                            Some(ratio) => {
+                                let color = if ratio >= 1.0 { "text-green-700" } else { "text-red-700" };
+                                view! {
+                                    <div class=format!("font-mono {color}")>{format!("{ratio:.2}×")}</div>
+                                }
+                                    .into_any()
+                            }
+                            None => view! { <div class="font-mono text-gray-400">"—"</div> }.into_any(),
+                        }}
                     </div>
-                    <div class="bg-gray-50 p-2 rounded">
-                        <div class="text-gray-500">"Created at"</div>
-                        <div class="font-mono text-gray-800">
-                            {format_timestamp(plans.first().unwrap().created_at)}
-                        </div>
+                    <div class="bg-gray-50 dark:bg-gray-800 p-2 rounded">
+                        <div class="text-gray-500 dark:text-gray-400">"Plan Count"</div>
+                        <div class="font-mono text-gray-800 dark:text-gray-100">{plans.len()}</div>
+                    </div>
+                    <div class="bg-gray-50 dark:bg-gray-800 p-2 rounded">
+                        <div class="text-gray-500 dark:text-gray-400">"Created at"</div>
+                        {
+                            let created_at = plans.first().unwrap().created_at;
+                            view! {
+                                <div
+                                    class="font-mono text-gray-800 dark:text-gray-100"
+                                    title=format_timestamp(created_at)
+                                >
+                                    {move || {
+                                        relative_time_tick.track();
+                                        format_relative_time(created_at)
+                                    }}
+                                </div>
+                            }
+                        }
                     </div>
                 </div>
 
-                <div class="mt-4">
-                    <div class="bg-gray-50 rounded p-3 border max-h-48 overflow-y-auto">
-                        <pre class="text-xs font-mono text-gray-800 whitespace-pre-wrap overflow-x-auto">
-                            {execution_stats.user_sql.clone()}
-                        </pre>
-                    </div>
+                <div class="mt-4 relative">
+                    <SqlViewer sql=execution_stats.user_sql.clone() />
+                    <SqlCopyButton sql=execution_stats.user_sql.clone() />
                 </div>
             </div>
 
             // Plan tabs
             {if plans.len() > 1 {
                 view! {
-                    <div class="border-b border-gray-100">
+                    <div class="border-b border-gray-100 dark:border-gray-800">
                         <div class="flex">
                             {plans
                                 .iter()
@@ -216,11 +1347,15 @@ fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
                                                     if is_selected() {
                                                         "text-blue-600 border-blue-600 bg-blue-50"
                                                     } else {
-                                                        "text-gray-500 border-transparent hover:text-gray-700 hover:border-gray-300"
+                                                        "text-gray-500 dark:text-gray-400 border-transparent hover:text-gray-700 dark:hover:text-gray-200 hover:border-gray-300"
                                                     },
                                                 )
                                             }
-                                            on:click=move |_| set_selected_plan_index.set(index)
+                                            on:click=move |_| {
+                                                set_selected_plan_index.set(index);
+                                                set_filter_text.set(String::new());
+                                                version_id.set(None);
+                                            }
                                         >
                                             {if let Some(predicate) = plan.predicate.clone() {
                                                 predicate
@@ -241,22 +1376,234 @@ fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
 
             // Selected plan content
             <div class="p-4">
+                {
+                    let plans = plans.clone();
+                    let versions = Signal::derive(move || {
+                        plans
+                            .get(selected_plan_index.get())
+                            .map(|plan_info| metric_history.plan_versions(&plan_info.id))
+                            .unwrap_or_default()
+                    });
+                    // rendered once, so recording new versions keeps the selection and playback going
+                    view! {
+                        <Show when=move || versions.with(|versions| versions.len() > 1)>
+                            <PlanPlayback versions=versions version_id=version_id />
+                        </Show>
+                    }
+                }
                 {move || {
                     let selected_index = selected_plan_index.get();
                     if let Some(plan_info) = plans.get(selected_index) {
+                        // while scrubbing, the recorded version replaces the live tree
+                        // and metrics are compared against the version before it
+                        let selected_version = version_id.get().and_then(|id| {
+                            let versions = untrack(|| metric_history.plan_versions(&plan_info.id));
+                            let index = versions.iter().position(|version| version.id == id)?;
+                            Some((versions, index))
+                        });
+                        let (plan_info, previous_version) = match selected_version {
+                            Some((versions, index)) => {
+                                let mut plan_info = plan_info.clone();
+                                plan_info.plan = versions[index].plan.clone();
+                                let previous_version = index
+                                    .checked_sub(1)
+                                    .and_then(|previous| versions.get(previous))
+                                    .map(|version| CompareNode {
+                                        plan_id: plan_info.id.clone(),
+                                        node_path: "0".to_string(),
+                                        node: version.plan.clone(),
+                                    });
+                                (plan_info, previous_version)
+                            }
+                            None => (plan_info.clone(), None),
+                        };
+                        // a new version fades in
+                        let faded_in = RwSignal::new(previous_version.is_none());
+                        request_animation_frame(move || faded_in.set(true));
+                        let relative_times = Arc::new(compute_relative_times(&plan_info.plan));
+                        let critical_path = Arc::new(find_critical_path(&plan_info.plan));
+                        let clusters = Arc::new(identify_clusters(&plan_info.plan));
+                        if let Some(dev_stats) = dev_stats {
+                            let render_start = dev_stats.begin_render();
+                            Effect::new(move || {
+                                if let Some(container) = tree_ref.get() {
+                                    dev_stats.finish_render(render_start, &container);
+                                }
+                            });
+                        }
                         view! {
                             <div class="space-y-6">
                                 <div>
+                                    <TimeBreakdownBar plan=plan_info.plan.clone() on_select=scroll_to_node />
                                     <div class="flex items-center justify-between mb-3">
-                                        <h4 class="text-sm font-medium text-gray-700">
-                                            "Execution Plan"
-                                        </h4>
+                                        <div class="flex items-center gap-3">
+                                            <h4 class="text-sm font-medium text-gray-700 dark:text-gray-200">
+                                                {move || t("Execution Plan")}
+                                            </h4>
+                                            <label
+                                                class="flex items-center gap-1 text-xs text-gray-500 dark:text-gray-400"
+                                                title="Divide metrics of repeated operators by their execution count"
+                                            >
+                                                <input
+                                                    type="checkbox"
+                                                    prop:checked=per_execution
+                                                    on:change=move |ev| {
+                                                        set_per_execution.set(event_target_checked(&ev))
+                                                    }
+                                                />
+                                                "Per-execution"
+                                            </label>
+                                            <label
+                                                class="flex items-center gap-1 text-xs text-gray-500 dark:text-gray-400"
+                                                title="Show node metrics in two columns instead of four"
+                                            >
+                                                <input
+                                                    type="checkbox"
+                                                    prop:checked=compact_cards
+                                                    on:change=move |ev| {
+                                                        set_compact_cards.set(event_target_checked(&ev))
+                                                    }
+                                                />
+                                                "Compact cards"
+                                            </label>
+                                            <div class="flex border border-gray-200 dark:border-gray-700 rounded overflow-hidden text-xs">
+                                                {[(PlanView::Visual, "Visual"), (PlanView::Text, "Text")]
+                                                    .map(|(view, label)| {
+                                                        view! {
+                                                            <button
+                                                                class=move || {
+                                                                    if plan_view.get() == view {
+                                                                        "px-2 py-0.5 bg-gray-100 dark:bg-gray-700 text-gray-800 dark:text-gray-100"
+                                                                    } else {
+                                                                        "px-2 py-0.5 text-gray-500 dark:text-gray-400 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                                    }
+                                                                }
+                                                                on:click=move |_| set_plan_view.set(view)
+                                                            >
+                                                                {label}
+                                                            </button>
+                                                        }
+                                                    })}
+                                            </div>
+                                            <button
+                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                title="Download the plan as a Graphviz DOT file, render it with `dot -Tpng`"
+                                                on:click={
+                                                    let plan_info = plan_info.clone();
+                                                    move |_| {
+                                                        download_text_file(
+                                                            &format!("plan-{}.dot", plan_info.id),
+                                                            &render_plan_dot(&plan_info.plan),
+                                                            "text/vnd.graphviz",
+                                                        )
+                                                    }
+                                                }
+                                            >
+                                                {move || t("Export DOT")}
+                                            </button>
+                                            <button
+                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                title="Download the plan as a Mermaid diagram to paste into GitHub issues"
+                                                on:click={
+                                                    let plan_info = plan_info.clone();
+                                                    move |_| {
+                                                        download_text_file(
+                                                            &format!("plan-{}.md", plan_info.id),
+                                                            &format!(
+                                                                "```mermaid\n{}```\n",
+                                                                render_plan_mermaid(&plan_info.plan),
+                                                            ),
+                                                            "text/markdown",
+                                                        )
+                                                    }
+                                                }
+                                            >
+                                                {move || t("Export Mermaid")}
+                                            </button>
+                                            <button
+                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                title="Download the node metrics as InfluxDB line protocol"
+                                                on:click={
+                                                    let stats = influx_stats.clone();
+                                                    move |_| {
+                                                        let server_address = server_address
+                                                            .map(|address| address.0.get_untracked())
+                                                            .unwrap_or_default();
+                                                        download_text_file(
+                                                            &format!("plan-{}.lp", stats.execution_stats.display_name),
+                                                            &to_influx_line_protocol(&stats, &server_address),
+                                                            "text/plain",
+                                                        )
+                                                    }
+                                                }
+                                            >
+                                                {move || t("Export InfluxDB")}
+                                            </button>
+                                            <input
+                                                type="search"
+                                                placeholder="Filter nodes…"
+                                                class="w-36 px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900 focus:outline-none focus:border-gray-400"
+                                                prop:value=filter_text
+                                                on:input=move |ev| set_filter_text.set(event_target_value(&ev))
+                                            />
+                                            {(plan_info.plan.nodes_with_paths().len() > 3)
+                                                .then(|| {
+                                                    view! {
+                                                        <div class="flex gap-1 text-xs">
+                                                            <button
+                                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                                on:click=move |_| collapse_mode.set(CollapseMode::All)
+                                                            >
+                                                                "Expand All"
+                                                            </button>
+                                                            <button
+                                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                                on:click=move |_| collapse_mode.set(CollapseMode::None)
+                                                            >
+                                                                "Collapse All"
+                                                            </button>
+                                                        </div>
+                                                    }
+                                                })}
+                                            {snapshot_compare
+                                                .map(|snapshot_compare| {
+                                                    let stats = snapshot_stats.clone();
+                                                    view! {
+                                                        <button
+                                                            class="px-2 py-0.5 border border-blue-200 rounded text-xs text-blue-700 hover:bg-blue-50 disabled:opacity-50"
+                                                            title="Keep this plan, refresh and compare the fresh plan against it"
+                                                            disabled=move || snapshot_compare.is_waiting()
+                                                            on:click=move |_| snapshot_compare.start(stats.clone())
+                                                        >
+                                                            "Snapshot + Compare"
+                                                        </button>
+                                                    }
+                                                })}
+                                            <button
+                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                on:click={
+                                                    let execution_stats = execution_stats.clone();
+                                                    let plan_info = plan_info.clone();
+                                                    move |_| export_plan_json(&execution_stats, &plan_info)
+                                                }
+                                            >
+                                                {move || t("Export JSON")}
+                                            </button>
+                                            <button
+                                                class="px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                class:bg-gray-100=show_schema_overview
+                                                title="Data types across the schemas of all operators"
+                                                on:click=move |_| set_show_schema_overview.update(|show| *show = !*show)
+                                            >
+                                                "Schema overview"
+                                            </button>
+                                        </div>
                                         {if let Some(predicate) = plan_info.predicate.clone() {
                                             view! {
                                                 <div class="flex items-center gap-2">
-                                                    <span class="text-xs text-gray-500">"Predicate:"</span>
-                                                    <div class="bg-gray-50 rounded px-2 py-1 border max-w-md">
-                                                        <code class="text-xs font-mono text-gray-800 truncate block">
+                                                    <span class="text-xs text-gray-500 dark:text-gray-400">"Predicate:"</span>
+                                                    <div class="bg-gray-50 dark:bg-gray-800 rounded px-2 py-1 border max-w-md">
+                                                        <code class="text-xs font-mono text-gray-800 dark:text-gray-100 truncate block">
                                                             {predicate}
                                                         </code>
                                                     </div>
@@ -267,8 +1614,64 @@ fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
                                             ().into_any()
                                         }}
                                     </div>
-                                    <div class="flex justify-center">
-                                        <ExecutionPlanNodeComponent node=plan_info.plan.clone() />
+                                    <div class:hidden=move || plan_view.get() != PlanView::Text>
+                                        <PlanTextView text=render_plan_text(&plan_info.plan, 0) />
+                                    </div>
+                                    <div class:hidden=move || plan_view.get() != PlanView::Visual>
+                                        <RelativeTimeLegend />
+                                        {
+                                            let plan = plan_info.plan.clone();
+                                            move || {
+                                                show_schema_overview
+                                                    .get()
+                                                    .then(|| view! { <TypeHistogram plan=plan.clone() /> })
+                                            }
+                                        }
+                                        <BookmarkedMetrics plan=plan_info.plan.clone() />
+                                        <CacheOperatorSummary plan=plan_info.plan.clone() />
+                                        <WaterfallCompare plan=plan_info.plan.clone() />
+                                        {
+                                            // built eagerly, the children closure must not move the captured plan data
+                                            let tree = view! {
+                                                <div
+                                                    class="flex justify-center transition-opacity duration-300"
+                                                    class:opacity-0=move || !faded_in.get()
+                                                    node_ref=tree_ref
+                                                >
+                                                    <ExecutionPlanNodeComponent
+                                                        node=plan_info.plan.clone()
+                                                        node_path="0".to_string()
+                                                        plan_id=plan_info.id.clone()
+                                                        plan_ids=plan_ids.clone()
+                                                        plan_versions=plan_versions.clone()
+                                                        per_execution=per_execution
+                                                        compact_cards=compact_cards
+                                                        filter_text=filter_text
+                                                        parent_column_statistics=Vec::new()
+                                                        ancestors=Vec::new()
+                                                        relative_time=relative_times.get("0").copied()
+                                                        relative_times=relative_times.clone()
+                                                        critical_path=critical_path.clone()
+                                                        clusters=clusters.clone()
+                                                        compare_node=compare_to
+                                                            .as_ref()
+                                                            .and_then(|other| {
+                                                                other
+                                                                    .plans
+                                                                    .get(selected_plan_index.get())
+                                                                    .or(other.plans.first())
+                                                            })
+                                                            .map(|other_plan| CompareNode {
+                                                                plan_id: other_plan.id.clone(),
+                                                                node_path: "0".to_string(),
+                                                                node: other_plan.plan.clone(),
+                                                            })
+                                                            .or(previous_version)
+                                                    />
+                                                </div>
+                                            };
+                                            view! { <ZoomablePlanView>{tree}</ZoomablePlanView> }
+                                        }
                                     </div>
                                 </div>
 
@@ -280,6 +1683,7 @@ fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
                                         <Flamegraph
                                             svg_content=flamegraph_svg
                                             plan_id=plan_info.id.clone()
+                                            byte_decimal_places=0
                                         />
                                     }
                                         .into_any()
@@ -291,12 +1695,15 @@ fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
                             .into_any()
                     } else {
                         view! {
-                            <div class="text-center text-gray-500 py-8">"No plan selected"</div>
+                            <div class="text-center text-gray-500 dark:text-gray-400 py-8">
+                                {move || t("No plan selected")}
+                            </div>
                         }
                             .into_any()
                     }
                 }}
             </div>
+            <NodeDetailDrawer node=selected_plan_node />
         </div>
     }
 }
@@ -305,90 +1712,355 @@ fn OneExecutionStat(stats: ExecutionStatsWithPlan) -> impl IntoView {
 pub fn ExecutionStats(
     execution_stats: Arc<Vec<ExecutionStatsWithPlan>>,
     on_refresh: RefreshCallback,
+    /// Whether a refresh is in flight, the stale plans stay visible but dimmed
+    #[prop(into)]
+    loading: Signal<bool>,
+    /// Display name of the plan to select first, wins over the plan selected before a refresh
+    #[prop(default = None)]
+    initial_plan: Option<String>,
 ) -> impl IntoView {
-    let (selected_plan_id, set_selected_plan_id) = signal(
-        execution_stats
-            .first()
-            .map(|plan| plan.execution_stats.display_name.clone())
-            .unwrap_or_default(),
-    );
-    let (selected_plan, set_selected_plan) = signal(execution_stats.first().cloned());
-    let display_names = execution_stats
-        .iter()
-        .map(|plan| plan.execution_stats.display_name.clone())
-        .collect::<Vec<_>>();
+    let settings = use_settings();
+    provide_context(LoadedExecutions(execution_stats.clone()));
+    let (selected_plan_id, set_selected_plan_id) = signal(String::new());
+    let (selected_plan, set_selected_plan) = signal(None::<ExecutionStatsWithPlan>);
+    let (compare_mode, set_compare_mode) = signal(false);
+    let (show_tree_diff, set_show_tree_diff) = signal(false);
+    let (compare_plan_id, set_compare_plan_id) = signal(String::new());
+    let (compare_plan, set_compare_plan) = signal(None::<ExecutionStatsWithPlan>);
+    let snapshot_compare = use_context::<SnapshotCompare>();
+    let plan_selector_id = scoped_id(PLAN_SELECTOR_ID);
 
-    let execution_stats_clone = execution_stats.clone();
+    let sorted_stats = {
+        let execution_stats = execution_stats.clone();
+        Signal::derive(move || {
+            let mut sorted = execution_stats.to_vec();
+            settings.plan_sort.get().sort(&mut sorted);
+            Arc::new(sorted)
+        })
+    };
 
-    Effect::new(move |_| {
-        if !execution_stats_clone.is_empty() && selected_plan_id.get().is_empty() {
-            if let Some(first_plan) = execution_stats_clone.first() {
-                set_selected_plan_id.set(first_plan.execution_stats.display_name.clone());
-                set_selected_plan.set(Some(first_plan.clone()));
-            }
+    // Auto-select the first plan in the preferred order whenever plans are loaded
+    // or the preference changes. Initially, the deep-linked plan or the one selected before a
+    // page refresh wins.
+    Effect::new(move |previous: Option<()>| {
+        let sorted_stats = sorted_stats.get();
+        let last_plan = previous
+            .is_none()
+            .then(|| {
+                initial_plan
+                    .clone()
+                    .or_else(|| storage::get::<String>(LAST_PLAN_ID_KEY))
+            })
+            .flatten()
+            .and_then(|last_plan_id| {
+                sorted_stats
+                    .iter()
+                    .find(|plan| plan.execution_stats.display_name == last_plan_id)
+            });
+        if let Some(first_plan) = last_plan.or(sorted_stats.first()) {
+            set_selected_plan_id.set(first_plan.execution_stats.display_name.clone());
+            set_selected_plan.set(Some(first_plan.clone()));
+        }
+        // compare against the next plan by default
+        if let Some(second_plan) = sorted_stats.get(1).or(sorted_stats.first()) {
+            set_compare_plan_id.set(second_plan.execution_stats.display_name.clone());
+            set_compare_plan.set(Some(second_plan.clone()));
+        }
+        // fresh plans after "Snapshot + Compare": compare against the snapshot instead
+        if let Some(snapshot) = snapshot_compare.and_then(|s| s.take_pending()) {
+            set_compare_plan_id.set(snapshot.execution_stats.display_name.clone());
+            set_compare_plan.set(Some(snapshot));
+            set_compare_mode.set(true);
         }
     });
+    Effect::new(move || {
+        selected_plan_id.with(|plan_id| {
+            if !plan_id.is_empty() {
+                storage::set(LAST_PLAN_ID_KEY, plan_id);
+            }
+        })
+    });
+
+    let find_plan = {
+        let execution_stats = execution_stats.clone();
+        move |display_name: &str| {
+            execution_stats
+                .iter()
+                .find(|plan| plan.execution_stats.display_name == display_name)
+                .cloned()
+        }
+    };
+    let plan_options = move || {
+        let sorted_stats = sorted_stats.get();
+        let names = sorted_stats
+            .iter()
+            .map(|plan| plan.execution_stats.display_name.clone())
+            .collect::<Vec<_>>();
+        // plans of the same query as the selected one, with different parameters
+        let selected_fingerprint = selected_plan
+            .with(|plan| {
+                plan.as_ref()
+                    .and_then(|plan| plan.execution_stats.query_fingerprint.clone())
+            })
+            .filter(|fingerprint| {
+                sorted_stats
+                    .iter()
+                    .filter(|plan| {
+                        plan.execution_stats.query_fingerprint.as_ref() == Some(fingerprint)
+                    })
+                    .count()
+                    > 1
+            });
+        let same_query = |name: &str| {
+            selected_fingerprint.is_some()
+                && sorted_stats.iter().any(|plan| {
+                    plan.execution_stats.display_name == name
+                        && plan.execution_stats.query_fingerprint == selected_fingerprint
+                })
+        };
+        let slow = |name: &str| {
+            sorted_stats.iter().any(|plan| {
+                plan.execution_stats.display_name == name
+                    && settings.is_slow_query(&plan.execution_stats)
+            })
+        };
+        let groups = group_by_prefix(&names);
+        let options = |plans: Vec<String>| {
+            plans
+                .into_iter()
+                .map(|name| {
+                    let same_query = same_query(&name);
+                    let slow = slow(&name);
+                    let label = match (slow, same_query) {
+                        (true, true) => format!("🐢 ≡ {name}"),
+                        (true, false) => format!("🐢 {name}"),
+                        (false, true) => format!("≡ {name}"),
+                        (false, false) => name.clone(),
+                    };
+                    view! {
+                        <option value=name class:bg-yellow-100=same_query>
+                            {label}
+                        </option>
+                    }
+                })
+                .collect_view()
+        };
+        // without any shared prefix everything is in "Other", so skip the grouping
+        if groups.iter().all(|group| group.prefix == OTHER_PLAN_GROUP) {
+            return options(names).into_any();
+        }
+        groups
+            .into_iter()
+            .map(|group| view! { <optgroup label=group.prefix>{options(group.plans)}</optgroup> })
+            .collect_view()
+            .into_any()
+    };
 
     view! {
         <div class="space-y-4">
-            <div class="bg-white border border-gray-200 rounded-lg p-4">
+            {snapshot_compare
+                .map(|snapshot_compare| {
+                    view! {
+                        <Show when=move || snapshot_compare.is_waiting()>
+                            <div class="px-3 py-2 text-xs text-blue-700 bg-blue-50 border border-blue-100 rounded">
+                                {move || match snapshot_compare.countdown.get() {
+                                    Some(seconds) => {
+                                        format!("Waiting for fresh plan... (refreshing in {seconds}s)")
+                                    }
+                                    None => "Waiting for fresh plan... (refreshing)".to_string(),
+                                }}
+                            </div>
+                        </Show>
+                    }
+                })}
+            <div
+                class="bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded-lg p-4 transition-opacity"
+                class:opacity-75=loading
+                class:pointer-events-none=loading
+            >
                 <div class="flex justify-between items-center mb-4">
-                    <h2 class="text-lg font-semibold text-gray-800">"Execution Plans"</h2>
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100">
+                        {move || t("Execution Plans")}
+                    </h2>
                     <div class="flex items-center space-x-3">
                         <select
-                            class="px-3 py-2 border border-gray-200 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 text-sm text-gray-700 bg-white"
+                            class="px-2 py-2 border border-gray-200 dark:border-gray-700 rounded-md focus:outline-none text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900"
+                            title="Sort by"
+                            prop:value=move || {
+                                match settings.plan_sort.get() {
+                                    PlanSortOrder::NewestFirst => "newest",
+                                    PlanSortOrder::SlowestFirst => "slowest",
+                                }
+                            }
                             on:change=move |ev| {
-                                let display_name = event_target_value(&ev);
-                                if let Some(plan) = execution_stats
-                                    .iter()
-                                    .find(|plan| plan.execution_stats.display_name == display_name)
-                                {
-                                    set_selected_plan.set(Some(plan.clone()));
-                                    set_selected_plan_id.set(display_name);
+                                settings
+                                    .plan_sort
+                                    .set(
+                                        if event_target_value(&ev) == "slowest" {
+                                            PlanSortOrder::SlowestFirst
+                                        } else {
+                                            PlanSortOrder::NewestFirst
+                                        },
+                                    )
+                            }
+                        >
+                            <option value="newest">"Newest first"</option>
+                            <option value="slowest">"Slowest first"</option>
+                        </select>
+                        <label class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300">
+                            <input
+                                type="checkbox"
+                                prop:checked=compare_mode
+                                on:change=move |ev| set_compare_mode.set(event_target_checked(&ev))
+                            />
+                            "Compare"
+                        </label>
+                        <select
+                            id=plan_selector_id.clone()
+                            class="px-3 py-2 border border-gray-200 dark:border-gray-700 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 text-sm text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900"
+                            on:change={
+                                let find_plan = find_plan.clone();
+                                move |ev| {
+                                    let display_name = event_target_value(&ev);
+                                    if let Some(plan) = find_plan(&display_name) {
+                                        set_selected_plan.set(Some(plan));
+                                        set_selected_plan_id.set(display_name);
+                                    }
                                 }
                             }
                             prop:value=move || selected_plan_id.get()
                         >
-                            {move || {
-                                display_names
-                                    .iter()
-                                    .map(|display_name| {
-                                        view! {
-                                            <option value=display_name
-                                                .clone()>{display_name.clone()}</option>
-                                        }
-                                    })
-                                    .collect_view()
-                            }}
+                            {plan_options}
                         </select>
+                        <Show when=move || compare_mode.get()>
+                            <span class="text-xs text-gray-500 dark:text-gray-400">"vs"</span>
+                            <select
+                                class="px-3 py-2 border border-gray-200 dark:border-gray-700 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 focus:border-blue-500 text-sm text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900"
+                                on:change={
+                                    let find_plan = find_plan.clone();
+                                    move |ev| {
+                                        let display_name = event_target_value(&ev);
+                                        if let Some(plan) = find_plan(&display_name) {
+                                            set_compare_plan.set(Some(plan));
+                                            set_compare_plan_id.set(display_name);
+                                        }
+                                    }
+                                }
+                                prop:value=move || compare_plan_id.get()
+                            >
+                                {plan_options}
+                            </select>
+                        </Show>
                         <button
-                            class="px-3 py-2 bg-gray-100 border border-gray-200 rounded-md text-gray-700 hover:bg-gray-200 transition-colors text-sm flex items-center gap-2"
+                            class="px-3 py-2 bg-gray-100 dark:bg-gray-700 border border-gray-200 dark:border-gray-700 rounded-md text-gray-700 dark:text-gray-200 hover:bg-gray-200 transition-colors text-sm flex items-center gap-2"
                             on:click=move |_| {
                                 on_refresh();
                             }
                         >
-                            <svg
-                                class="w-4 h-4"
-                                fill="none"
-                                stroke="currentColor"
-                                viewBox="0 0 24 24"
-                            >
-                                <path
-                                    stroke-linecap="round"
-                                    stroke-linejoin="round"
-                                    stroke-width="2"
-                                    d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15"
-                                ></path>
-                            </svg>
-                            "Refresh"
+                            {move || {
+                                if loading.get() {
+                                    view! { <Spinner class="w-4 h-4 text-gray-500" /> }.into_any()
+                                } else {
+                                    view! {
+                                        <svg
+                                            class="w-4 h-4"
+                                            fill="none"
+                                            stroke="currentColor"
+                                            viewBox="0 0 24 24"
+                                        >
+                                            <path
+                                                stroke-linecap="round"
+                                                stroke-linejoin="round"
+                                                stroke-width="2"
+                                                d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15"
+                                            ></path>
+                                        </svg>
+                                    }
+                                        .into_any()
+                                }
+                            }}
+                            {move || t("Refresh")}
                         </button>
                     </div>
                 </div>
                 {move || {
-                    if let Some(selected_plan) = selected_plan.get() {
-                        view! { <OneExecutionStat stats=selected_plan /> }.into_any()
-                    } else {
-                        ().into_any()
+                    match (selected_plan.get(), compare_mode.get().then(|| compare_plan.get()).flatten()) {
+                        (Some(selected_plan), Some(compare_plan)) => {
+                            let radar = selected_plan
+                                .plans
+                                .first()
+                                .zip(compare_plan.plans.first())
+                                .filter(|(left, right)| radar_comparable(&left.plan, &right.plan))
+                                .map(|(left, right)| {
+                                    view! {
+                                        <RadarChart
+                                            left=left.plan.clone()
+                                            right=right.plan.clone()
+                                            left_label=selected_plan.execution_stats.display_name.clone()
+                                            right_label=compare_plan.execution_stats.display_name.clone()
+                                        />
+                                    }
+                                });
+                            let tree_diff = selected_plan
+                                .plans
+                                .first()
+                                .zip(compare_plan.plans.first())
+                                .map(|(left, right)| {
+                                    let left_label = selected_plan.execution_stats.display_name.clone();
+                                    let right_label = compare_plan.execution_stats.display_name.clone();
+                                    let (left, right) = (left.plan.clone(), right.plan.clone());
+                                    view! {
+                                        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4 mb-4">
+                                            <button
+                                                class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300 font-medium"
+                                                on:click=move |_| set_show_tree_diff.update(|s| *s = !*s)
+                                            >
+                                                {move || if show_tree_diff.get() { "▾" } else { "▸" }}
+                                                "Structural diff"
+                                            </button>
+                                            {move || {
+                                                show_tree_diff
+                                                    .get()
+                                                    .then(|| {
+                                                        view! {
+                                                            <div class="mt-3">
+                                                                <PlanTreeDiff
+                                                                    left=left.clone()
+                                                                    right=right.clone()
+                                                                    left_label=left_label.clone()
+                                                                    right_label=right_label.clone()
+                                                                />
+                                                            </div>
+                                                        }
+                                                    })
+                                            }}
+                                        </div>
+                                    }
+                                });
+                            view! {
+                                {radar}
+                                {tree_diff}
+                                <div class="grid grid-cols-2 gap-4">
+                                    <div class="min-w-0 overflow-x-auto">
+                                        <OneExecutionStat
+                                            stats=selected_plan.clone()
+                                            compare_to=Some(compare_plan.clone())
+                                        />
+                                    </div>
+                                    <div class="min-w-0 overflow-x-auto">
+                                        <OneExecutionStat
+                                            stats=compare_plan
+                                            compare_to=Some(selected_plan)
+                                        />
+                                    </div>
+                                </div>
+                            }
+                                .into_any()
+                        }
+                        (Some(selected_plan), None) => {
+                            view! { <OneExecutionStat stats=selected_plan /> }.into_any()
+                        }
+                        (None, _) => ().into_any(),
                     }
                 }}
             </div>
@@ -1,8 +1,77 @@
 use leptos::prelude::*;
-use leptos::wasm_bindgen::JsCast;
+use leptos::task::spawn_local;
+use leptos::wasm_bindgen::{JsCast, JsValue};
+
+use crate::components::toast::use_toast;
+use crate::utils::{format_bytes_with_precision, i18n::t};
+
+/// Canvas pixels per SVG unit, 2× keeps the PNG sharp on retina screens
+const PNG_SCALE: f64 = 2.0;
+/// Size used when the SVG has no `viewBox`
+const DEFAULT_SVG_SIZE: (f64, f64) = (1200.0, 800.0);
+
+/// Width and height from the SVG `viewBox="min-x min-y width height"`
+fn svg_dimensions(svg: &str) -> (f64, f64) {
+    svg.split_once("viewBox=\"")
+        .and_then(|(_, rest)| rest.split('"').next())
+        .and_then(|view_box| {
+            let values = view_box
+                .split([' ', ','])
+                .filter(|value| !value.is_empty())
+                .map(|value| value.parse::<f64>().ok())
+                .collect::<Option<Vec<_>>>()?;
+            match values[..] {
+                [_, _, width, height] if width > 0.0 && height > 0.0 => Some((width, height)),
+                _ => None,
+            }
+        })
+        .unwrap_or(DEFAULT_SVG_SIZE)
+}
+
+/// Rasterize the SVG on a canvas and return it as a PNG data URL
+async fn render_svg_to_png(svg: &str) -> Result<String, JsValue> {
+    let (width, height) = svg_dimensions(svg);
+    let document = document();
+
+    let parts = js_sys::Array::of1(&JsValue::from_str(svg));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("image/svg+xml;charset=utf-8");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options)?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)?;
+
+    let image = web_sys::HtmlImageElement::new()?;
+    let loaded = js_sys::Promise::new(&mut |resolve, reject| {
+        image.set_onload(Some(&resolve));
+        image.set_onerror(Some(&reject));
+    });
+    image.set_src(&url);
+    let load_result = wasm_bindgen_futures::JsFuture::from(loaded).await;
+    let _ = web_sys::Url::revoke_object_url(&url);
+    load_result?;
+
+    let canvas = document
+        .create_element("canvas")?
+        .unchecked_into::<web_sys::HtmlCanvasElement>();
+    canvas.set_width((width * PNG_SCALE) as u32);
+    canvas.set_height((height * PNG_SCALE) as u32);
+    let context = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d context unavailable"))?
+        .unchecked_into::<web_sys::CanvasRenderingContext2d>();
+    context.scale(PNG_SCALE, PNG_SCALE)?;
+    context.draw_image_with_html_image_element_and_dw_and_dh(&image, 0.0, 0.0, width, height)?;
+    canvas.to_data_url_with_type("image/png")
+}
 
 #[component]
-pub fn Flamegraph(svg_content: String, plan_id: String) -> impl IntoView {
+pub fn Flamegraph(
+    svg_content: String,
+    plan_id: String,
+    /// Fractional digits of the download size
+    #[prop(default = 2)]
+    byte_decimal_places: u8,
+) -> impl IntoView {
+    let svg_size = format_bytes_with_precision(svg_content.len() as u64, byte_decimal_places);
     let svg_for_download = svg_content.clone();
     let plan_id_for_download = plan_id.clone();
 
@@ -30,30 +99,114 @@ pub fn Flamegraph(svg_content: String, plan_id: String) -> impl IntoView {
         }
     };
 
+    let download_png = {
+        let svg_content = svg_content.clone();
+        let plan_id = plan_id.clone();
+        let toast = use_toast();
+        move |_| {
+            let svg_content = svg_content.clone();
+            let file_name = format!("flamegraph-{plan_id}.png");
+            let toast = toast.clone();
+            spawn_local(async move {
+                match render_svg_to_png(&svg_content).await {
+                    Ok(data_url) => {
+                        let document = document();
+                        if let Ok(element) = document.create_element("a") {
+                            let anchor = element.unchecked_into::<web_sys::HtmlAnchorElement>();
+                            anchor.set_href(&data_url);
+                            anchor.set_download(&file_name);
+                            if let Some(body) = document.body() {
+                                let _ = body.append_child(&anchor);
+                                anchor.click();
+                                let _ = body.remove_child(&anchor);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to export PNG: {e:?}"));
+                    }
+                }
+            });
+        }
+    };
+
+    let container_ref = NodeRef::<leptos::html::Div>::new();
+    // CSS overlay used when the Fullscreen API is unavailable or disallowed, e.g. in an iframe
+    let (overlay, set_overlay) = signal(false);
+
+    let enter_full_screen = move |_| {
+        let Some(container) = container_ref.get() else {
+            return;
+        };
+        // the request is rejected asynchronously when not allowed, so check up front
+        if !document().fullscreen_enabled() || container.request_fullscreen().is_err() {
+            set_overlay.set(true);
+        }
+    };
+
+    let handle = window_event_listener(leptos::ev::keydown, move |ev| {
+        if ev.key() == "Escape" {
+            let document = document();
+            if document.fullscreen_element().is_some() {
+                document.exit_fullscreen();
+            }
+            set_overlay.set(false);
+        }
+    });
+    on_cleanup(move || handle.remove());
+
     view! {
-        <div class="bg-white rounded overflow-auto mt-0">
-            <iframe
-                srcdoc=format!(
-                    "<!DOCTYPE html><html><head><style>body{{margin:0;padding:0;}} svg{{width:100%;height:auto;}}</style></head><body>{}</body></html>",
-                    svg_content,
-                )
-                class="w-full h-[600px] border-0"
-                sandbox="allow-scripts allow-same-origin"
-            ></iframe>
-        </div>
-        <button
-            class="px-3 py-1 border border-gray-200 rounded text-gray-600 hover:bg-gray-50 transition-colors text-xs flex items-center gap-1"
-            on:click=download_svg
+        <div
+            node_ref=container_ref
+            class="bg-white dark:bg-gray-900 [&:fullscreen]:p-2 [&:fullscreen_iframe]:h-[calc(100vh-3rem)]"
+            class=(["fixed", "inset-0", "z-[9999]", "p-2", "overflow-auto"], move || overlay.get())
         >
-            <svg class="w-3 h-3" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                <path
-                    stroke-linecap="round"
-                    stroke-linejoin="round"
-                    stroke-width="2"
-                    d="M12 10v6m0 0l-3-3m3 3l3-3m2 8H7a2 2 0 01-2-2V5a2 2 0 012-2h5.586a1 1 0 01.707.293l5.414 5.414a1 1 0 01.293.707V19a2 2 0 01-2 2z"
-                ></path>
-            </svg>
-            "Download SVG"
-        </button>
+            <div class="bg-white dark:bg-gray-900 rounded overflow-auto mt-0">
+                <iframe
+                    srcdoc=format!(
+                        "<!DOCTYPE html><html><head><style>body{{margin:0;padding:0;}} svg{{width:100%;height:auto;}}</style></head><body>{}</body></html>",
+                        svg_content,
+                    )
+                    class=move || {
+                        if overlay.get() {
+                            "w-full h-[calc(100vh-3rem)] border-0"
+                        } else {
+                            "w-full h-[600px] border-0"
+                        }
+                    }
+                    sandbox="allow-scripts allow-same-origin"
+                ></iframe>
+            </div>
+            <div class="flex gap-2">
+                <button
+                    class="px-3 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs flex items-center gap-1"
+                    on:click=download_svg
+                >
+                    <svg class="w-3 h-3" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                        <path
+                            stroke-linecap="round"
+                            stroke-linejoin="round"
+                            stroke-width="2"
+                            d="M12 10v6m0 0l-3-3m3 3l3-3m2 8H7a2 2 0 01-2-2V5a2 2 0 012-2h5.586a1 1 0 01.707.293l5.414 5.414a1 1 0 01.293.707V19a2 2 0 01-2 2z"
+                        ></path>
+                    </svg>
+                    {move || t("Download SVG")}
+                    <span class="text-gray-400">{format!("({svg_size})")}</span>
+                </button>
+                <button
+                    class="px-3 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs"
+                    on:click=download_png
+                >
+                    {move || t("Download PNG")}
+                </button>
+                <button
+                    class="px-3 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs"
+                    title="Press Escape to exit"
+                    on:click=enter_full_screen
+                >
+                    "Full screen"
+                </button>
+            </div>
+        </div>
     }
 }
@@ -0,0 +1,69 @@
+use leptos::prelude::*;
+
+use crate::models::execution_plan::MetricValues;
+use crate::utils::format_bytes;
+
+fn metric_bytes(metrics: &[MetricValues], name: &str) -> Option<u64> {
+    metrics
+        .iter()
+        .find(|metric| metric.name == name)
+        .and_then(|metric| metric.value.parse::<u64>().ok())
+}
+
+/// Proportional diagram of an operator's memory usage structure,
+/// rendered only for operators reporting `hash_table_size` or `reservoir_size`
+#[component]
+pub fn MemoryLayout(metrics: Vec<MetricValues>) -> impl IntoView {
+    let hash_table = metric_bytes(&metrics, "hash_table_size")
+        .or_else(|| metric_bytes(&metrics, "reservoir_size"));
+    let Some(hash_table) = hash_table else {
+        return ().into_any();
+    };
+    let output_buffer = match metric_bytes(&metrics, "mem_used") {
+        Some(mem_used) => mem_used.saturating_sub(hash_table),
+        None => metric_bytes(&metrics, "output_bytes").unwrap_or(0),
+    };
+    let spill_buffer = metric_bytes(&metrics, "spilled_bytes").unwrap_or(0);
+    let total = (hash_table + output_buffer + spill_buffer).max(1);
+
+    let segments = [
+        ("hash table", hash_table, "bg-indigo-400"),
+        ("output buffer", output_buffer, "bg-teal-400"),
+        ("spill buffer", spill_buffer, "bg-amber-400"),
+    ];
+
+    view! {
+        <div class="mb-3">
+            <div class="text-xs text-gray-500 dark:text-gray-400 mb-1">"Memory layout"</div>
+            <div class="flex h-3 w-full rounded overflow-hidden bg-gray-100 dark:bg-gray-700">
+                {segments
+                    .iter()
+                    .filter(|(_, bytes, _)| *bytes > 0)
+                    .map(|(label, bytes, color)| {
+                        view! {
+                            <div
+                                class=*color
+                                style=format!("width: {:.2}%", *bytes as f64 * 100.0 / total as f64)
+                                title=format!("{label}: {}", format_bytes(*bytes))
+                            ></div>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <div class="flex gap-3 mt-1 text-xs text-gray-500 dark:text-gray-400">
+                {segments
+                    .iter()
+                    .map(|(label, bytes, color)| {
+                        view! {
+                            <span class="flex items-center gap-1">
+                                <span class=format!("inline-block w-2 h-2 rounded-sm {color}")></span>
+                                {format!("{label} {}", format_bytes(*bytes))}
+                            </span>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+    .into_any()
+}
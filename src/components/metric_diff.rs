@@ -0,0 +1,39 @@
+use leptos::prelude::*;
+
+use crate::models::diff::{DiffMetric, MetricDirection};
+
+/// Delta of a metric against the compared plan, colored by whether it improved
+#[component]
+pub fn MetricDiffBadge(
+    diff: DiffMetric,
+    /// Whether the change is statistically significant across recorded samples,
+    /// None when there are too few samples to test
+    #[prop(default = None)]
+    significant: Option<bool>,
+) -> impl IntoView {
+    let color = match diff.direction {
+        MetricDirection::Better => "text-green-700 bg-green-50",
+        MetricDirection::Worse => "text-red-700 bg-red-50",
+        MetricDirection::Neutral => "text-gray-600 dark:text-gray-300 bg-gray-100 dark:bg-gray-700",
+    };
+    let delta = match diff.relative_change() {
+        Some(change) => format!("{:+.1}%", change * 100.0),
+        None => "≠".to_string(),
+    };
+    let title = format!("{} → {}", diff.left, diff.right);
+
+    view! {
+        <span class=format!("text-xs font-mono rounded px-1 {color}") title=title>
+            {delta}
+        </span>
+        {significant
+            .map(|significant| {
+                let (label, class) = if significant {
+                    ("Significant", "text-blue-700 bg-blue-50 dark:text-blue-300 dark:bg-blue-900")
+                } else {
+                    ("Not significant", "text-gray-500 bg-gray-100 dark:text-gray-400 dark:bg-gray-700")
+                };
+                view! { <span class=format!("ml-1 text-xs rounded px-1 {class}")>{label}</span> }
+            })}
+    }
+}
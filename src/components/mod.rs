@@ -1,6 +1,43 @@
+pub mod active_queries;
+pub mod auto_refresh;
+pub mod batch_size_vis;
+pub mod bookmarked_metrics;
+pub mod cache_browser;
 pub mod cache_info;
+pub mod cache_operators;
+pub mod cache_ops;
+pub mod charts;
+pub mod cluster_summary;
+pub mod collapse;
+pub mod dev_panel;
+pub mod dialog;
+pub mod event_log;
+pub mod eviction_policy;
 pub mod execution_plans;
 pub mod flamegraph;
+pub mod memory_layout;
+pub mod metric_diff;
+pub mod nav_tabs;
+pub mod node_detail_drawer;
+pub mod plan_cache_stats;
+pub mod plan_playback;
+pub mod progress;
+pub mod quota_chart;
+pub mod radar_chart;
+pub mod request_headers;
+pub mod row_group_inspector;
+pub mod server_selector;
+pub mod settings;
+pub mod shortcut_help;
+pub mod sla_config;
+pub mod spinner;
 pub mod statistics;
 pub mod system_info;
+pub mod tier_planning;
+pub mod time_breakdown;
 pub mod toast;
+pub mod toast_history;
+pub mod tree_diff;
+pub mod type_histogram;
+pub mod waterfall_compare;
+pub mod zoomable_plan_view;
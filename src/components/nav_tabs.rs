@@ -0,0 +1,34 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_location};
+
+use crate::utils::i18n::t;
+
+const PAGES: [(&str, &str); 2] = [("/", "Home"), ("/metrics", "Metrics")];
+
+/// Links between the dashboard pages, `host` is carried over to the next page
+#[component]
+pub fn NavTabs(#[prop(into)] host: Signal<String>) -> impl IntoView {
+    let location = use_location();
+
+    view! {
+        <nav class="flex border border-gray-200 dark:border-gray-700 rounded overflow-hidden text-xs">
+            {PAGES
+                .map(|(path, label)| {
+                    view! {
+                        <A
+                            href=move || format!("{path}?host={}", urlencoding::encode(&host.get()))
+                            attr:class=move || {
+                                if location.pathname.get() == path {
+                                    "px-3 py-1 bg-gray-100 dark:bg-gray-700 text-gray-800 dark:text-gray-100"
+                                } else {
+                                    "px-3 py-1 text-gray-500 dark:text-gray-400 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                }
+                            }
+                        >
+                            {move || t(label)}
+                        </A>
+                    }
+                })}
+        </nav>
+    }
+}
@@ -0,0 +1,172 @@
+use leptos::prelude::*;
+
+use crate::models::execution_plan::ExecutionPlanWithStats;
+use crate::utils::shortcuts::on_close_modals;
+
+/// Path (e.g. "0.1.0") of the node shown in the detail drawer, plan nodes set it when clicked
+#[derive(Clone, Copy)]
+pub struct SelectedNode(pub RwSignal<Option<String>>);
+
+pub fn provide_selected_node() -> SelectedNode {
+    let selected_node = SelectedNode(RwSignal::new(None));
+    provide_context(selected_node);
+    selected_node
+}
+
+pub fn use_selected_node() -> Option<SelectedNode> {
+    use_context::<SelectedNode>()
+}
+
+/// Side panel sliding in from the right with every metric, schema field and column statistic
+/// of the selected node. Escape or a click on the backdrop closes it.
+#[component]
+pub fn NodeDetailDrawer(node: Signal<Option<ExecutionPlanWithStats>>) -> impl IntoView {
+    let selected_node = use_selected_node();
+    let close = move || {
+        if let Some(selected_node) = selected_node {
+            selected_node.0.set(None);
+        }
+    };
+    on_close_modals(close);
+    let open = move || node.with(Option::is_some);
+    // the last node stays rendered while the drawer slides out
+    let shown = RwSignal::new(None::<ExecutionPlanWithStats>);
+    Effect::new(move || {
+        if let Some(node) = node.get() {
+            shown.set(Some(node));
+        }
+    });
+
+    view! {
+        <div
+            class="fixed inset-0 z-40 bg-black/20 transition-opacity duration-300"
+            class=("opacity-0", move || !open())
+            class=("pointer-events-none", move || !open())
+            on:click=move |_| close()
+        ></div>
+        <aside
+            class="fixed top-0 right-0 z-50 h-full w-[32rem] max-w-full overflow-y-auto bg-white dark:bg-gray-900 border-l border-gray-200 dark:border-gray-700 shadow-lg p-4 transition-transform duration-300"
+            class=("translate-x-full", move || !open())
+            class=("translate-x-0", open)
+        >
+            {move || {
+                shown
+                    .get()
+                    .map(|node| {
+                        view! {
+                            <div class="flex justify-between items-center mb-4">
+                                <h3 class="text-sm font-semibold text-gray-800 dark:text-gray-100 break-all">
+                                    {node.name.clone()}
+                                </h3>
+                                <button
+                                    class="px-2 text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                                    title="Close (Esc)"
+                                    on:click=move |_| close()
+                                >
+                                    "×"
+                                </button>
+                            </div>
+                            <NodeDetails node=node />
+                        }
+                    })
+            }}
+        </aside>
+    }
+}
+
+#[component]
+fn NodeDetails(node: ExecutionPlanWithStats) -> impl IntoView {
+    let statistics = node.statistics;
+    view! {
+        <div class="space-y-4 text-xs">
+            <section>
+                <h4 class="font-medium text-gray-700 dark:text-gray-200 mb-1">
+                    {format!("Metrics ({})", node.metrics.len())}
+                </h4>
+                <table class="w-full">
+                    <tbody>
+                        {node
+                            .metrics
+                            .into_iter()
+                            .map(|metric| {
+                                view! {
+                                    <tr class="border-b border-gray-50 dark:border-gray-800">
+                                        <td class="py-0.5 pr-2 text-gray-500 dark:text-gray-400 break-all">
+                                            {metric.name}
+                                        </td>
+                                        <td class="py-0.5 font-mono text-gray-800 dark:text-gray-100 break-all">
+                                            {metric.value}
+                                        </td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </section>
+            <section>
+                <h4 class="font-medium text-gray-700 dark:text-gray-200 mb-1">
+                    {format!("Schema ({} fields)", node.schema.len())}
+                </h4>
+                <table class="w-full">
+                    <tbody>
+                        {node
+                            .schema
+                            .into_iter()
+                            .map(|field| {
+                                view! {
+                                    <tr class="border-b border-gray-50 dark:border-gray-800">
+                                        <td class="py-0.5 pr-2 text-gray-800 dark:text-gray-100 break-all">
+                                            {field.name}
+                                        </td>
+                                        <td class="py-0.5 font-mono text-gray-500 dark:text-gray-400 break-all">
+                                            {field.data_type}
+                                        </td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </section>
+            <section>
+                <h4 class="font-medium text-gray-700 dark:text-gray-200 mb-1">"Statistics"</h4>
+                <div class="flex gap-4 mb-2 text-gray-600 dark:text-gray-300">
+                    <span>"Rows: " <span class="font-mono">{statistics.num_rows}</span></span>
+                    <span>"Bytes: " <span class="font-mono">{statistics.total_byte_size}</span></span>
+                </div>
+                <table class="w-full">
+                    <thead>
+                        <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                            <th class="py-1 font-normal">"Column"</th>
+                            <th class="py-1 font-normal">"Nulls"</th>
+                            <th class="py-1 font-normal">"Min"</th>
+                            <th class="py-1 font-normal">"Max"</th>
+                            <th class="py-1 font-normal">"Sum"</th>
+                            <th class="py-1 font-normal">"Distinct"</th>
+                        </tr>
+                    </thead>
+                    <tbody class="font-mono text-gray-800 dark:text-gray-100">
+                        {statistics
+                            .column_statistics
+                            .into_iter()
+                            .map(|column| {
+                                let value = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+                                view! {
+                                    <tr class="border-b border-gray-50 dark:border-gray-800 align-top">
+                                        <td class="py-0.5 pr-2 font-sans break-all">{column.name}</td>
+                                        <td class="py-0.5 pr-2 break-all">{value(column.null)}</td>
+                                        <td class="py-0.5 pr-2 break-all">{value(column.min)}</td>
+                                        <td class="py-0.5 pr-2 break-all">{value(column.max)}</td>
+                                        <td class="py-0.5 pr-2 break-all">{value(column.sum)}</td>
+                                        <td class="py-0.5 break-all">{value(column.distinct_count)}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+            </section>
+        </div>
+    }
+}
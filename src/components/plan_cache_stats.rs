@@ -0,0 +1,154 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    components::{cache_info::CacheInfo, toast::use_toast},
+    utils::{fetch_api, fetch_api_with_method, i18n::t, ApiResponse},
+};
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PlanCacheStats {
+    pub size: usize,
+    pub max_size: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+impl PlanCacheStats {
+    fn lookups(&self) -> u64 {
+        self.hits + self.misses
+    }
+
+    fn hit_rate(&self) -> Option<f64> {
+        (self.lookups() > 0).then(|| self.hits as f64 / self.lookups() as f64)
+    }
+
+    fn eviction_rate(&self) -> Option<f64> {
+        (self.lookups() > 0).then(|| self.evictions as f64 / self.lookups() as f64)
+    }
+
+    fn utilization(&self) -> f64 {
+        if self.max_size == 0 {
+            0.0
+        } else {
+            (self.size as f64 / self.max_size as f64).min(1.0)
+        }
+    }
+}
+
+fn format_rate(rate: Option<f64>) -> String {
+    rate.map(|rate| format!("{:.1}%", rate * 100.0))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// Query plan cache statistics, refreshed together with the cache info
+#[component]
+pub fn PlanCacheStats(
+    server_address: ReadSignal<String>,
+    cache_info: ReadSignal<Option<CacheInfo>>,
+) -> impl IntoView {
+    let toast = use_toast();
+    let (stats, set_stats) = signal(None::<PlanCacheStats>);
+
+    let fetch_stats = {
+        let toast = toast.clone();
+        Action::new(move |_: &()| {
+            let address = server_address.get_untracked();
+            let toast = toast.clone();
+
+            async move {
+                match fetch_api::<PlanCacheStats>(&format!("{address}/plan_cache")).await {
+                    Ok(response) => {
+                        set_stats.set(Some(response));
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to fetch plan cache stats: {e}"));
+                    }
+                }
+            }
+        })
+    };
+
+    let clear_plan_cache = {
+        let toast = toast.clone();
+        Action::new(move |_: &()| {
+            let address = server_address.get_untracked();
+            let toast = toast.clone();
+
+            async move {
+                match fetch_api_with_method::<ApiResponse>(
+                    gloo_net::http::Method::DELETE,
+                    &format!("{address}/plan_cache"),
+                )
+                .await
+                {
+                    Ok(response) => {
+                        toast.show_success(response.message);
+                        fetch_stats.dispatch(());
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to clear plan cache: {e}"));
+                    }
+                }
+            }
+        })
+    };
+
+    // Refresh whenever the cache info is refreshed
+    Effect::new(move || {
+        if cache_info.with(Option::is_some) {
+            fetch_stats.dispatch(());
+        }
+    });
+
+    view! {
+        {move || {
+            stats
+                .get()
+                .map(|stats| {
+                    view! {
+                        <div class="text-sm border-t border-gray-100 dark:border-gray-800 pt-3">
+                            <div class="flex justify-between items-center mb-1">
+                                <span class="text-xs font-medium text-gray-600 dark:text-gray-300">"Plan cache"</span>
+                                <button
+                                    class="text-xs text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                                    on:click=move |_| {
+                                        clear_plan_cache.dispatch(());
+                                    }
+                                >
+                                    {move || t("Clear plan cache")}
+                                </button>
+                            </div>
+                            <div class="grid grid-cols-4 gap-y-1 text-xs">
+                                <span class="text-gray-500 dark:text-gray-400">"Hit rate"</span>
+                                <span class="text-gray-800 dark:text-gray-100">{format_rate(stats.hit_rate())}</span>
+
+                                <span class="text-gray-500 dark:text-gray-400">"Eviction rate"</span>
+                                <span class="text-gray-800 dark:text-gray-100">
+                                    {format_rate(stats.eviction_rate())}
+                                </span>
+
+                                <span class="text-gray-500 dark:text-gray-400">"Entries"</span>
+                                <span class="text-gray-800 dark:text-gray-100">
+                                    {format!("{} / {}", stats.size, stats.max_size)}
+                                </span>
+
+                                <span class="text-gray-500 dark:text-gray-400">"Evictions"</span>
+                                <span class="text-gray-800 dark:text-gray-100">{stats.evictions}</span>
+                            </div>
+                            <div
+                                class="mt-2 h-1.5 w-full rounded bg-gray-100 dark:bg-gray-700 overflow-hidden"
+                                title=format!("{:.1}% utilized", stats.utilization() * 100.0)
+                            >
+                                <div
+                                    class="h-full bg-blue-400"
+                                    style=format!("width: {:.1}%", stats.utilization() * 100.0)
+                                ></div>
+                            </div>
+                        </div>
+                    }
+                })
+        }}
+    }
+}
@@ -0,0 +1,143 @@
+use leptos::prelude::*;
+use std::time::Duration;
+
+use crate::models::metric_history::PlanVersion;
+use crate::utils::format_timestamp;
+
+const PLAYBACK_STEP_MS: u64 = 1_000;
+/// Versions beyond this many only label every n-th tick
+const MAX_TICK_LABELS: usize = 8;
+
+/// Slider over the recorded versions of a plan, `version_id` is `None` for the live plan.
+/// Playing steps forward once a second and stops at the live plan. The selected version stays
+/// selected while new versions are recorded, and falls back to live once it is dropped.
+#[component]
+pub fn PlanPlayback(
+    #[prop(into)] versions: Signal<Vec<PlanVersion>>,
+    version_id: RwSignal<Option<u64>>,
+) -> impl IntoView {
+    let (playing, set_playing) = signal(false);
+    let last = move || versions.with(|versions| versions.len().saturating_sub(1));
+    // index of the selected version, `None` when live
+    let selected = move || {
+        let id = version_id.get()?;
+        versions.with(|versions| versions.iter().position(|version| version.id == id))
+    };
+    let position = move || selected().unwrap_or_else(last);
+    let select = move |index: usize| {
+        let id = versions
+            .with_untracked(|versions| (index + 1 < versions.len()).then(|| versions[index].id));
+        version_id.set(id);
+    };
+
+    let timer = StoredValue::new(None::<IntervalHandle>);
+    let clear_timer = move || {
+        if let Some(handle) = timer.get_value() {
+            handle.clear();
+            timer.set_value(None);
+        }
+    };
+    Effect::new(move || {
+        clear_timer();
+        if !playing.get() {
+            return;
+        }
+        let handle = set_interval_with_handle(
+            move || {
+                let next = untrack(position) + 1;
+                select(next);
+                if next >= untrack(last) {
+                    set_playing.set(false);
+                }
+            },
+            Duration::from_millis(PLAYBACK_STEP_MS),
+        );
+        timer.set_value(handle.ok());
+    });
+    on_cleanup(clear_timer);
+
+    let ticks = move || {
+        let last = last();
+        versions.with(|versions| {
+            let label_every = versions.len().div_ceil(MAX_TICK_LABELS).max(1);
+            versions
+                .iter()
+                .enumerate()
+                .map(|(index, version)| {
+                    let left = if last == 0 {
+                        0.0
+                    } else {
+                        index as f64 * 100.0 / last as f64
+                    };
+                    let time = format_timestamp(version.recorded_at);
+                    let label = (index % label_every == 0 || index == last).then(|| time.clone());
+                    view! {
+                        <span
+                            class="absolute top-0 -translate-x-1/2 flex flex-col items-center"
+                            style:left=format!("{left:.2}%")
+                            title=time
+                        >
+                            <span class="w-px h-1.5 bg-gray-300 dark:bg-gray-600"></span>
+                            {label
+                                .map(|label| {
+                                    view! { <span class="font-mono text-gray-400">{label}</span> }
+                                })}
+                        </span>
+                    }
+                })
+                .collect_view()
+        })
+    };
+    let current_time = move || {
+        versions.with(|versions| {
+            versions
+                .get(position())
+                .map(|version| format_timestamp(version.recorded_at))
+                .unwrap_or_default()
+        })
+    };
+
+    view! {
+        <div class="mb-4 border border-gray-100 dark:border-gray-800 rounded p-3 text-xs">
+            <div class="flex items-center gap-3">
+                <button
+                    class="w-14 px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                    on:click=move |_| {
+                        // playing from the live plan starts over at the oldest version
+                        if !playing.get_untracked() && untrack(selected).is_none() {
+                            select(0);
+                        }
+                        set_playing.update(|playing| *playing = !*playing);
+                    }
+                >
+                    {move || if playing.get() { "Pause" } else { "Play" }}
+                </button>
+                <div class="flex-1">
+                    <input
+                        type="range"
+                        class="w-full"
+                        min="0"
+                        max=move || last().to_string()
+                        prop:value=move || position().to_string()
+                        on:input=move |ev| {
+                            set_playing.set(false);
+                            if let Ok(index) = event_target_value(&ev).parse() {
+                                select(index);
+                            }
+                        }
+                    />
+                    <div class="relative h-5">{ticks}</div>
+                </div>
+                <span class="w-28 text-right text-gray-600 dark:text-gray-300">
+                    {move || {
+                        if selected().is_some() {
+                            format!("{} / {}: {}", position() + 1, last() + 1, current_time())
+                        } else {
+                            "Live".to_string()
+                        }
+                    }}
+                </span>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,36 @@
+use leptos::prelude::*;
+
+/// Fill level above which the bar is red
+const CRITICAL_PCT: f64 = 95.0;
+
+/// Horizontal bar of `usage / max`, green below `warn_pct`, yellow up to 95% and red above
+#[component]
+pub fn UsageBar(
+    usage: u64,
+    max: u64,
+    /// Percentage at which the bar turns yellow
+    #[prop(default = 80.0)]
+    warn_pct: f64,
+) -> impl IntoView {
+    let pct = if max == 0 {
+        0.0
+    } else {
+        (usage as f64 / max as f64 * 100.0).min(100.0)
+    };
+    let color = if pct > CRITICAL_PCT {
+        "bg-red-400"
+    } else if pct >= warn_pct {
+        "bg-yellow-400"
+    } else {
+        "bg-green-400"
+    };
+
+    view! {
+        <div class="relative h-4 w-full rounded bg-gray-100 dark:bg-gray-800 overflow-hidden">
+            <div class=format!("h-full {color}") style:width=format!("{pct:.1}%")></div>
+            <span class="absolute inset-0 flex items-center justify-center text-xs font-mono text-gray-800 dark:text-gray-100">
+                {format!("{pct:.1}%")}
+            </span>
+        </div>
+    }
+}
@@ -0,0 +1,130 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    components::toast::use_toast,
+    utils::{fetch_api, format_bytes, i18n::t},
+};
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct QuotaEntry {
+    /// Top-level directory prefix, e.g. "/data/table1/"
+    pub prefix: String,
+    pub used_bytes: u64,
+    pub quota_bytes: Option<u64>,
+}
+
+impl QuotaEntry {
+    fn capacity(&self) -> u64 {
+        self.quota_bytes.unwrap_or(0).max(self.used_bytes)
+    }
+
+    fn bar_color(&self) -> &'static str {
+        match self.quota_bytes {
+            Some(quota) if self.used_bytes as f64 >= quota as f64 * 0.9 => "bg-red-400",
+            Some(_) => "bg-blue-400",
+            None => "bg-gray-400",
+        }
+    }
+}
+
+#[component]
+pub fn QuotaChart(server_address: ReadSignal<String>) -> impl IntoView {
+    let toast = use_toast();
+    let (entries, set_entries) = signal(None::<Vec<QuotaEntry>>);
+
+    let fetch_quota = Action::new(move |_: &()| {
+        let address = server_address.get_untracked();
+        let toast = toast.clone();
+
+        async move {
+            match fetch_api::<Vec<QuotaEntry>>(&format!("{address}/cache_quota")).await {
+                Ok(response) => {
+                    set_entries.set(Some(response));
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to fetch cache quota: {e}"));
+                }
+            }
+        }
+    });
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Quota by prefix")}</h2>
+                <button
+                    class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                    on:click=move |_| {
+                        fetch_quota.dispatch(());
+                    }
+                >
+                    {move || t("Refresh")}
+                </button>
+            </div>
+            {move || match entries.get() {
+                Some(entries) => {
+                    let scale = entries.iter().map(QuotaEntry::capacity).max().unwrap_or(0).max(1)
+                        as f64;
+                    view! {
+                        <div class="space-y-2">
+                            {entries
+                                .into_iter()
+                                .map(|entry| {
+                                    let used_pct = entry.used_bytes as f64 * 100.0 / scale;
+                                    let free_pct = entry
+                                        .quota_bytes
+                                        .map(|quota| {
+                                            quota.saturating_sub(entry.used_bytes) as f64 * 100.0
+                                                / scale
+                                        })
+                                        .unwrap_or(0.0);
+                                    let label = match entry.quota_bytes {
+                                        Some(quota) => {
+                                            format!(
+                                                "{} / {}",
+                                                format_bytes(entry.used_bytes),
+                                                format_bytes(quota),
+                                            )
+                                        }
+                                        None => format!("{} (no quota)", format_bytes(entry.used_bytes)),
+                                    };
+                                    view! {
+                                        <div class="text-xs">
+                                            <div class="flex justify-between mb-0.5">
+                                                <span
+                                                    class="font-mono text-gray-700 dark:text-gray-200 truncate"
+                                                    title=entry.prefix.clone()
+                                                >
+                                                    {entry.prefix.clone()}
+                                                </span>
+                                                <span class="text-gray-500 dark:text-gray-400">{label}</span>
+                                            </div>
+                                            <div class="flex h-2 w-full rounded bg-gray-50 dark:bg-gray-800 overflow-hidden">
+                                                <div
+                                                    class=entry.bar_color()
+                                                    style=format!("width: {used_pct:.2}%")
+                                                ></div>
+                                                <div
+                                                    class="bg-gray-200 dark:bg-gray-700"
+                                                    style=format!("width: {free_pct:.2}%")
+                                                ></div>
+                                            </div>
+                                        </div>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <div class="text-gray-400 text-xs italic">"Refresh to view cache quotas"</div>
+                    }
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
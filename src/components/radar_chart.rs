@@ -0,0 +1,155 @@
+use leptos::prelude::*;
+use std::f64::consts::PI;
+
+use crate::models::execution_plan::ExecutionPlanWithStats;
+
+/// Metrics compared on the axes, summed over all nodes of a plan
+const RADAR_METRICS: [&str; 5] = [
+    "elapsed_compute",
+    "output_rows",
+    "bytes_read",
+    "mem_used",
+    "spill_bytes",
+];
+
+const SIZE: f64 = 260.0;
+const RADIUS: f64 = 90.0;
+const LABEL_OFFSET: f64 = 16.0;
+const RINGS: [f64; 4] = [0.25, 0.5, 0.75, 1.0];
+
+fn radar_value(plan: &ExecutionPlanWithStats, metric: &str) -> f64 {
+    if metric == "bytes_read" {
+        plan.nodes_with_paths()
+            .into_iter()
+            .filter_map(|(_, node)| node.bytes_read())
+            .sum()
+    } else {
+        plan.total_metric(metric)
+    }
+}
+
+/// Point at `fraction` of the radius on the axis of metric `index`
+fn axis_point(index: usize, fraction: f64) -> (f64, f64) {
+    let angle = 2.0 * PI * index as f64 / RADAR_METRICS.len() as f64 - PI / 2.0;
+    let center = SIZE / 2.0;
+    (
+        center + RADIUS * fraction * angle.cos(),
+        center + RADIUS * fraction * angle.sin(),
+    )
+}
+
+fn polygon_points(fractions: &[f64]) -> String {
+    fractions
+        .iter()
+        .enumerate()
+        .map(|(index, fraction)| {
+            let (x, y) = axis_point(index, *fraction);
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether the plans have the same operators, otherwise the totals are not comparable
+pub fn radar_comparable(left: &ExecutionPlanWithStats, right: &ExecutionPlanWithStats) -> bool {
+    left.operator_types() == right.operator_types()
+}
+
+/// Two plans overlaid on axes of key metrics, each axis normalized to the larger plan
+#[component]
+pub fn RadarChart(
+    left: ExecutionPlanWithStats,
+    right: ExecutionPlanWithStats,
+    left_label: String,
+    right_label: String,
+) -> impl IntoView {
+    let left_values = RADAR_METRICS.map(|metric| radar_value(&left, metric));
+    let right_values = RADAR_METRICS.map(|metric| radar_value(&right, metric));
+    let normalize = |values: &[f64; 5]| {
+        values
+            .iter()
+            .zip(left_values.iter().zip(right_values.iter()))
+            .map(|(value, (l, r))| {
+                let max = l.max(*r);
+                if max > 0.0 {
+                    value / max
+                } else {
+                    0.0
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+    let left_points = polygon_points(&normalize(&left_values));
+    let right_points = polygon_points(&normalize(&right_values));
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4 mb-4 flex items-center gap-6">
+            <svg width=SIZE height=SIZE viewBox=format!("0 0 {SIZE} {SIZE}")>
+                {RINGS
+                    .map(|ring| {
+                        view! {
+                            <polygon
+                                points=polygon_points(&[ring; 5])
+                                fill="none"
+                                class="stroke-gray-200 dark:stroke-gray-700"
+                            />
+                        }
+                    })
+                    .collect_view()}
+                {RADAR_METRICS
+                    .iter()
+                    .enumerate()
+                    .map(|(index, metric)| {
+                        let (x, y) = axis_point(index, 1.0);
+                        let (label_x, label_y) = axis_point(index, 1.0 + LABEL_OFFSET / RADIUS);
+                        view! {
+                            <line
+                                x1=SIZE / 2.0
+                                y1=SIZE / 2.0
+                                x2=x
+                                y2=y
+                                class="stroke-gray-200 dark:stroke-gray-700"
+                            />
+                            <text
+                                x=label_x
+                                y=label_y
+                                text-anchor="middle"
+                                dominant-baseline="middle"
+                                font-size="9"
+                                class="fill-gray-500 dark:fill-gray-400"
+                            >
+                                {*metric}
+                            </text>
+                        }
+                    })
+                    .collect_view()}
+                <polygon
+                    points=left_points
+                    fill="#3b82f6"
+                    fill-opacity="0.25"
+                    stroke="#3b82f6"
+                    stroke-width="1.5"
+                />
+                <polygon
+                    points=right_points
+                    fill="#f97316"
+                    fill-opacity="0.25"
+                    stroke="#f97316"
+                    stroke-width="1.5"
+                />
+            </svg>
+            <div class="space-y-1 text-xs">
+                <div class="font-medium text-gray-700 dark:text-gray-200">"Metric comparison"</div>
+                <div class="flex items-center gap-1 text-gray-600 dark:text-gray-300">
+                    <span class="inline-block w-3 h-3 rounded-sm bg-blue-500/50 border border-blue-500"></span>
+                    {left_label}
+                </div>
+                <div class="flex items-center gap-1 text-gray-600 dark:text-gray-300">
+                    <span class="inline-block w-3 h-3 rounded-sm bg-orange-500/50 border border-orange-500"></span>
+                    {right_label}
+                </div>
+                <div class="text-gray-400">"Each axis is scaled to the larger of the two plans"</div>
+            </div>
+        </div>
+    }
+}
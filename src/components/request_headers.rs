@@ -0,0 +1,119 @@
+use leptos::prelude::*;
+
+use crate::utils::config::{is_valid_header, use_request_config};
+use crate::utils::i18n::t;
+
+/// Editable list of custom headers sent with every request
+#[component]
+pub fn RequestHeadersEditor() -> impl IntoView {
+    let request_config = use_request_config();
+    let (expanded, set_expanded) = signal(false);
+
+    let header_count = move || {
+        request_config.headers.with(|headers| {
+            headers
+                .iter()
+                .filter(|(name, value)| is_valid_header(name, value))
+                .count()
+        })
+    };
+    let update_header = move |index: usize, name: Option<String>, value: Option<String>| {
+        request_config.headers.update(|headers| {
+            if let Some(header) = headers.get_mut(index) {
+                if let Some(name) = name {
+                    header.0 = name;
+                }
+                if let Some(value) = value {
+                    header.1 = value;
+                }
+            }
+        });
+    };
+
+    view! {
+        <div class="mt-2 text-xs">
+            <button
+                class="flex items-center gap-1 text-gray-600 dark:text-gray-300"
+                on:click=move |_| set_expanded.update(|e| *e = !*e)
+            >
+                {move || if expanded.get() { "▾" } else { "▸" }}
+                "Configure headers"
+                <span class="text-gray-400">
+                    {move || match header_count() {
+                        0 => String::new(),
+                        count => format!("({count})"),
+                    }}
+                </span>
+            </button>
+            <Show when=move || expanded.get()>
+                <div class="mt-2 space-y-1">
+                    {move || {
+                        // rows are re-rendered on commit (change), not on every keystroke
+                        let count = request_config.headers.with(Vec::len);
+                        (0..count)
+                            .map(|index| {
+                                let (name, value) = request_config
+                                    .headers
+                                    .with_untracked(|headers| headers[index].clone());
+                                let invalid = !name.trim().is_empty() && !is_valid_header(&name, &value);
+                                view! {
+                                    <div class="flex items-center gap-2">
+                                        <input
+                                            type="text"
+                                            placeholder="X-Api-Key"
+                                            class="w-48 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded font-mono text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                                            prop:value=name
+                                            on:change=move |ev| {
+                                                update_header(index, Some(event_target_value(&ev)), None)
+                                            }
+                                        />
+                                        <input
+                                            type="password"
+                                            placeholder="Value"
+                                            autocomplete="off"
+                                            class="w-72 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded font-mono text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                                            prop:value=value
+                                            on:change=move |ev| {
+                                                update_header(index, None, Some(event_target_value(&ev)))
+                                            }
+                                        />
+                                        <button
+                                            class="text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                                            title="Remove header"
+                                            on:click=move |_| {
+                                                request_config
+                                                    .headers
+                                                    .update(|headers| {
+                                                        headers.remove(index);
+                                                    })
+                                            }
+                                        >
+                                            "✕"
+                                        </button>
+                                        <Show when=move || invalid>
+                                            <span
+                                                class="text-red-500 dark:text-red-400"
+                                                title="Header names must be HTTP tokens and values printable ASCII"
+                                            >
+                                                "Invalid, not sent"
+                                            </span>
+                                        </Show>
+                                    </div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                    <button
+                        class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                        on:click=move |_| {
+                            request_config.headers.update(|headers| headers.push(Default::default()))
+                        }
+                    >
+                        {move || t("Add header")}
+                    </button>
+                    <div class="text-gray-400">"Headers are kept for this tab only"</div>
+                </div>
+            </Show>
+        </div>
+    }
+}
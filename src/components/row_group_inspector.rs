@@ -0,0 +1,175 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    components::toast::use_toast,
+    utils::{fetch_api, format_bytes, format_number},
+};
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ColumnChunkStats {
+    pub name: String,
+    pub encoding: String,
+    pub compressed_size: u64,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct RowGroupStats {
+    pub offset: u64,
+    pub rows: u64,
+    pub compressed_size: u64,
+    pub columns: Vec<ColumnChunkStats>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ParquetFileStats {
+    pub row_groups: Vec<RowGroupStats>,
+}
+
+#[component]
+pub fn RowGroupInspector(
+    server_address: ReadSignal<String>,
+    path: String,
+    on_close: Callback<()>,
+) -> impl IntoView {
+    let toast = use_toast();
+    let (file_stats, set_file_stats) = signal(None::<ParquetFileStats>);
+    let (selected_row_group, set_selected_row_group) = signal(0usize);
+    let (expanded, set_expanded) = signal(true);
+
+    let fetch_stats = {
+        let path = path.clone();
+        Action::new(move |_: &()| {
+            let address = server_address.get_untracked();
+            let path = path.clone();
+            let toast = toast.clone();
+
+            async move {
+                match fetch_api::<ParquetFileStats>(&format!(
+                    "{address}/cache_files/stats?path={}",
+                    urlencoding::encode(&path)
+                ))
+                .await
+                {
+                    Ok(response) => {
+                        set_selected_row_group.set(0);
+                        set_file_stats.set(Some(response));
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to fetch parquet stats: {e}"));
+                    }
+                }
+            }
+        })
+    };
+    fetch_stats.dispatch(());
+
+    view! {
+        <div class="border border-gray-100 dark:border-gray-800 rounded mt-3 p-3 text-xs">
+            <div class="flex justify-between items-center">
+                <button
+                    class="flex items-center gap-1 text-gray-600 dark:text-gray-300 font-medium truncate"
+                    title=path.clone()
+                    on:click=move |_| set_expanded.update(|e| *e = !*e)
+                >
+                    <span>{move || if expanded.get() { "▾" } else { "▸" }}</span>
+                    {format!("Row groups of {path}")}
+                </button>
+                <button
+                    class="text-gray-400 hover:text-gray-600 dark:hover:text-gray-200 ml-2"
+                    on:click=move |_| on_close.run(())
+                >
+                    "✕"
+                </button>
+            </div>
+            <Show when=move || expanded.get()>
+                {move || match file_stats.get() {
+                    Some(stats) if !stats.row_groups.is_empty() => {
+                        let row_group_count = stats.row_groups.len();
+                        let row_group = stats
+                            .row_groups
+                            .get(selected_row_group.get())
+                            .cloned()
+                            .unwrap_or_else(|| stats.row_groups[0].clone());
+                        view! {
+                            <div class="mt-2 space-y-2">
+                                <div class="flex items-center gap-3">
+                                    <select
+                                        class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 focus:outline-none"
+                                        prop:value=move || selected_row_group.get().to_string()
+                                        on:change=move |ev| {
+                                            if let Ok(index) = event_target_value(&ev).parse() {
+                                                set_selected_row_group.set(index);
+                                            }
+                                        }
+                                    >
+                                        {(0..row_group_count)
+                                            .map(|index| {
+                                                view! {
+                                                    <option value=index
+                                                        .to_string()>{format!("Row group {index}")}</option>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </select>
+                                    <span class="text-gray-500 dark:text-gray-400">
+                                        "Offset " <span class="font-mono text-gray-800 dark:text-gray-100">{row_group.offset}</span>
+                                    </span>
+                                    <span class="text-gray-500 dark:text-gray-400">
+                                        "Rows "
+                                        <span class="font-mono text-gray-800 dark:text-gray-100">
+                                            {format_number(&row_group.rows.to_string())}
+                                        </span>
+                                    </span>
+                                    <span class="text-gray-500 dark:text-gray-400">
+                                        "Compressed "
+                                        <span class="font-mono text-gray-800 dark:text-gray-100">
+                                            {format_bytes(row_group.compressed_size)}
+                                        </span>
+                                    </span>
+                                </div>
+                                <table class="w-full">
+                                    <thead>
+                                        <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                            <th class="py-1 font-normal">"Column"</th>
+                                            <th class="py-1 font-normal">"Encoding"</th>
+                                            <th class="py-1 font-normal">"Compressed size"</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                        {row_group
+                                            .columns
+                                            .into_iter()
+                                            .map(|column| {
+                                                view! {
+                                                    <tr class="border-b border-gray-50 dark:border-gray-800">
+                                                        <td class="py-1 text-gray-800 dark:text-gray-100">{column.name}</td>
+                                                        <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                                            {column.encoding}
+                                                        </td>
+                                                        <td class="py-1 text-gray-800 dark:text-gray-100">
+                                                            {format_bytes(column.compressed_size)}
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </tbody>
+                                </table>
+                            </div>
+                        }
+                            .into_any()
+                    }
+                    Some(_) => {
+                        view! { <div class="mt-2 text-gray-400 italic">"No row groups"</div> }
+                            .into_any()
+                    }
+                    None => {
+                        view! { <div class="mt-2 text-gray-400 italic">"Loading..."</div> }
+                            .into_any()
+                    }
+                }}
+            </Show>
+        </div>
+    }
+}
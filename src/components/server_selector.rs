@@ -0,0 +1,193 @@
+use leptos::prelude::*;
+
+use crate::models::profile::ConnectionProfile;
+use crate::utils::i18n::t;
+use crate::utils::shortcuts::on_close_modals;
+
+const ADD_PROFILE_VALUE: &str = "__add__";
+
+/// Dropdown of saved connection profiles with an add modal and inline rename/delete
+#[component]
+pub fn ServerSelector(
+    profiles: RwSignal<Vec<ConnectionProfile>>,
+    server_address: ReadSignal<String>,
+    /// Called with the address of the selected profile
+    on_select: Callback<String>,
+) -> impl IntoView {
+    let (show_add_modal, set_show_add_modal) = signal(false);
+    let (show_manage, set_show_manage) = signal(false);
+    on_close_modals(move || {
+        set_show_add_modal.set(false);
+        set_show_manage.set(false);
+    });
+    let (new_name, set_new_name) = signal(String::new());
+    let (new_address, set_new_address) = signal(String::new());
+
+    Effect::new(move |_| {
+        profiles.with(|profiles| ConnectionProfile::save_all(profiles));
+    });
+
+    let active_index = move || {
+        let address = server_address.get();
+        profiles.with(|profiles| {
+            profiles
+                .iter()
+                .position(|profile| profile.address == address)
+                .map(|index| index.to_string())
+                .unwrap_or_default()
+        })
+    };
+
+    let open_add_modal = move || {
+        set_new_name.set(String::new());
+        set_new_address.set(server_address.get_untracked());
+        set_show_add_modal.set(true);
+    };
+
+    let save_profile = move |_| {
+        let name = new_name.get().trim().to_string();
+        let address = new_address.get().trim().to_string();
+        if name.is_empty() || address.is_empty() {
+            return;
+        }
+        profiles.update(|profiles| {
+            profiles.push(ConnectionProfile {
+                name,
+                address: address.clone(),
+            })
+        });
+        set_show_add_modal.set(false);
+        on_select.run(address);
+    };
+
+    view! {
+        <div class="relative flex items-center gap-1">
+            <select
+                class="px-2 py-2 border border-gray-200 dark:border-gray-700 rounded text-sm text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900 focus:outline-none focus:border-gray-400"
+                prop:value=active_index
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if value == ADD_PROFILE_VALUE {
+                        open_add_modal();
+                    } else if let Ok(index) = value.parse::<usize>() {
+                        if let Some(profile) = profiles.with(|profiles| profiles.get(index).cloned()) {
+                            on_select.run(profile.address);
+                        }
+                    }
+                }
+            >
+                <option value="" disabled=true>
+                    "Saved servers…"
+                </option>
+                {move || {
+                    profiles
+                        .get()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, profile)| {
+                            view! { <option value=index.to_string()>{profile.name}</option> }
+                        })
+                        .collect_view()
+                }}
+                <option value=ADD_PROFILE_VALUE>"Add new…"</option>
+            </select>
+            <button
+                class="px-2 py-2 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                title="Rename or delete saved servers"
+                on:click=move |_| set_show_manage.update(|s| *s = !*s)
+            >
+                "Manage"
+            </button>
+
+            <Show when=move || show_manage.get()>
+                <div class="absolute z-10 left-0 top-full mt-1 p-3 bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded shadow-sm text-xs space-y-1 min-w-72">
+                    {move || {
+                        if profiles.with(|profiles| profiles.is_empty()) {
+                            view! { <div class="text-gray-500 dark:text-gray-400">"No saved servers"</div> }.into_any()
+                        } else {
+                            profiles
+                                .get()
+                                .into_iter()
+                                .enumerate()
+                                .map(|(index, profile)| {
+                                    view! {
+                                        <div class="flex items-center gap-2">
+                                            <input
+                                                type="text"
+                                                class="w-28 px-1 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                                                prop:value=profile.name
+                                                on:change=move |ev| {
+                                                    let name = event_target_value(&ev).trim().to_string();
+                                                    if !name.is_empty() {
+                                                        profiles
+                                                            .update(|profiles| {
+                                                                if let Some(profile) = profiles.get_mut(index) {
+                                                                    profile.name = name;
+                                                                }
+                                                            });
+                                                    }
+                                                }
+                                            />
+                                            <span class="flex-1 font-mono text-gray-500 dark:text-gray-400 truncate">
+                                                {profile.address}
+                                            </span>
+                                            <button
+                                                class="text-gray-400 hover:text-red-600"
+                                                title="Delete"
+                                                on:click=move |_| {
+                                                    profiles
+                                                        .update(|profiles| {
+                                                            profiles.remove(index);
+                                                        })
+                                                }
+                                            >
+                                                "✕"
+                                            </button>
+                                        </div>
+                                    }
+                                })
+                                .collect_view()
+                                .into_any()
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show when=move || show_add_modal.get()>
+                <div class="fixed inset-0 z-40 flex items-center justify-center bg-black/20">
+                    <div class="w-96 p-4 bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded shadow-lg space-y-3">
+                        <h3 class="text-sm font-medium text-gray-800 dark:text-gray-100">{move || t("Add server")}</h3>
+                        <input
+                            type="text"
+                            placeholder="Name"
+                            class="w-full px-3 py-2 border border-gray-200 dark:border-gray-700 rounded text-sm text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                            prop:value=new_name
+                            on:input=move |ev| set_new_name.set(event_target_value(&ev))
+                        />
+                        <input
+                            type="text"
+                            placeholder="http://localhost:53703"
+                            class="w-full px-3 py-2 border border-gray-200 dark:border-gray-700 rounded text-sm text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                            prop:value=new_address
+                            on:input=move |ev| set_new_address.set(event_target_value(&ev))
+                        />
+                        <div class="flex justify-end gap-2">
+                            <button
+                                class="px-3 py-1 border border-gray-200 dark:border-gray-700 rounded text-sm text-gray-600 dark:text-gray-300 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                                on:click=move |_| set_show_add_modal.set(false)
+                            >
+                                {move || t("Cancel")}
+                            </button>
+                            <button
+                                class="px-3 py-1 border border-gray-300 rounded text-sm text-gray-800 dark:text-gray-100 bg-gray-50 dark:bg-gray-800 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                                on:click=save_profile
+                            >
+                                {move || t("Save")}
+                            </button>
+                        </div>
+                    </div>
+                </div>
+            </Show>
+        </div>
+    }
+}
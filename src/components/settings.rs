@@ -0,0 +1,338 @@
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+
+use crate::models::bookmark::NodeBookmark;
+use crate::models::execution_plan::{ExecutionStats, ExecutionStatsWithPlan};
+use crate::utils::{glob_match, i18n::t, storage};
+
+const IGNORED_METRICS_KEY: &str = "liquid_cache_admin.ignored_metrics";
+const PLAN_SORT_KEY: &str = "liquid_cache_admin.plan_sort";
+const BOOKMARKS_KEY: &str = "liquid_cache_admin.bookmarks";
+const SIGNIFICANCE_LEVEL_KEY: &str = "liquid_cache_admin.significance_level";
+const LAYOUT_PRESET_KEY: &str = "liquid_cache_admin.layout_preset";
+const SLOW_QUERY_THRESHOLD_KEY: &str = "liquid_cache_admin.slow_query_threshold_ms";
+
+/// Default α for marking metric differences as significant
+pub const DEFAULT_SIGNIFICANCE_LEVEL: f64 = 0.05;
+/// Default execution time above which a query is flagged as slow
+pub const DEFAULT_SLOW_QUERY_THRESHOLD_MS: u64 = 1_000;
+
+/// Order of the execution plan list, the first plan is auto-selected
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum PlanSortOrder {
+    #[default]
+    NewestFirst,
+    SlowestFirst,
+}
+
+impl PlanSortOrder {
+    pub fn sort(&self, execution_stats: &mut [ExecutionStatsWithPlan]) {
+        match self {
+            PlanSortOrder::NewestFirst => execution_stats.sort_by_key(|stats| {
+                Reverse(stats.plans.first().map(|plan| plan.created_at).unwrap_or(0))
+            }),
+            PlanSortOrder::SlowestFirst => execution_stats
+                .sort_by_key(|stats| Reverse(stats.execution_stats.execution_time_ms)),
+        }
+    }
+}
+
+/// Which dashboard panels are expanded
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum LayoutPreset {
+    /// All panels visible at default size
+    #[default]
+    Overview,
+    /// Execution plans maximized, system and cache collapsed
+    Debug,
+    /// System and cache expanded, execution plans collapsed
+    Monitor,
+}
+
+impl LayoutPreset {
+    pub const ALL: [LayoutPreset; 3] = [
+        LayoutPreset::Overview,
+        LayoutPreset::Debug,
+        LayoutPreset::Monitor,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LayoutPreset::Overview => "Overview",
+            LayoutPreset::Debug => "Debug",
+            LayoutPreset::Monitor => "Monitor",
+        }
+    }
+}
+
+/// Expansion state of each dashboard section, reset whenever a preset is applied
+#[derive(Clone, Copy)]
+pub struct PanelLayout {
+    pub system: RwSignal<bool>,
+    pub cache: RwSignal<bool>,
+    pub execution_plans: RwSignal<bool>,
+    /// Cache browser, quota, eviction policy, events, active queries and SLAs
+    pub secondary: RwSignal<bool>,
+}
+
+impl PanelLayout {
+    /// Sections follow the preset, re-applied whenever `preset` changes
+    pub fn new(preset: RwSignal<LayoutPreset>) -> Self {
+        let layout = Self {
+            system: RwSignal::new(true),
+            cache: RwSignal::new(true),
+            execution_plans: RwSignal::new(true),
+            secondary: RwSignal::new(true),
+        };
+        Effect::new(move || layout.apply(preset.get()));
+        layout
+    }
+
+    fn apply(&self, preset: LayoutPreset) {
+        let (system_and_cache, execution_plans, secondary) = match preset {
+            LayoutPreset::Overview => (true, true, true),
+            LayoutPreset::Debug => (false, true, false),
+            LayoutPreset::Monitor => (true, false, true),
+        };
+        self.system.set(system_and_cache);
+        self.cache.set(system_and_cache);
+        self.execution_plans.set(execution_plans);
+        self.secondary.set(secondary);
+    }
+}
+
+/// User preferences shared across the dashboard, persisted to localStorage
+#[derive(Clone, Copy)]
+pub struct Settings {
+    /// Glob-style patterns of metric names to hide, e.g. "*_baseline"
+    pub ignored_metrics: RwSignal<Vec<String>>,
+    /// Order of the execution plan selector
+    pub plan_sort: RwSignal<PlanSortOrder>,
+    /// Node metrics shown prominently for every plan
+    pub bookmarks: RwSignal<Vec<NodeBookmark>>,
+    /// α used when testing metric differences between plans
+    pub significance_level: RwSignal<f64>,
+    /// Last applied dashboard layout
+    pub layout_preset: RwSignal<LayoutPreset>,
+    /// Execution time above which a query is flagged as slow
+    pub slow_query_threshold_ms: RwSignal<u64>,
+}
+
+impl Settings {
+    pub fn is_bookmarked(&self, node_name: &str, metric_name: &str) -> bool {
+        self.bookmarks.with(|bookmarks| {
+            bookmarks
+                .iter()
+                .any(|bookmark| bookmark.matches(node_name, metric_name))
+        })
+    }
+
+    /// Bookmark the metric of the node, or remove the bookmarks matching it
+    pub fn toggle_bookmark(&self, node_name: &str, metric_name: &str) {
+        self.bookmarks.update(|bookmarks| {
+            if bookmarks
+                .iter()
+                .any(|bookmark| bookmark.matches(node_name, metric_name))
+            {
+                bookmarks.retain(|bookmark| !bookmark.matches(node_name, metric_name));
+            } else {
+                bookmarks.push(NodeBookmark {
+                    node_name_pattern: node_name.to_string(),
+                    metric_name: metric_name.to_string(),
+                });
+            }
+        });
+    }
+
+    pub fn is_slow_query(&self, stats: &ExecutionStats) -> bool {
+        stats.execution_time_ms > self.slow_query_threshold_ms.get()
+    }
+
+    pub fn is_metric_ignored(&self, metric_name: &str) -> bool {
+        self.ignored_metrics.with(|patterns| {
+            patterns
+                .iter()
+                .any(|pattern| glob_match(pattern, metric_name))
+        })
+    }
+}
+
+pub fn provide_settings() -> Settings {
+    let settings = Settings {
+        ignored_metrics: RwSignal::new(storage::get(IGNORED_METRICS_KEY).unwrap_or_default()),
+        plan_sort: RwSignal::new(storage::get(PLAN_SORT_KEY).unwrap_or_default()),
+        bookmarks: RwSignal::new(storage::get(BOOKMARKS_KEY).unwrap_or_default()),
+        significance_level: RwSignal::new(
+            storage::get(SIGNIFICANCE_LEVEL_KEY).unwrap_or(DEFAULT_SIGNIFICANCE_LEVEL),
+        ),
+        layout_preset: RwSignal::new(storage::get(LAYOUT_PRESET_KEY).unwrap_or_default()),
+        slow_query_threshold_ms: RwSignal::new(
+            storage::get::<u64>(SLOW_QUERY_THRESHOLD_KEY)
+                .unwrap_or(DEFAULT_SLOW_QUERY_THRESHOLD_MS)
+                .max(1),
+        ),
+    };
+
+    Effect::new(move || {
+        settings
+            .ignored_metrics
+            .with(|patterns| storage::set(IGNORED_METRICS_KEY, patterns));
+    });
+    Effect::new(move || {
+        storage::set(PLAN_SORT_KEY, &settings.plan_sort.get());
+    });
+    Effect::new(move || {
+        settings
+            .bookmarks
+            .with(|bookmarks| storage::set(BOOKMARKS_KEY, bookmarks));
+    });
+    Effect::new(move || {
+        storage::set(SIGNIFICANCE_LEVEL_KEY, &settings.significance_level.get());
+    });
+    Effect::new(move || {
+        storage::set(LAYOUT_PRESET_KEY, &settings.layout_preset.get());
+    });
+    Effect::new(move || {
+        storage::set(
+            SLOW_QUERY_THRESHOLD_KEY,
+            &settings.slow_query_threshold_ms.get(),
+        );
+    });
+
+    provide_context(settings);
+    settings
+}
+
+pub fn use_settings() -> Settings {
+    use_context::<Settings>().expect("Settings must be provided")
+}
+
+#[component]
+pub fn SettingsPanel() -> impl IntoView {
+    let settings = use_settings();
+    let (new_pattern, set_new_pattern) = signal(String::new());
+
+    let add_pattern = move || {
+        let pattern = new_pattern.get().trim().to_string();
+        if pattern.is_empty() {
+            return;
+        }
+        settings.ignored_metrics.update(|patterns| {
+            if !patterns.contains(&pattern) {
+                patterns.push(pattern);
+            }
+        });
+        set_new_pattern.set(String::new());
+    };
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4 mb-6">
+            <h2 class="text-base font-medium text-gray-700 dark:text-gray-200 mb-3">{move || t("Settings")}</h2>
+            <div class="text-xs">
+                <div class="text-gray-500 dark:text-gray-400 mb-1">"Layout"</div>
+                <div class="flex border border-gray-200 dark:border-gray-700 rounded overflow-hidden w-fit mb-3">
+                    {LayoutPreset::ALL
+                        .map(|preset| {
+                            view! {
+                                <button
+                                    class=move || {
+                                        if settings.layout_preset.get() == preset {
+                                            "px-2 py-0.5 bg-gray-100 dark:bg-gray-700 text-gray-800 dark:text-gray-100"
+                                        } else {
+                                            "px-2 py-0.5 text-gray-500 dark:text-gray-400 hover:bg-gray-50 dark:hover:bg-gray-800"
+                                        }
+                                    }
+                                    on:click=move |_| settings.layout_preset.set(preset)
+                                >
+                                    {preset.label()}
+                                </button>
+                            }
+                        })}
+                </div>
+                <div class="text-gray-500 dark:text-gray-400 mb-1">"Hidden metrics (glob patterns)"</div>
+                <div class="flex flex-wrap gap-1 mb-2">
+                    {move || {
+                        settings
+                            .ignored_metrics
+                            .get()
+                            .into_iter()
+                            .map(|pattern| {
+                                let to_remove = pattern.clone();
+                                view! {
+                                    <span class="flex items-center gap-1 bg-gray-50 dark:bg-gray-800 border border-gray-100 dark:border-gray-800 rounded px-2 py-0.5 font-mono text-gray-700 dark:text-gray-200">
+                                        {pattern}
+                                        <button
+                                            class="text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                                            on:click=move |_| {
+                                                settings
+                                                    .ignored_metrics
+                                                    .update(|patterns| patterns.retain(|p| *p != to_remove))
+                                            }
+                                        >
+                                            "✕"
+                                        </button>
+                                    </span>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+                <div class="flex items-center gap-2">
+                    <input
+                        type="text"
+                        placeholder="e.g. *_baseline"
+                        class="flex-1 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 font-mono focus:outline-none focus:border-gray-400"
+                        prop:value=new_pattern
+                        on:input=move |ev| set_new_pattern.set(event_target_value(&ev))
+                        on:keydown=move |ev| {
+                            if ev.key() == "Enter" {
+                                add_pattern();
+                            }
+                        }
+                    />
+                    <button
+                        class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs"
+                        on:click=move |_| add_pattern()
+                    >
+                        {move || t("Add")}
+                    </button>
+                </div>
+                <div class="text-gray-500 dark:text-gray-400 mt-3 mb-1">"Significance level (α) for plan comparison"</div>
+                <div class="flex items-center gap-2">
+                    <input
+                        type="range"
+                        min="0.01"
+                        max="0.2"
+                        step="0.01"
+                        class="flex-1"
+                        prop:value=move || settings.significance_level.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(alpha) = event_target_value(&ev).parse::<f64>() {
+                                settings.significance_level.set(alpha);
+                            }
+                        }
+                    />
+                    <span class="w-10 font-mono text-gray-700 dark:text-gray-200">
+                        {move || format!("{:.2}", settings.significance_level.get())}
+                    </span>
+                </div>
+                <div class="text-gray-500 dark:text-gray-400 mt-3 mb-1">"Slow query threshold"</div>
+                <div class="flex items-center gap-1">
+                    <input
+                        type="number"
+                        min="1"
+                        class="w-24 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-900 font-mono focus:outline-none focus:border-gray-400"
+                        prop:value=move || settings.slow_query_threshold_ms.get().to_string()
+                        on:input=move |ev| {
+                            if let Ok(threshold) = event_target_value(&ev).parse::<u64>() {
+                                settings.slow_query_threshold_ms.set(threshold.max(1));
+                            }
+                        }
+                    />
+                    <span class="text-gray-500 dark:text-gray-400">"ms"</span>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,39 @@
+use leptos::prelude::*;
+
+use crate::utils::i18n::t;
+use crate::utils::shortcuts::SHORTCUTS;
+
+/// Overlay listing the keyboard shortcuts, opened with `?`
+#[component]
+pub fn ShortcutHelp(show: RwSignal<bool>) -> impl IntoView {
+    view! {
+        <Show when=move || show.get()>
+            <div
+                class="fixed inset-0 z-40 flex items-center justify-center bg-black/20"
+                on:click=move |_| show.set(false)
+            >
+                <div
+                    class="w-80 p-4 bg-white dark:bg-gray-900 border border-gray-200 dark:border-gray-700 rounded shadow-lg"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <h3 class="text-sm font-medium text-gray-800 dark:text-gray-100 mb-3">
+                        {move || t("Keyboard shortcuts")}
+                    </h3>
+                    <div class="grid grid-cols-[auto_1fr] gap-x-3 gap-y-1 text-xs">
+                        {SHORTCUTS
+                            .iter()
+                            .map(|(key, description)| {
+                                view! {
+                                    <kbd class="px-1 font-mono text-gray-800 dark:text-gray-100 bg-gray-50 dark:bg-gray-800 border border-gray-200 dark:border-gray-700 rounded text-center">
+                                        {*key}
+                                    </kbd>
+                                    <span class="text-gray-600 dark:text-gray-300">{*description}</span>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
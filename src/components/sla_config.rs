@@ -0,0 +1,221 @@
+use leptos::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::components::toast::use_toast;
+use crate::models::execution_plan::ExecutionStatsWithPlan;
+use crate::utils::{glob_match, i18n::t};
+
+/// Performance budget for queries whose display name matches a pattern
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlaRule {
+    /// Glob-style pattern, e.g. "q*"
+    pub display_name_pattern: String,
+    /// Maximum allowed 95th percentile execution time
+    pub max_p95_ms: u64,
+}
+
+/// Compliance of one rule against the observed executions
+#[derive(Clone, Debug, PartialEq)]
+struct SlaStatus {
+    p95_ms: Option<u64>,
+    samples: usize,
+}
+
+impl SlaStatus {
+    fn passing(&self, rule: &SlaRule) -> bool {
+        self.p95_ms.is_none_or(|p95| p95 <= rule.max_p95_ms)
+    }
+}
+
+// Nearest-rank 95th percentile
+fn p95(mut values: Vec<u64>) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let rank = (values.len() as f64 * 0.95).ceil() as usize;
+    values.get(rank.saturating_sub(1)).copied()
+}
+
+fn evaluate(rule: &SlaRule, execution_stats: &[ExecutionStatsWithPlan]) -> SlaStatus {
+    let times = execution_stats
+        .iter()
+        .filter(|stats| {
+            glob_match(
+                &rule.display_name_pattern,
+                &stats.execution_stats.display_name,
+            )
+        })
+        .map(|stats| stats.execution_stats.execution_time_ms)
+        .collect::<Vec<_>>();
+    SlaStatus {
+        samples: times.len(),
+        p95_ms: p95(times),
+    }
+}
+
+#[component]
+pub fn SlaConfig(
+    execution_stats: ReadSignal<Option<Arc<Vec<ExecutionStatsWithPlan>>>>,
+) -> impl IntoView {
+    let toast = use_toast();
+    let (rules, set_rules) = signal(vec![SlaRule {
+        display_name_pattern: "*".to_string(),
+        max_p95_ms: 500,
+    }]);
+    let (new_pattern, set_new_pattern) = signal(String::new());
+    let (new_max_ms, set_new_max_ms) = signal(String::from("500"));
+
+    let statuses = Memo::new(move |_| {
+        let execution_stats = execution_stats.get();
+        let execution_stats = execution_stats.as_deref().map(Vec::as_slice).unwrap_or(&[]);
+        rules
+            .get()
+            .into_iter()
+            .map(|rule| {
+                let status = evaluate(&rule, execution_stats);
+                (rule, status)
+            })
+            .collect::<Vec<_>>()
+    });
+
+    // Warn when a rule goes from passing to failing after a fetch
+    Effect::new(move |previous: Option<HashMap<String, bool>>| {
+        let current = statuses
+            .get()
+            .iter()
+            .filter(|(_, status)| status.samples > 0)
+            .map(|(rule, status)| (rule.display_name_pattern.clone(), status.passing(rule)))
+            .collect::<HashMap<_, _>>();
+        if let Some(previous) = previous {
+            for (pattern, passing) in &current {
+                if !passing && previous.get(pattern).copied().unwrap_or(false) {
+                    toast.show_error(format!("SLA violated for queries matching \"{pattern}\""));
+                }
+            }
+        }
+        current
+    });
+
+    let add_rule = move |_| {
+        let pattern = new_pattern.get().trim().to_string();
+        let Ok(max_p95_ms) = new_max_ms.get().trim().parse::<u64>() else {
+            return;
+        };
+        if pattern.is_empty() {
+            return;
+        }
+        set_rules.update(|rules| {
+            rules.retain(|rule| rule.display_name_pattern != pattern);
+            rules.push(SlaRule {
+                display_name_pattern: pattern,
+                max_p95_ms,
+            });
+        });
+        set_new_pattern.set(String::new());
+    };
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <h2 class="text-base font-medium text-gray-700 dark:text-gray-200 mb-3">{move || t("SLA Compliance")}</h2>
+            <table class="w-full text-xs">
+                <thead>
+                    <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                        <th class="py-1 font-normal">"Pattern"</th>
+                        <th class="py-1 font-normal">"Max p95"</th>
+                        <th class="py-1 font-normal">"Observed p95"</th>
+                        <th class="py-1 font-normal">"Samples"</th>
+                        <th class="py-1 font-normal">"Status"</th>
+                        <th></th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {move || {
+                        statuses
+                            .get()
+                            .into_iter()
+                            .map(|(rule, status)| {
+                                let passing = status.passing(&rule);
+                                let pattern = rule.display_name_pattern.clone();
+                                view! {
+                                    <tr class="border-b border-gray-50 dark:border-gray-800">
+                                        <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                            {rule.display_name_pattern.clone()}
+                                        </td>
+                                        <td class="py-1 text-gray-800 dark:text-gray-100">
+                                            {format!("{}ms", rule.max_p95_ms)}
+                                        </td>
+                                        <td class="py-1 text-gray-800 dark:text-gray-100">
+                                            {status
+                                                .p95_ms
+                                                .map(|p95| format!("{p95}ms"))
+                                                .unwrap_or_else(|| "-".to_string())}
+                                        </td>
+                                        <td class="py-1 text-gray-800 dark:text-gray-100">{status.samples}</td>
+                                        <td class="py-1">
+                                            {if status.samples == 0 {
+                                                view! { <span class="text-gray-400">"No data"</span> }
+                                                    .into_any()
+                                            } else if passing {
+                                                view! {
+                                                    <span class="text-green-700 bg-green-50 rounded px-1">
+                                                        "Pass"
+                                                    </span>
+                                                }
+                                                    .into_any()
+                                            } else {
+                                                view! {
+                                                    <span class="text-red-700 bg-red-50 rounded px-1">
+                                                        "Fail"
+                                                    </span>
+                                                }
+                                                    .into_any()
+                                            }}
+                                        </td>
+                                        <td class="py-1 text-right">
+                                            <button
+                                                class="text-gray-400 hover:text-gray-600 dark:hover:text-gray-200"
+                                                on:click=move |_| {
+                                                    set_rules
+                                                        .update(|rules| {
+                                                            rules.retain(|rule| rule.display_name_pattern != pattern)
+                                                        })
+                                                }
+                                            >
+                                                "✕"
+                                            </button>
+                                        </td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </tbody>
+            </table>
+            <div class="flex items-center gap-2 mt-3">
+                <input
+                    type="text"
+                    placeholder="Display name pattern"
+                    class="flex-1 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                    prop:value=new_pattern
+                    on:input=move |ev| set_new_pattern.set(event_target_value(&ev))
+                />
+                <input
+                    type="number"
+                    min="1"
+                    class="w-24 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                    prop:value=new_max_ms
+                    on:input=move |ev| set_new_max_ms.set(event_target_value(&ev))
+                />
+                <span class="text-xs text-gray-500 dark:text-gray-400">"ms"</span>
+                <button
+                    class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs"
+                    on:click=add_rule
+                >
+                    {move || t("Add rule")}
+                </button>
+            </div>
+        </div>
+    }
+}
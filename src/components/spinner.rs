@@ -0,0 +1,27 @@
+use leptos::prelude::*;
+
+/// Spinning circle shown while a request is in flight
+#[component]
+pub fn Spinner(#[prop(optional, into)] class: Option<&'static str>) -> impl IntoView {
+    view! {
+        <svg
+            class=format!("animate-spin {}", class.unwrap_or("w-3 h-3 text-gray-400"))
+            fill="none"
+            viewBox="0 0 24 24"
+        >
+            <circle
+                class="opacity-25"
+                cx="12"
+                cy="12"
+                r="10"
+                stroke="currentColor"
+                stroke-width="4"
+            ></circle>
+            <path
+                class="opacity-75"
+                fill="currentColor"
+                d="M4 12a8 8 0 018-8v4a4 4 0 00-4 4H4z"
+            ></path>
+        </svg>
+    }
+}
@@ -1,41 +1,199 @@
 use leptos::prelude::*;
 
-use crate::models::execution_plan::Statistics;
+use crate::components::collapse::use_collapsible;
+use crate::models::execution_plan::{ColumnStatistics, Statistics};
+use crate::utils::{download_text_file, i18n::t, parse_stat_number};
+
+fn csv_field(value: Option<&str>) -> String {
+    let value = value.unwrap_or_default();
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn column_statistics_to_csv(columns: &[ColumnStatistics]) -> String {
+    let mut csv = String::from("name,min,max,sum,null,distinct_count\n");
+    for col in columns {
+        let row = [
+            Some(col.name.as_str()),
+            col.min.as_deref(),
+            col.max.as_deref(),
+            col.sum.as_deref(),
+            col.null.as_deref(),
+            col.distinct_count.as_deref(),
+        ]
+        .map(csv_field)
+        .join(",");
+        csv.push_str(&row);
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Split `name` around the first case-insensitive occurrence of `query`
+fn split_match<'a>(name: &'a str, query: &str) -> Option<(&'a str, &'a str, &'a str)> {
+    if query.is_empty() {
+        return None;
+    }
+    let start = name
+        .to_ascii_lowercase()
+        .find(&query.to_ascii_lowercase())?;
+    let end = start + query.len();
+    Some((&name[..start], &name[start..end], &name[end..]))
+}
+
+/// Where the mean falls within [min, max] and the fraction of null rows, if the values are numeric
+#[component]
+fn ColumnStatsChart(
+    column: ColumnStatistics,
+    /// Row count of the plan node, needed for the mean and the null fraction
+    num_rows: Option<f64>,
+) -> impl IntoView {
+    let parse = |value: &Option<String>| value.as_deref().and_then(parse_stat_number);
+    let range = parse(&column.min)
+        .zip(parse(&column.max))
+        .filter(|(min, max)| min <= max);
+    let nulls = parse(&column.null).unwrap_or(0.0);
+    let mean = parse(&column.sum)
+        .zip(num_rows.map(|rows| rows - nulls).filter(|rows| *rows > 0.0))
+        .map(|(sum, rows)| sum / rows);
+    let null_fraction = parse(&column.null)
+        .zip(num_rows.filter(|rows| *rows > 0.0))
+        .map(|(nulls, rows)| (nulls / rows).min(1.0));
+
+    view! {
+        <div class="mt-1 space-y-1">
+            {range
+                .map(|(min, max)| {
+                    // a single-valued column has its mean at the left edge
+                    let mean_pct = mean
+                        .filter(|_| max > min)
+                        .map(|mean| ((mean - min) / (max - min)).clamp(0.0, 1.0) * 100.0);
+                    view! {
+                        <div class="flex items-center gap-1">
+                            <span class="w-8 text-gray-500 dark:text-gray-400">"Range"</span>
+                            <div
+                                class="relative flex-1 h-2 rounded bg-blue-100 dark:bg-blue-900"
+                                title=format!("min {min} … max {max}")
+                            >
+                                {mean_pct
+                                    .zip(mean)
+                                    .map(|(pct, mean)| {
+                                        view! {
+                                            <div
+                                                class="absolute -top-0.5 w-0.5 h-3 bg-blue-600"
+                                                style:left=format!("{pct:.1}%")
+                                                title=format!("mean ≈ {mean:.2}")
+                                            ></div>
+                                        }
+                                    })}
+                            </div>
+                        </div>
+                    }
+                })}
+            {null_fraction
+                .map(|fraction| {
+                    view! {
+                        <div class="flex items-center gap-1">
+                            <span class="w-8 text-gray-500 dark:text-gray-400">"Null"</span>
+                            <div
+                                class="flex-1 h-2 rounded bg-gray-100 dark:bg-gray-800 overflow-hidden"
+                                title=format!("{:.1}% null", fraction * 100.0)
+                            >
+                                <div
+                                    class="h-full bg-gray-400"
+                                    style:width=format!("{:.1}%", fraction * 100.0)
+                                ></div>
+                            </div>
+                        </div>
+                    }
+                })}
+        </div>
+    }
+}
+
+/// Whether `ColumnStatsChart` has anything to draw for the column
+fn has_numeric_stats(column: &ColumnStatistics, num_rows: Option<f64>) -> bool {
+    let numeric = |value: &Option<String>| value.as_deref().and_then(parse_stat_number).is_some();
+    (numeric(&column.min) && numeric(&column.max)) || (numeric(&column.null) && num_rows.is_some())
+}
 
 #[component]
 fn StatisticsContent(stats: Statistics) -> impl IntoView {
-    let columns = stats.column_statistics;
+    let columns = StoredValue::new(stats.column_statistics);
+    let total_columns = columns.with_value(|columns| columns.len());
+    let (query, set_query) = signal(String::new());
+    let filtered_columns = move || {
+        let query = query.get();
+        columns.with_value(|columns| {
+            columns
+                .iter()
+                .filter(|col| query.is_empty() || split_match(&col.name, &query).is_some())
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    };
     let num_rows = stats.num_rows.clone();
+    let row_count = parse_stat_number(&num_rows);
     let total_byte_size = stats.total_byte_size.clone();
 
     view! {
         <div>
             <div class="flex gap-4 mb-2">
                 <div class="truncate">
-                    <span class="text-gray-500">"Num rows: "</span>
-                    <span class="text-gray-800">{num_rows}</span>
+                    <span class="text-gray-500 dark:text-gray-400">"Num rows: "</span>
+                    <span class="text-gray-800 dark:text-gray-100">{num_rows}</span>
                 </div>
                 <div class="truncate">
-                    <span class="text-gray-500">"Total byte size: "</span>
-                    <span class="text-gray-800">{total_byte_size}</span>
+                    <span class="text-gray-500 dark:text-gray-400">"Total byte size: "</span>
+                    <span class="text-gray-800 dark:text-gray-100">{total_byte_size}</span>
                 </div>
             </div>
 
             <div class="mt-2">
-                <div class="font-medium mb-1">"Column Statistics:"</div>
+                <div class="flex items-center justify-between gap-2 mb-1">
+                    <div class="font-medium">"Column Statistics:"</div>
+                    <input
+                        type="search"
+                        placeholder="Search columns"
+                        class="w-32 px-1 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-700 dark:text-gray-200 focus:outline-none focus:border-gray-400"
+                        prop:value=query
+                        on:input=move |ev| set_query.set(event_target_value(&ev))
+                    />
+                </div>
+                <div class="text-gray-500 dark:text-gray-400 mb-1">
+                    {move || {
+                        format!("Showing {} of {} columns", filtered_columns().len(), total_columns)
+                    }}
+                </div>
                 <div class="space-y-1 max-h-32 overflow-y-auto">
-                    {columns
+                    {move || {
+                        let query = query.get();
+                        filtered_columns()
                         .into_iter()
                         .map(|col| {
+                            let name = match split_match(&col.name, &query) {
+                                Some((before, matched, after)) => {
+                                    view! {
+                                        {before.to_string()}
+                                        <mark class="bg-yellow-200 rounded-sm">{matched.to_string()}</mark>
+                                        {after.to_string()}
+                                    }
+                                        .into_any()
+                                }
+                                None => col.name.clone().into_any(),
+                            };
                             view! {
-                                <div class="text-xs bg-white border border-gray-100 rounded p-1">
-                                    <div class="font-medium text-gray-700">{col.name}</div>
+                                <div class="text-xs bg-white dark:bg-gray-900 border border-gray-100 dark:border-gray-800 rounded p-1">
+                                    <div class="font-medium text-gray-700 dark:text-gray-200">{name}</div>
                                     <div class="grid grid-cols-4 gap-1 text-xs">
                                         {if let Some(min) = &col.min {
                                             view! {
                                                 <div class="truncate">
-                                                    <span class="text-gray-500">"Min: "</span>
-                                                    <span class="text-gray-800">{min.clone()}</span>
+                                                    <span class="text-gray-500 dark:text-gray-400">"Min: "</span>
+                                                    <span class="text-gray-800 dark:text-gray-100">{min.clone()}</span>
                                                 </div>
                                             }
                                                 .into_any()
@@ -45,8 +203,8 @@ fn StatisticsContent(stats: Statistics) -> impl IntoView {
                                         {if let Some(max) = &col.max {
                                             view! {
                                                 <div class="truncate">
-                                                    <span class="text-gray-500">"Max: "</span>
-                                                    <span class="text-gray-800">{max.clone()}</span>
+                                                    <span class="text-gray-500 dark:text-gray-400">"Max: "</span>
+                                                    <span class="text-gray-800 dark:text-gray-100">{max.clone()}</span>
                                                 </div>
                                             }
                                                 .into_any()
@@ -56,8 +214,8 @@ fn StatisticsContent(stats: Statistics) -> impl IntoView {
                                         {if let Some(sum) = &col.sum {
                                             view! {
                                                 <div class="truncate">
-                                                    <span class="text-gray-500">"Sum: "</span>
-                                                    <span class="text-gray-800">{sum.clone()}</span>
+                                                    <span class="text-gray-500 dark:text-gray-400">"Sum: "</span>
+                                                    <span class="text-gray-800 dark:text-gray-100">{sum.clone()}</span>
                                                 </div>
                                             }
                                                 .into_any()
@@ -67,8 +225,8 @@ fn StatisticsContent(stats: Statistics) -> impl IntoView {
                                         {if let Some(null) = &col.null {
                                             view! {
                                                 <div class="truncate">
-                                                    <span class="text-gray-500">"Null: "</span>
-                                                    <span class="text-gray-800">{null.clone()}</span>
+                                                    <span class="text-gray-500 dark:text-gray-400">"Null: "</span>
+                                                    <span class="text-gray-800 dark:text-gray-100">{null.clone()}</span>
                                                 </div>
                                             }
                                                 .into_any()
@@ -78,8 +236,8 @@ fn StatisticsContent(stats: Statistics) -> impl IntoView {
                                         {if let Some(distinct) = &col.distinct_count {
                                             view! {
                                                 <div class="truncate">
-                                                    <span class="text-gray-500">"Distinct: "</span>
-                                                    <span class="text-gray-800">{distinct.clone()}</span>
+                                                    <span class="text-gray-500 dark:text-gray-400">"Distinct: "</span>
+                                                    <span class="text-gray-800 dark:text-gray-100">{distinct.clone()}</span>
                                                 </div>
                                             }
                                                 .into_any()
@@ -87,10 +245,13 @@ fn StatisticsContent(stats: Statistics) -> impl IntoView {
                                             view! { <div></div> }.into_any()
                                         }}
                                     </div>
+                                    {has_numeric_stats(&col, row_count)
+                                        .then(|| view! { <ColumnStatsChart column=col.clone() num_rows=row_count /> })}
                                 </div>
                             }
                         })
-                        .collect_view()}
+                        .collect_view()
+                    }}
                 </div>
             </div>
         </div>
@@ -98,14 +259,23 @@ fn StatisticsContent(stats: Statistics) -> impl IntoView {
 }
 
 #[component]
-pub fn StatisticsComponent(stats: Statistics) -> impl IntoView {
-    let (expand_statistics, set_expand_statistics) = signal(false);
+pub fn StatisticsComponent(stats: Statistics, plan_id: String) -> impl IntoView {
+    let (expand_statistics, toggle_statistics) = use_collapsible(false);
+
+    let columns_for_export = stats.column_statistics.clone();
+    let export_csv = move |_| {
+        download_text_file(
+            &format!("column-stats-{plan_id}.csv"),
+            &column_statistics_to_csv(&columns_for_export),
+            "text/csv;charset=utf-8",
+        );
+    };
 
     view! {
         <div class="text-xs rounded">
             <button
-                class="flex items-center gap-1 text-xs text-gray-600 hover:text-gray-800 transition-colors mb-2 font-medium"
-                on:click=move |_| set_expand_statistics.update(|e| *e = !*e)
+                class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300 hover:text-gray-800 transition-colors mb-2 font-medium"
+                on:click=move |_| toggle_statistics()
             >
                 <svg class="w-3 h-3" fill="currentColor" viewBox="0 0 20 20">
                     <path
@@ -120,11 +290,17 @@ pub fn StatisticsComponent(stats: Statistics) -> impl IntoView {
                         clip-rule="evenodd"
                     />
                 </svg>
-                "Statistics"
+                {move || t("Statistics")}
             </button>
 
             <Show when=move || expand_statistics.get()>
                 <StatisticsContent stats=stats.clone() />
+                <button
+                    class="mt-2 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors text-xs"
+                    on:click=export_csv.clone()
+                >
+                    {move || t("Export column stats")}
+                </button>
             </Show>
         </div>
     }
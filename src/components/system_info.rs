@@ -1,19 +1,18 @@
 use leptos::prelude::*;
-use serde::Deserialize;
 
-use crate::utils::format_bytes;
+use crate::components::{charts::GaugeChart, spinner::Spinner};
+use crate::models::server_info::SystemInfo;
+use crate::utils::{format_bytes_with_precision, i18n::t};
 
-#[derive(Deserialize, Clone)]
-pub struct SystemInfo {
-    pub total_memory_bytes: u64,
-    pub used_memory_bytes: u64,
-    pub name: String,
-    pub kernel: String,
-    pub os: String,
-    pub host_name: String,
-    pub cpu_cores: usize,
-    pub server_resident_memory_bytes: u64,
-    pub server_virtual_memory_bytes: u64,
+/// Share of `max_file_handles` in use above which the handle count is flagged
+const FILE_HANDLE_WARNING_RATIO: f64 = 0.8;
+
+impl SystemInfo {
+    fn file_handles_near_limit(&self) -> bool {
+        self.max_file_handles > 0
+            && self.open_file_handles as f64
+                > self.max_file_handles as f64 * FILE_HANDLE_WARNING_RATIO
+    }
 }
 
 type RefreshCallback = Box<dyn Fn() + 'static>;
@@ -22,59 +21,104 @@ type RefreshCallback = Box<dyn Fn() + 'static>;
 pub fn SystemInfo(
     system_info: ReadSignal<Option<SystemInfo>>,
     on_refresh: RefreshCallback,
+    /// Whether a refresh is in flight, the stale data stays visible but dimmed
+    #[prop(into)]
+    loading: Signal<bool>,
+    /// Fractional digits of byte sizes
+    #[prop(default = 2)]
+    byte_decimal_places: u8,
 ) -> impl IntoView {
     view! {
-        <div class="border border-gray-200 rounded-lg bg-white p-4">
+        <div
+            class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4 transition-opacity"
+            class:opacity-75=loading
+            class:pointer-events-none=loading
+        >
             <div class="flex justify-between items-center mb-3">
-                <h2 class="text-base font-medium text-gray-700">"System"</h2>
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("System")}</h2>
                 <button
-                    class="text-xs text-gray-500 hover:text-gray-700 px-2 py-1 rounded hover:bg-gray-50"
+                    class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
                     on:click=move |_| on_refresh()
                 >
-                    "Refresh"
+                    {move || {
+                        if loading.get() {
+                            view! { <Spinner /> }.into_any()
+                        } else {
+                            t("Refresh").into_any()
+                        }
+                    }}
                 </button>
             </div>
             {move || match system_info.get() {
                 Some(info) => {
                     view! {
                         <div class="grid grid-cols-4 gap-y-1 text-sm">
-                            <span class="text-gray-500 text-xs">"Host Name"</span>
-                            <span class="text-gray-800 text-xs truncate">
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Host Name"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs truncate">
                                 {info.host_name.clone()}
                             </span>
 
-                            <span class="text-gray-500 text-xs">"OS"</span>
-                            <span class="text-gray-800 text-xs truncate">
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"OS"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs truncate">
                                 {format!("{} ({})", info.name, info.os)}
                             </span>
 
-                            <span class="text-gray-500 text-xs">"Kernel"</span>
-                            <span class="text-gray-800 text-xs truncate">
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Kernel"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs truncate">
                                 {info.kernel.clone()}
                             </span>
 
-                            <span class="text-gray-500 text-xs">"CPU Cores"</span>
-                            <span class="text-gray-800 text-xs">{info.cpu_cores}</span>
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"CPU Cores"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">{info.cpu_cores}</span>
+
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Memory used"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">
+                                {format_bytes_with_precision(info.used_memory_bytes, byte_decimal_places)}
+                            </span>
 
-                            <span class="text-gray-500 text-xs">"Memory used"</span>
-                            <span class="text-gray-800 text-xs">
-                                {format_bytes(info.used_memory_bytes)}
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Memory total"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">
+                                {format_bytes_with_precision(info.total_memory_bytes, byte_decimal_places)}
                             </span>
 
-                            <span class="text-gray-500 text-xs">"Memory total"</span>
-                            <span class="text-gray-800 text-xs">
-                                {format_bytes(info.total_memory_bytes)}
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Server Resident"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">
+                                {format_bytes_with_precision(info.server_resident_memory_bytes, byte_decimal_places)}
                             </span>
 
-                            <span class="text-gray-500 text-xs">"Server Resident"</span>
-                            <span class="text-gray-800 text-xs">
-                                {format_bytes(info.server_resident_memory_bytes)}
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Server Virtual"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">
+                                {format_bytes_with_precision(info.server_virtual_memory_bytes, byte_decimal_places)}
                             </span>
 
-                            <span class="text-gray-500 text-xs">"Server Virtual"</span>
-                            <span class="text-gray-800 text-xs">
-                                {format_bytes(info.server_virtual_memory_bytes)}
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"Memory-mapped"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">
+                                {format_bytes_with_precision(info.mmap_bytes, byte_decimal_places)}
                             </span>
+
+                            <span class="text-gray-500 dark:text-gray-400 text-xs">"File handles"</span>
+                            <span class="text-gray-800 dark:text-gray-100 text-xs">
+                                {if info.max_file_handles > 0 {
+                                    format!("{} / {}", info.open_file_handles, info.max_file_handles)
+                                } else {
+                                    info.open_file_handles.to_string()
+                                }}
+                                {info
+                                    .file_handles_near_limit()
+                                    .then(|| {
+                                        view! {
+                                            <span
+                                                class="ml-1 px-1 rounded text-amber-700 bg-amber-50 border border-amber-100"
+                                                title="More than 80% of the open file limit (ulimit -n) in use"
+                                            >
+                                                "⚠ near limit"
+                                            </span>
+                                        }
+                                    })}
+                            </span>
+                        </div>
+                        <div class="flex mt-3">
+                            <GaugeChart value=info.cpu_usage_pct label="CPU usage" />
                         </div>
                     }
                         .into_any()
@@ -82,7 +126,7 @@ pub fn SystemInfo(
                 None => {
                     view! {
                         <div class="text-gray-400 text-xs italic">
-                            "Connect to view system information"
+                            {move || t("Connect to view system information")}
                         </div>
                     }
                         .into_any()
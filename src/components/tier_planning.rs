@@ -0,0 +1,140 @@
+use leptos::prelude::*;
+use serde::Deserialize;
+
+use crate::utils::format_bytes;
+
+/// One storage tier of the cache, ordered from fastest to slowest
+#[derive(Deserialize, Clone, Debug)]
+pub struct CacheTier {
+    /// Tier name, e.g. "memory" or "disk"
+    pub name: String,
+    pub capacity_bytes: u64,
+    pub used_bytes: u64,
+    /// Space held back for in-flight writes
+    #[serde(default)]
+    pub reserved_bytes: u64,
+}
+
+/// Stacked used / reserved / free bar across all tiers,
+/// with markers where data spills into the next tier
+#[component]
+pub fn TierPlanning(tiers: Vec<CacheTier>) -> impl IntoView {
+    let (simulate_full, set_simulate_full) = signal(false);
+    let total_capacity = tiers
+        .iter()
+        .map(|tier| tier.capacity_bytes)
+        .sum::<u64>()
+        .max(1) as f64;
+    let percent = move |bytes: u64| bytes as f64 / total_capacity * 100.0;
+
+    // cumulative capacity where each tier ends, except the last one
+    let breakovers = tiers
+        .windows(2)
+        .scan(0, |end, pair| {
+            *end += pair[0].capacity_bytes;
+            Some((*end, pair[0].name.clone(), pair[1].name.clone()))
+        })
+        .collect::<Vec<_>>();
+    let tiers_for_legend = tiers.clone();
+
+    view! {
+        <div class="text-xs space-y-2">
+            <div class="flex justify-between items-center">
+                <span class="text-gray-600 dark:text-gray-300 font-medium">"Tier capacity"</span>
+                <button
+                    class=move || {
+                        if simulate_full.get() {
+                            "px-2 py-0.5 border border-gray-300 rounded bg-gray-100 dark:bg-gray-700 text-gray-800 dark:text-gray-100"
+                        } else {
+                            "px-2 py-0.5 border border-gray-200 dark:border-gray-700 rounded text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                        }
+                    }
+                    on:click=move |_| set_simulate_full.update(|s| *s = !*s)
+                >
+                    "Simulate full"
+                </button>
+            </div>
+            <div class="relative pt-4">
+                {breakovers
+                    .into_iter()
+                    .map(|(end, from, to)| {
+                        view! {
+                            <div
+                                class="absolute top-0 bottom-0 border-l border-dashed border-gray-500"
+                                style=format!("left: {:.2}%", percent(end))
+                                title=format!("Data spills from {from} to {to} beyond this point")
+                            >
+                                <span class="absolute -top-0.5 left-1 text-gray-500 dark:text-gray-400 whitespace-nowrap">
+                                    {format!("→ {to}")}
+                                </span>
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+                <div class="flex h-4 rounded overflow-hidden border border-gray-200 dark:border-gray-700">
+                    {move || {
+                        let simulate_full = simulate_full.get();
+                        tiers
+                            .iter()
+                            .map(|tier| {
+                                let reserved = tier.reserved_bytes.min(tier.capacity_bytes);
+                                let used = if simulate_full {
+                                    tier.capacity_bytes - reserved
+                                } else {
+                                    tier.used_bytes.min(tier.capacity_bytes - reserved)
+                                };
+                                let free = tier.capacity_bytes - reserved - used;
+                                view! {
+                                    <div
+                                        class="h-full bg-gray-700"
+                                        style=format!("width: {:.2}%", percent(used))
+                                        title=format!("{} used: {}", tier.name, format_bytes(used))
+                                    ></div>
+                                    <div
+                                        class="h-full bg-gray-400"
+                                        style=format!("width: {:.2}%", percent(reserved))
+                                        title=format!("{} reserved: {}", tier.name, format_bytes(reserved))
+                                    ></div>
+                                    <div
+                                        class="h-full bg-gray-100 dark:bg-gray-800"
+                                        style=format!("width: {:.2}%", percent(free))
+                                        title=format!("{} free: {}", tier.name, format_bytes(free))
+                                    ></div>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </div>
+            </div>
+            <div class="flex flex-wrap gap-3 text-gray-500 dark:text-gray-400">
+                {tiers_for_legend
+                    .into_iter()
+                    .map(|tier| {
+                        view! {
+                            <span>
+                                {format!(
+                                    "{}: {} / {}",
+                                    tier.name,
+                                    format_bytes(tier.used_bytes),
+                                    format_bytes(tier.capacity_bytes),
+                                )}
+                            </span>
+                        }
+                    })
+                    .collect_view()}
+                <span class="flex items-center gap-1">
+                    <span class="w-2 h-2 bg-gray-700"></span>
+                    "used"
+                </span>
+                <span class="flex items-center gap-1">
+                    <span class="w-2 h-2 bg-gray-400"></span>
+                    "reserved"
+                </span>
+                <span class="flex items-center gap-1">
+                    <span class="w-2 h-2 bg-gray-100 dark:bg-gray-800 border border-gray-200 dark:border-gray-700"></span>
+                    "free"
+                </span>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,68 @@
+use leptos::prelude::*;
+
+use crate::models::execution_plan::{aggregate_node_times, ExecutionPlanWithStats};
+use crate::utils::format_duration;
+
+/// Segment color by operator type
+fn node_type_color(name: &str) -> &'static str {
+    if name.contains("Scan") || name.starts_with("DataSourceExec") {
+        "bg-blue-400"
+    } else if name.starts_with("FilterExec") {
+        "bg-green-400"
+    } else if name.contains("Aggregate") {
+        "bg-orange-400"
+    } else if name.contains("Sort") {
+        "bg-purple-400"
+    } else {
+        "bg-gray-400"
+    }
+}
+
+/// Share of the total `elapsed_compute` spent in each node as one stacked bar.
+/// Clicking a segment calls `on_select` with the node path. Hidden when no node reports time.
+#[component]
+pub fn TimeBreakdownBar(
+    plan: ExecutionPlanWithStats,
+    on_select: Callback<String>,
+) -> impl IntoView {
+    let times = aggregate_node_times(&plan);
+    let total = times.iter().map(|(_, nanos)| nanos).sum::<u64>();
+    if total == 0 {
+        return ().into_any();
+    }
+
+    view! {
+        <div class="mb-4 text-xs">
+            <div class="flex justify-between text-gray-500 dark:text-gray-400 mb-1">
+                <span>"Time breakdown"</span>
+                <span class="font-mono">{format_duration(&format!("{total}ns"))}</span>
+            </div>
+            <div class="flex h-5 w-full rounded overflow-hidden bg-gray-100 dark:bg-gray-800">
+                {times
+                    .into_iter()
+                    .filter_map(|(path, nanos)| {
+                        let name = plan.node_at_path(&path)?.name.clone();
+                        let pct = nanos as f64 * 100.0 / total as f64;
+                        Some(view! {
+                            <button
+                                class=format!(
+                                    "{} h-full px-1 truncate text-left text-white border-r border-white/50 hover:brightness-110",
+                                    node_type_color(&name),
+                                )
+                                style:width=format!("{pct:.2}%")
+                                title=format!(
+                                    "{name}: {} ({pct:.1}%)",
+                                    format_duration(&format!("{nanos}ns")),
+                                )
+                                on:click=move |_| on_select.run(path.clone())
+                            >
+                                {name.clone()}
+                            </button>
+                        })
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+    .into_any()
+}
@@ -1,13 +1,64 @@
 use leptos::task::spawn_local;
 use leptos::{logging, prelude::*};
 use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
-#[derive(Clone, Debug)]
+use crate::utils::config::AppConfig;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ToastType {
     Success,
     Error,
     Info,
+    Warning,
+    /// Server errors that stay until dismissed
+    Critical,
+}
+
+impl ToastType {
+    pub const ALL: [ToastType; 5] = [
+        ToastType::Success,
+        ToastType::Error,
+        ToastType::Info,
+        ToastType::Warning,
+        ToastType::Critical,
+    ];
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ToastType::Success => "✓",
+            ToastType::Error => "✕",
+            ToastType::Info => "ℹ",
+            ToastType::Warning => "⚠",
+            ToastType::Critical => "‼",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ToastType::Success => "Success",
+            ToastType::Error => "Error",
+            ToastType::Info => "Info",
+            ToastType::Warning => "Warning",
+            ToastType::Critical => "Critical",
+        }
+    }
+}
+
+/// Button shown in a toast, e.g. to retry a failed request
+#[derive(Clone)]
+pub struct ToastAction {
+    pub label: String,
+    pub callback: Arc<dyn Fn() + Send + Sync>,
+}
+
+impl std::fmt::Debug for ToastAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -16,6 +67,9 @@ pub struct Toast {
     pub message: String,
     pub toast_type: ToastType,
     pub duration: Option<u64>, // duration in milliseconds, None for persistent
+    pub action: Option<ToastAction>,
+    /// Messages of all related toasts merged into this one, oldest first, empty for a single toast
+    pub grouped_messages: Vec<String>,
 }
 
 impl Toast {
@@ -25,44 +79,169 @@ impl Toast {
             message,
             toast_type,
             duration,
+            action: None,
+            grouped_messages: Vec::new(),
+        }
+    }
+
+    /// Shared start of related error messages, e.g. "Failed to fetch"
+    fn group_key(&self) -> Option<&str> {
+        if !matches!(self.toast_type, ToastType::Error | ToastType::Critical) {
+            return None;
         }
+        let rest = self.message.strip_prefix("Failed to ")?;
+        let verb_len = rest.find(|c: char| !c.is_alphanumeric())?;
+        Some(&self.message[.."Failed to ".len() + verb_len])
     }
 
-    pub fn success(message: String) -> Self {
-        Self::new(message, ToastType::Success, Some(4000))
+    /// Merge a related toast into this group, its message becomes the shown one
+    fn merge(&mut self, other: Toast) {
+        if self.grouped_messages.is_empty() {
+            self.grouped_messages.push(self.message.clone());
+        }
+        self.grouped_messages.push(other.message.clone());
+        self.message = other.message;
+        // the retry button re-runs the newest failed request
+        if other.action.is_some() {
+            self.action = other.action;
+        }
+        // a persistent toast keeps the group open, otherwise the longer duration wins
+        self.duration = self.duration.zip(other.duration).map(|(a, b)| a.max(b));
     }
 
-    pub fn error(message: String) -> Self {
-        Self::new(message, ToastType::Error, Some(6000))
+    /// e.g. "3 fetch errors" for a group, None for a single toast
+    pub fn group_label(&self) -> Option<String> {
+        if self.grouped_messages.is_empty() {
+            return None;
+        }
+        let verb = self.group_key()?.trim_start_matches("Failed to ");
+        Some(format!("{} {verb} errors", self.grouped_messages.len()))
     }
 
-    pub fn info(message: String) -> Self {
-        Self::new(message, ToastType::Info, Some(4000))
+    /// Error that stays until the user dismisses it
+    pub fn critical(message: String) -> Self {
+        Self::new(message, ToastType::Critical, None)
+    }
+
+    /// Error toast with a "Retry" button running `retry_fn`
+    pub fn error_with_retry(
+        message: String,
+        duration: Option<u64>,
+        retry_fn: Arc<dyn Fn() + Send + Sync>,
+    ) -> Self {
+        Self {
+            action: Some(ToastAction {
+                label: "Retry".to_string(),
+                callback: retry_fn,
+            }),
+            ..Self::new(message, ToastType::Error, duration)
+        }
     }
 }
 
+/// Default display duration of each toast type in milliseconds, None keeps the toast until closed
+#[derive(Clone, Copy, Debug)]
+pub struct ToastConfig {
+    pub success_ms: Option<u64>,
+    pub error_ms: Option<u64>,
+    pub info_ms: Option<u64>,
+    pub warning_ms: Option<u64>,
+    /// Number of past toasts kept in the history panel
+    pub history_cap: usize,
+}
+
+impl Default for ToastConfig {
+    fn default() -> Self {
+        Self {
+            success_ms: Some(4000),
+            error_ms: Some(6000),
+            info_ms: Some(4000),
+            warning_ms: Some(6000),
+            history_cap: 100,
+        }
+    }
+}
+
+impl ToastConfig {
+    pub fn duration(&self, toast_type: &ToastType) -> Option<u64> {
+        match toast_type {
+            ToastType::Success => self.success_ms,
+            ToastType::Error => self.error_ms,
+            ToastType::Info => self.info_ms,
+            ToastType::Warning => self.warning_ms,
+            ToastType::Critical => None,
+        }
+    }
+}
+
+/// A shown toast with the unix timestamp it appeared at
+#[derive(Clone, Debug)]
+pub struct ToastRecord {
+    pub toast: Toast,
+    pub shown_at: u64,
+}
+
+/// All toasts shown so far, newest first, including dismissed ones
+#[derive(Clone, Copy, Debug)]
+pub struct ToastHistory {
+    pub records: RwSignal<Vec<ToastRecord>>,
+    /// Toasts shown since the history panel was last opened
+    pub unread: RwSignal<usize>,
+}
+
 #[derive(Clone, Debug)]
 pub struct ToastContext {
     pub toasts: ReadSignal<HashMap<Uuid, Toast>>,
     pub add_toast: WriteSignal<Option<Toast>>,
     pub remove_toast: WriteSignal<Option<Uuid>>,
+    pub config: ToastConfig,
+    pub history: ToastHistory,
 }
 
 impl ToastContext {
+    fn show(&self, message: String, toast_type: ToastType) {
+        let duration = self.config.duration(&toast_type);
+        self.add_toast
+            .set(Some(Toast::new(message, toast_type, duration)));
+    }
+
     pub fn show_success(&self, message: String) {
         logging::log!("Showing success toast: {}", message);
-        self.add_toast.set(Some(Toast::success(message)));
+        self.show(message, ToastType::Success);
     }
 
     pub fn show_error(&self, message: String) {
         logging::error!("Showing error toast: {}", message);
-        self.add_toast.set(Some(Toast::error(message)));
+        self.show(message, ToastType::Error);
+    }
+
+    /// Show an error with a "Retry" button for the configured error duration
+    pub fn show_error_with_retry(&self, message: String, retry_fn: Arc<dyn Fn() + Send + Sync>) {
+        logging::error!("Showing error toast: {}", message);
+        self.add_toast.set(Some(Toast::error_with_retry(
+            message,
+            self.config.error_ms,
+            retry_fn,
+        )));
+    }
+
+    pub fn show_critical(&self, message: String) {
+        logging::error!("Showing critical toast: {}", message);
+        self.add_toast.set(Some(Toast::critical(message)));
     }
 
     #[allow(dead_code)]
     pub fn show_info(&self, message: String) {
         logging::log!("Showing info toast: {}", message);
-        self.add_toast.set(Some(Toast::info(message)));
+        self.show(message, ToastType::Info);
+    }
+
+    /// Show a warning for `duration_ms`, or the configured warning duration when None
+    pub fn show_warning(&self, message: String, duration_ms: Option<u64>) {
+        logging::warn!("Showing warning toast: {}", message);
+        let duration = duration_ms.or(self.config.warning_ms);
+        self.add_toast
+            .set(Some(Toast::new(message, ToastType::Warning, duration)));
     }
 
     pub fn remove(&self, id: Uuid) {
@@ -71,7 +250,12 @@ impl ToastContext {
 }
 
 #[component]
-pub fn ToastProvider(children: ChildrenFn) -> impl IntoView {
+pub fn ToastProvider(
+    children: ChildrenFn,
+    /// Display durations, defaults to `ToastConfig::default()`
+    #[prop(optional)]
+    config: Option<ToastConfig>,
+) -> impl IntoView {
     let (toasts, set_toasts) = signal(HashMap::<Uuid, Toast>::new());
     let (add_toast, set_add_toast) = signal(None::<Toast>);
     let (remove_toast, set_remove_toast) = signal(None::<Uuid>);
@@ -80,25 +264,86 @@ pub fn ToastProvider(children: ChildrenFn) -> impl IntoView {
         toasts,
         add_toast: set_add_toast,
         remove_toast: set_remove_toast,
+        config: config.unwrap_or_default(),
+        history: ToastHistory {
+            records: RwSignal::new(Vec::new()),
+            unread: RwSignal::new(0),
+        },
     };
+    let history = toast_context.history;
+    let history_cap = toast_context.config.history_cap;
+    // when each toast should disappear, regrouping pushes it back
+    let deadlines = StoredValue::new(HashMap::<Uuid, f64>::new());
 
     provide_context(toast_context.clone());
 
+    // requests rejected for their auth token, see `fetch_api_with_options`
+    if let Some(app_config) = use_context::<AppConfig>() {
+        let toast_context = toast_context.clone();
+        Effect::new(move |previous: Option<()>| {
+            app_config.unauthorized.track();
+            if previous.is_some() {
+                toast_context.show_warning(
+                    "The server rejected the auth token (401). Check the token in the connection section."
+                        .to_string(),
+                    None,
+                );
+            }
+        });
+    }
+
     // Effect to add new toasts
     Effect::new(move || {
         if let Some(toast) = add_toast.get() {
-            let toast_id = toast.id;
-            let duration = toast.duration;
-
-            set_toasts.update(|toasts| {
-                toasts.insert(toast_id, toast);
+            history.records.update(|records| {
+                records.insert(
+                    0,
+                    ToastRecord {
+                        toast: toast.clone(),
+                        shown_at: (js_sys::Date::now() / 1000.0) as u64,
+                    },
+                );
+                records.truncate(history_cap);
             });
+            history.unread.update(|unread| *unread += 1);
+
+            // related errors join the shown toast instead of stacking up
+            let Some((toast_id, duration)) = set_toasts.try_update(|toasts| {
+                let group = toast.group_key().and_then(|key| {
+                    toasts
+                        .values_mut()
+                        .find(|shown| shown.group_key() == Some(key))
+                });
+                let shown = match group {
+                    Some(group) => {
+                        group.merge(toast);
+                        group
+                    }
+                    None => toasts.entry(toast.id).or_insert(toast),
+                };
+                (shown.id, shown.duration)
+            }) else {
+                return;
+            };
 
-            // Auto-remove toast after duration
+            // Auto-remove toast after duration, unless a merged toast extended it meanwhile
             if let Some(duration_ms) = duration {
+                let deadline = js_sys::Date::now() + duration_ms as f64;
+                deadlines.update_value(|deadlines| {
+                    deadlines.insert(toast_id, deadline);
+                });
                 spawn_local(async move {
                     gloo_timers::future::TimeoutFuture::new(duration_ms as u32).await;
-                    set_remove_toast.set(Some(toast_id));
+                    let expired = deadlines
+                        .with_value(|deadlines| deadlines.get(&toast_id) == Some(&deadline));
+                    if expired {
+                        set_remove_toast.set(Some(toast_id));
+                    }
+                });
+            } else {
+                // a group that became persistent outlives its pending timer
+                deadlines.update_value(|deadlines| {
+                    deadlines.remove(&toast_id);
                 });
             }
 
@@ -112,6 +357,9 @@ pub fn ToastProvider(children: ChildrenFn) -> impl IntoView {
             set_toasts.update(|toasts| {
                 toasts.remove(&toast_id);
             });
+            deadlines.update_value(|deadlines| {
+                deadlines.remove(&toast_id);
+            });
             set_remove_toast.set(None);
         }
     });
@@ -130,7 +378,8 @@ pub fn ToastContainer() -> impl IntoView {
         <div class="fixed top-4 right-4 z-50 space-y-2 max-w-sm">
             <For
                 each=move || { toast_context.toasts.get().into_iter().collect::<Vec<_>>() }
-                key=|(id, _)| *id
+                // a merged toast renders again with its new message and count
+                key=|(id, toast)| (*id, toast.grouped_messages.len())
                 children={
                     let toast_context = toast_context.clone();
                     move |(id, toast)| {
@@ -156,12 +405,40 @@ pub fn ToastItem(toast: Toast, #[prop(into)] on_close: Callback<()>) -> impl Int
         ToastType::Success => ("bg-green-50", "border-green-100", "text-green-700"),
         ToastType::Error => ("bg-red-50", "border-red-100", "text-red-700"),
         ToastType::Info => ("bg-blue-50", "border-blue-100", "text-blue-700"),
+        ToastType::Warning => ("bg-amber-50", "border-amber-100", "text-amber-700"),
+        ToastType::Critical => ("bg-red-600", "border-red-700", "text-white"),
+    };
+    let close_class = if matches!(toast.toast_type, ToastType::Critical) {
+        "flex-shrink-0 px-1 text-sm font-bold rounded hover:bg-red-700 transition-colors ml-2"
+    } else {
+        "flex-shrink-0 text-xs opacity-60 hover:opacity-100 transition-opacity ml-2"
     };
 
-    let icon = match toast.toast_type {
-        ToastType::Success => "✓",
-        ToastType::Error => "✕",
-        ToastType::Info => "ℹ",
+    let icon = toast.toast_type.icon();
+    let (expanded, set_expanded) = signal(false);
+    let message = match toast.group_label() {
+        Some(label) => {
+            let messages = toast.grouped_messages.clone();
+            view! {
+                <button
+                    class="flex items-center gap-1 font-medium"
+                    on:click=move |_| set_expanded.update(|expanded| *expanded = !*expanded)
+                >
+                    {move || if expanded.get() { "▾" } else { "▸" }}
+                    {label}
+                </button>
+                <Show when=move || expanded.get()>
+                    <ul class="mt-1 space-y-1 text-xs list-disc pl-4">
+                        {messages
+                            .iter()
+                            .map(|message| view! { <li>{message.clone()}</li> })
+                            .collect_view()}
+                    </ul>
+                </Show>
+            }
+            .into_any()
+        }
+        None => toast.message.clone().into_any(),
     };
 
     view! {
@@ -172,11 +449,25 @@ pub fn ToastItem(toast: Toast, #[prop(into)] on_close: Callback<()>) -> impl Int
             text_class,
         )>
             <div class="flex-shrink-0 text-sm font-medium mt-0.5">{icon}</div>
-            <div class="flex-1 text-sm">{toast.message}</div>
-            <button
-                class="flex-shrink-0 text-xs opacity-60 hover:opacity-100 transition-opacity ml-2"
-                on:click=move |_| on_close.run(())
-            >
+            <div class="flex-1 text-sm">{message}</div>
+            {toast
+                .action
+                .map(|action| {
+                    view! {
+                        <button
+                            class="flex-shrink-0 px-2 py-0.5 text-xs font-medium border border-current rounded hover:bg-white/60 transition-colors"
+                            on:click=move |_| {
+                                let callback = action.callback.clone();
+                                // the callback may start async work, run it outside the event handler
+                                spawn_local(async move { callback() });
+                                on_close.run(());
+                            }
+                        >
+                            {action.label.clone()}
+                        </button>
+                    }
+                })}
+            <button class=close_class aria-label="Dismiss notification" on:click=move |_| on_close.run(())>
                 "✕"
             </button>
         </div>
@@ -0,0 +1,128 @@
+use leptos::prelude::*;
+
+use crate::components::toast::{use_toast, ToastType};
+use crate::utils::format_timestamp;
+use crate::utils::i18n::t;
+use crate::utils::shortcuts::on_close_modals;
+
+/// 🔔 button with the unread count, opens the toast history panel
+#[component]
+pub fn ToastHistoryBell() -> impl IntoView {
+    let history = use_toast().history;
+    let (open, set_open) = signal(false);
+    on_close_modals(move || set_open.set(false));
+
+    view! {
+        <button
+            class="relative px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+            title="Notification history"
+            aria-label="Notification history"
+            on:click=move |_| {
+                history.unread.set(0);
+                set_open.set(true);
+            }
+        >
+            "🔔"
+            <Show when=move || { history.unread.get() > 0 }>
+                <span class="absolute -top-1.5 -right-1.5 min-w-4 px-1 text-[10px] leading-4 text-white bg-red-500 rounded-full">
+                    {move || history.unread.get()}
+                </span>
+            </Show>
+        </button>
+        <Show when=move || open.get()>
+            <ToastHistoryPanel on_close=Callback::new(move |_| set_open.set(false)) />
+        </Show>
+    }
+}
+
+/// Slide-over listing past notifications, newest first
+#[component]
+pub fn ToastHistoryPanel(on_close: Callback<()>) -> impl IntoView {
+    let history = use_toast().history;
+    let (type_filter, set_type_filter) = signal(None::<ToastType>);
+
+    let records = move || {
+        let filter = type_filter.get();
+        history.records.with(|records| {
+            records
+                .iter()
+                .filter(|record| {
+                    filter.is_none_or(|toast_type| record.toast.toast_type == toast_type)
+                })
+                .cloned()
+                .collect::<Vec<_>>()
+        })
+    };
+
+    view! {
+        <div class="fixed inset-0 z-40 bg-black/20" on:click=move |_| on_close.run(())></div>
+        <div class="fixed inset-y-0 right-0 z-50 w-96 flex flex-col bg-white dark:bg-gray-900 border-l border-gray-200 dark:border-gray-700 shadow-lg">
+            <div class="flex justify-between items-center p-4 border-b border-gray-100 dark:border-gray-800">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">{move || t("Notifications")}</h2>
+                <div class="flex items-center gap-2">
+                    <select
+                        class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 focus:outline-none"
+                        on:change=move |ev| {
+                            let value = event_target_value(&ev);
+                            set_type_filter
+                                .set(
+                                    ToastType::ALL
+                                        .into_iter()
+                                        .find(|toast_type| toast_type.label() == value),
+                                )
+                        }
+                    >
+                        <option value="">"All types"</option>
+                        {ToastType::ALL
+                            .into_iter()
+                            .map(|toast_type| {
+                                view! { <option value=toast_type.label()>{toast_type.label()}</option> }
+                            })
+                            .collect_view()}
+                    </select>
+                    <button
+                        class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                        on:click=move |_| history.records.set(Vec::new())
+                    >
+                        {move || t("Clear history")}
+                    </button>
+                    <button
+                        class="text-gray-400 hover:text-gray-600"
+                        aria-label="Close notification history"
+                        on:click=move |_| on_close.run(())
+                    >
+                        "✕"
+                    </button>
+                </div>
+            </div>
+            <div class="flex-1 overflow-y-auto p-4 space-y-2 text-xs">
+                {move || {
+                    let records = records();
+                    if records.is_empty() {
+                        return view! { <div class="text-gray-400 italic">"No notifications"</div> }
+                            .into_any();
+                    }
+                    records
+                        .into_iter()
+                        .map(|record| {
+                            view! {
+                                <div class="flex items-start gap-2 p-2 border border-gray-100 dark:border-gray-800 rounded">
+                                    <span title=record.toast.toast_type.label()>
+                                        {record.toast.toast_type.icon()}
+                                    </span>
+                                    <span class="flex-1 text-gray-800 dark:text-gray-100 break-words">
+                                        {record.toast.message.clone()}
+                                    </span>
+                                    <span class="font-mono text-gray-400">
+                                        {format_timestamp(record.shown_at)}
+                                    </span>
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                        .into_any()
+                }}
+            </div>
+        </div>
+    }
+}
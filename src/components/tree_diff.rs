@@ -0,0 +1,185 @@
+use leptos::prelude::*;
+
+use crate::models::diff::{align_trees, AlignedTree, DiffMetric, DiffNode};
+use crate::models::execution_plan::ExecutionPlanWithStats;
+
+/// Indentation of one tree level in rem
+const DEPTH_INDENT_REM: f64 = 1.0;
+
+#[derive(Clone, Copy, PartialEq)]
+enum RowKind {
+    Matched,
+    Removed,
+    Added,
+}
+
+/// One line of the side-by-side diff, a missing side leaves its column empty
+struct DiffRow {
+    depth: usize,
+    kind: RowKind,
+    left: Option<DiffNode>,
+    right: Option<DiffNode>,
+}
+
+impl DiffRow {
+    /// Names of the metrics whose value differs between both sides
+    fn changed_metrics(&self) -> Vec<String> {
+        let (Some(left), Some(right)) = (&self.left, &self.right) else {
+            return Vec::new();
+        };
+        left.metrics
+            .iter()
+            .filter(|metric| {
+                right
+                    .metrics
+                    .iter()
+                    .find(|other| other.name == metric.name)
+                    .is_some_and(|other| {
+                        DiffMetric::new(&metric.name, &metric.value, &other.value).is_some()
+                    })
+            })
+            .map(|metric| metric.name.clone())
+            .collect()
+    }
+}
+
+/// Rows of the aligned trees in depth-first order
+fn flatten(tree: AlignedTree, depth: usize, rows: &mut Vec<DiffRow>) {
+    match tree {
+        AlignedTree::Matched {
+            left,
+            right,
+            children,
+        } => {
+            rows.push(DiffRow {
+                depth,
+                kind: RowKind::Matched,
+                left: Some(left),
+                right: Some(right),
+            });
+            for child in children {
+                flatten(child, depth + 1, rows);
+            }
+        }
+        AlignedTree::Removed(node) => flatten_subtree(&node, depth, RowKind::Removed, rows),
+        AlignedTree::Added(node) => flatten_subtree(&node, depth, RowKind::Added, rows),
+    }
+}
+
+fn flatten_subtree(
+    node: &ExecutionPlanWithStats,
+    depth: usize,
+    kind: RowKind,
+    rows: &mut Vec<DiffRow>,
+) {
+    let side = Some(DiffNode::from(node));
+    let (left, right) = match kind {
+        RowKind::Added => (None, side),
+        _ => (side, None),
+    };
+    rows.push(DiffRow {
+        depth,
+        kind,
+        left,
+        right,
+    });
+    for child in &node.children {
+        flatten_subtree(child, depth + 1, kind, rows);
+    }
+}
+
+#[component]
+fn DiffCell(
+    node: Option<DiffNode>,
+    depth: usize,
+    kind: RowKind,
+    changed: Vec<String>,
+) -> impl IntoView {
+    let indent = format!("{}rem", depth as f64 * DEPTH_INDENT_REM);
+    let Some(node) = node else {
+        return view! {
+            <div style:margin-left=indent class="h-full min-h-6 border border-dashed border-gray-200 dark:border-gray-700 rounded"></div>
+        }
+            .into_any();
+    };
+    let style = match kind {
+        RowKind::Removed => {
+            "bg-red-50 dark:bg-red-900/30 text-red-700 dark:text-red-300 line-through"
+        }
+        RowKind::Added => "bg-green-50 dark:bg-green-900/30 text-green-700 dark:text-green-300",
+        RowKind::Matched => "bg-white dark:bg-gray-900 text-gray-800 dark:text-gray-100",
+    };
+
+    view! {
+        <div
+            style:margin-left=indent
+            class=format!("px-2 py-1 border border-gray-200 dark:border-gray-700 rounded {style}")
+        >
+            <div class="font-medium">{node.name}</div>
+            <div class="flex flex-wrap gap-1 mt-0.5 font-mono">
+                {node
+                    .metrics
+                    .into_iter()
+                    .map(|metric| {
+                        let highlight = if changed.contains(&metric.name) {
+                            "bg-amber-100 dark:bg-amber-900/40 text-amber-800 dark:text-amber-200"
+                        } else {
+                            "text-gray-500 dark:text-gray-400"
+                        };
+                        view! {
+                            <span class=format!("px-1 rounded {highlight}")>
+                                {format!("{}={}", metric.name, metric.value)}
+                            </span>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+    .into_any()
+}
+
+/// Side-by-side structural diff of two plans: removed subtrees in red, added ones in green and
+/// changed metrics or replaced operators highlighted in amber
+#[component]
+pub fn PlanTreeDiff(
+    left: ExecutionPlanWithStats,
+    right: ExecutionPlanWithStats,
+    left_label: String,
+    right_label: String,
+) -> impl IntoView {
+    let mut rows = Vec::new();
+    flatten(align_trees(&left, &right), 0, &mut rows);
+
+    view! {
+        <div class="grid grid-cols-[1fr_2rem_1fr] gap-y-1 text-xs">
+            <div class="font-medium text-gray-700 dark:text-gray-200 truncate">{left_label}</div>
+            <div></div>
+            <div class="font-medium text-gray-700 dark:text-gray-200 truncate">{right_label}</div>
+            {rows
+                .into_iter()
+                .map(|row| {
+                    let changed = row.changed_metrics();
+                    let renamed = matches!(
+                        (&row.left, &row.right), (Some(left), Some(right)) if left.name != right.name
+                    );
+                    let (connector, connector_class) = match row.kind {
+                        RowKind::Removed => ("−", "text-red-500"),
+                        RowKind::Added => ("+", "text-green-600"),
+                        RowKind::Matched if renamed || !changed.is_empty() => ("↔", "text-amber-500"),
+                        RowKind::Matched => ("↔", "text-gray-300 dark:text-gray-600"),
+                    };
+                    view! {
+                        <div class:ring-1=renamed class:ring-amber-400=renamed class="rounded">
+                            <DiffCell node=row.left depth=row.depth kind=row.kind changed=changed.clone() />
+                        </div>
+                        <div class=format!("flex items-center justify-center {connector_class}")>{connector}</div>
+                        <div class:ring-1=renamed class:ring-amber-400=renamed class="rounded">
+                            <DiffCell node=row.right depth=row.depth kind=row.kind changed=changed />
+                        </div>
+                    }
+                })
+                .collect_view()}
+        </div>
+    }
+}
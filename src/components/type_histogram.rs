@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+
+use crate::models::execution_plan::ExecutionPlanWithStats;
+use crate::utils::plan_traversal::collect_all_types;
+
+/// Frequency of each data type across the schemas of all operators, most common first
+#[component]
+pub fn TypeHistogram(plan: ExecutionPlanWithStats) -> impl IntoView {
+    let mut types = collect_all_types(&plan).into_iter().collect::<Vec<_>>();
+    types.sort_by(|(a_type, a_count), (b_type, b_count)| {
+        b_count.cmp(a_count).then_with(|| a_type.cmp(b_type))
+    });
+    let max = types.first().map_or(1, |(_, count)| *count).max(1);
+    let total = types.iter().map(|(_, count)| count).sum::<usize>();
+
+    view! {
+        <div class="mb-4 border border-gray-100 dark:border-gray-800 rounded p-3 text-xs">
+            <div class="flex items-center justify-between mb-2">
+                <span class="font-medium text-gray-700 dark:text-gray-200">"Schema overview"</span>
+                <span class="text-gray-500 dark:text-gray-400">
+                    {format!("{} types in {total} fields", types.len())}
+                </span>
+            </div>
+            {if types.is_empty() {
+                view! { <div class="text-gray-400 italic">"No schema fields in this plan"</div> }
+                    .into_any()
+            } else {
+                view! {
+                    <div class="grid grid-cols-[12rem_1fr_2.5rem] items-center gap-x-2 gap-y-1">
+                        {types
+                            .into_iter()
+                            .map(|(data_type, count)| {
+                                view! {
+                                    <span class="font-mono text-gray-700 dark:text-gray-200 truncate" title=data_type.clone()>
+                                        {data_type.clone()}
+                                    </span>
+                                    <div class="h-3 rounded bg-gray-100 dark:bg-gray-800">
+                                        <div
+                                            class="h-full rounded bg-indigo-400"
+                                            style:width=format!("{:.1}%", count as f64 * 100.0 / max as f64)
+                                        ></div>
+                                    </div>
+                                    <span class="font-mono text-right text-gray-600 dark:text-gray-300">{count}</span>
+                                }
+                            })
+                            .collect_view()}
+                    </div>
+                }
+                    .into_any()
+            }}
+        </div>
+    }
+}
@@ -0,0 +1,98 @@
+use leptos::prelude::*;
+
+use crate::models::execution_plan::ExecutionPlanWithStats;
+use crate::utils::format_duration;
+
+/// Nanoseconds as e.g. "1.20ms"
+fn format_nanos(nanos: f64) -> String {
+    format_duration(&format!("{nanos}ns"))
+}
+
+/// Expected vs. actual `elapsed_compute` per operator. The expected time assumes every output
+/// row costs the plan's average time per row, so operators far off it are unusually cheap or
+/// expensive. Hidden when the plan reports no time or rows.
+#[component]
+pub fn WaterfallCompare(plan: ExecutionPlanWithStats) -> impl IntoView {
+    let total_time = plan.total_metric("elapsed_compute");
+    let total_rows = plan.total_metric("output_rows");
+    if total_time <= 0.0 || total_rows <= 0.0 {
+        return ().into_any();
+    }
+    let nanos_per_row = total_time / total_rows;
+    let rows = plan
+        .nodes_with_paths()
+        .into_iter()
+        .filter_map(|(path, node)| {
+            let expected = node.metric_value("output_rows")? * nanos_per_row;
+            let actual = node.metric_value("elapsed_compute")?;
+            Some((path, node.name.clone(), expected, actual))
+        })
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return ().into_any();
+    }
+    let max = rows
+        .iter()
+        .map(|(_, _, expected, actual)| expected.max(*actual))
+        .fold(0.0, f64::max)
+        .max(1.0);
+    let width = move |nanos: f64| format!("{:.1}%", nanos / max * 100.0);
+
+    view! {
+        <div class="mb-4 border border-gray-100 dark:border-gray-800 rounded p-3 text-xs">
+            <div class="flex items-center justify-between mb-2">
+                <span class="font-medium text-gray-700 dark:text-gray-200">"Expected vs. actual time"</span>
+                <span class="flex items-center gap-3 text-gray-500 dark:text-gray-400">
+                    <span class="flex items-center gap-1">
+                        <span class="inline-block w-3 h-2 rounded-sm bg-gray-300 dark:bg-gray-600"></span>
+                        "Expected"
+                    </span>
+                    <span class="flex items-center gap-1">
+                        <span class="inline-block w-3 h-2 rounded-sm bg-blue-400"></span>
+                        "Actual"
+                    </span>
+                </span>
+            </div>
+            <div class="space-y-1">
+                {rows
+                    .into_iter()
+                    .map(|(path, name, expected, actual)| {
+                        let faster = actual <= expected;
+                        let delta = if expected > 0.0 {
+                            format!("{:+.0}%", (actual - expected) / expected * 100.0)
+                        } else {
+                            "n/a".to_string()
+                        };
+                        view! {
+                            <div class="grid grid-cols-[10rem_1fr_4rem_1fr] items-center gap-2">
+                                <span
+                                    class="truncate font-mono text-gray-700 dark:text-gray-200"
+                                    title=format!("{name} ({path})")
+                                >
+                                    {name.clone()}
+                                </span>
+                                <div class="flex justify-end" title=format!("Expected {}", format_nanos(expected))>
+                                    <div
+                                        class="h-3 rounded-sm bg-gray-300 dark:bg-gray-600"
+                                        style:width=width(expected)
+                                    ></div>
+                                </div>
+                                <span
+                                    class="text-center font-mono"
+                                    class:text-green-600=faster
+                                    class:text-red-600=!faster
+                                >
+                                    {format!("→ {delta}")}
+                                </span>
+                                <div title=format!("Actual {}", format_nanos(actual))>
+                                    <div class="h-3 rounded-sm bg-blue-400" style:width=width(actual)></div>
+                                </div>
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+        </div>
+    }
+        .into_any()
+}
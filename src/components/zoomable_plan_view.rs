@@ -0,0 +1,153 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+
+const MIN_ZOOM: f64 = 0.25;
+const MAX_ZOOM: f64 = 3.0;
+const ZOOM_STEP: f64 = 1.2;
+/// Pointer travel in pixels that turns a press into a drag-pan
+const DRAG_THRESHOLD_PX: f64 = 4.0;
+
+/// Whether the event started on a control inside the tree, those keep their own behavior
+fn is_interactive_target(ev: &web_sys::Event) -> bool {
+    ev.target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .and_then(|element| element.closest("button, input, select, textarea, a").ok())
+        .flatten()
+        .is_some()
+}
+
+/// Pointer state of the enclosing `ZoomablePlanView`, tells clicks on the tree from drag-pans
+#[derive(Clone, Copy)]
+pub struct PlanViewPointer {
+    /// Whether the pointer moved past `DRAG_THRESHOLD_PX` since the last press
+    dragged: StoredValue<bool>,
+}
+
+impl PlanViewPointer {
+    /// Whether the click selects what is under it: not on a control, not ending a drag-pan
+    pub fn is_click(&self, ev: &web_sys::MouseEvent) -> bool {
+        !self.dragged.get_value() && !is_interactive_target(ev)
+    }
+}
+
+/// Pointer state of the enclosing plan view, a never-dragging one outside of it
+pub fn use_plan_view_pointer() -> PlanViewPointer {
+    use_context::<PlanViewPointer>().unwrap_or_else(|| PlanViewPointer {
+        dragged: StoredValue::new(false),
+    })
+}
+
+/// Scroll-to-zoom and drag-to-pan container for the plan tree.
+/// Focus it to use `+`, `-` and `0` to zoom in, out and reset.
+#[component]
+pub fn ZoomablePlanView(children: Children) -> impl IntoView {
+    let zoom = RwSignal::new(1.0_f64);
+    let pan = RwSignal::new((0.0_f64, 0.0_f64));
+    // pointer position and pan when the drag started
+    let drag_start = RwSignal::new(None::<((f64, f64), (f64, f64))>);
+    let pointer = PlanViewPointer {
+        dragged: StoredValue::new(false),
+    };
+    provide_context(pointer);
+
+    let zoom_by = move |factor: f64| zoom.update(|z| *z = (*z * factor).clamp(MIN_ZOOM, MAX_ZOOM));
+    let reset = move || {
+        zoom.set(1.0);
+        pan.set((0.0, 0.0));
+    };
+
+    let on_wheel = move |ev: web_sys::WheelEvent| {
+        ev.prevent_default();
+        zoom_by(if ev.delta_y() < 0.0 {
+            ZOOM_STEP
+        } else {
+            1.0 / ZOOM_STEP
+        });
+    };
+    let on_pointerdown = move |ev: web_sys::PointerEvent| {
+        if ev.button() != 0 || is_interactive_target(&ev) {
+            return;
+        }
+        pointer.dragged.set_value(false);
+        drag_start.set(Some((
+            (ev.client_x() as f64, ev.client_y() as f64),
+            pan.get_untracked(),
+        )));
+    };
+    let on_pointermove = move |ev: web_sys::PointerEvent| {
+        if let Some(((start_x, start_y), (pan_x, pan_y))) = drag_start.get_untracked() {
+            let (dx, dy) = (
+                ev.client_x() as f64 - start_x,
+                ev.client_y() as f64 - start_y,
+            );
+            if dx.hypot(dy) > DRAG_THRESHOLD_PX {
+                pointer.dragged.set_value(true);
+            }
+            // translate is applied inside the scale, so screen distance shrinks with zoom
+            let zoom = zoom.get_untracked();
+            pan.set((pan_x + dx / zoom, pan_y + dy / zoom));
+        }
+    };
+    let end_drag = move |_| drag_start.set(None);
+    let on_keydown = move |ev: web_sys::KeyboardEvent| {
+        if is_interactive_target(&ev) {
+            return;
+        }
+        match ev.key().as_str() {
+            "+" | "=" => zoom_by(ZOOM_STEP),
+            "-" => zoom_by(1.0 / ZOOM_STEP),
+            "0" => reset(),
+            _ => return,
+        }
+        ev.prevent_default();
+    };
+
+    let button_class =
+        "w-6 h-6 border border-gray-200 dark:border-gray-700 rounded bg-white dark:bg-gray-900 text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800";
+
+    view! {
+        <div class="relative border border-gray-100 dark:border-gray-800 rounded">
+            <div class="absolute top-2 right-2 z-10 flex items-center gap-1">
+                <button class=button_class title="Zoom in (+)" on:click=move |_| zoom_by(ZOOM_STEP)>
+                    "+"
+                </button>
+                <button
+                    class=button_class
+                    title="Zoom out (-)"
+                    on:click=move |_| zoom_by(1.0 / ZOOM_STEP)
+                >
+                    "−"
+                </button>
+                <button
+                    class="h-6 px-2 border border-gray-200 dark:border-gray-700 rounded bg-white dark:bg-gray-900 text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800"
+                    title="Reset zoom (0)"
+                    on:click=move |_| reset()
+                >
+                    {move || format!("{:.0}%", zoom.get() * 100.0)}
+                </button>
+            </div>
+            <div
+                tabindex="0"
+                class="overflow-hidden max-h-[70vh] py-4 focus:outline-none select-none"
+                class:cursor-grab=move || drag_start.with(Option::is_none)
+                class:cursor-grabbing=move || drag_start.with(Option::is_some)
+                on:wheel=on_wheel
+                on:pointerdown=on_pointerdown
+                on:pointermove=on_pointermove
+                on:pointerup=end_drag
+                on:pointerleave=end_drag
+                on:keydown=on_keydown
+            >
+                <div
+                    class="origin-top transition-transform duration-75"
+                    style:transform=move || {
+                        let (pan_x, pan_y) = pan.get();
+                        format!("scale({}) translate({pan_x}px, {pan_y}px)", zoom.get())
+                    }
+                >
+                    {children()}
+                </div>
+            </div>
+        </div>
+    }
+}
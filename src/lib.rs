@@ -7,15 +7,29 @@ mod models;
 mod pages;
 mod utils;
 
+use crate::components::settings::provide_settings;
 use crate::components::toast::ToastProvider;
-use crate::pages::home::Home;
+use crate::pages::history::History;
+use crate::pages::home::{provide_server_tabs, Home};
+use crate::pages::metrics::Metrics;
+use crate::utils::config::{provide_app_config, provide_request_config};
+use crate::utils::i18n::Locale;
+use crate::utils::theme::provide_theme;
 
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
 
+    let locale = RwSignal::new(Locale::default());
+    provide_context(locale);
+    provide_settings();
+    provide_app_config();
+    provide_request_config();
+    let data_theme = provide_theme();
+    provide_server_tabs();
+
     view! {
-        <Html attr:lang="en" attr:dir="ltr" attr:data-theme="light" />
+        <Html attr:lang=move || locale.get().code() attr:dir="ltr" attr:data-theme=data_theme />
 
         <Title text="Liquid Cache Admin" />
 
@@ -26,6 +40,8 @@ pub fn App() -> impl IntoView {
             <Router>
                 <Routes fallback=|| view! { NotFound }>
                     <Route path=path!("/") view=Home />
+                    <Route path=path!("/history") view=History />
+                    <Route path=path!("/metrics") view=Metrics />
                 </Routes>
             </Router>
         </ToastProvider>
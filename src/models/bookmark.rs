@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::glob_match;
+
+/// A node type + metric combination tracked across all plans
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct NodeBookmark {
+    /// Glob-style pattern matched against node names, e.g. "FilterExec"
+    pub node_name_pattern: String,
+    /// Metric name, e.g. "output_rows"
+    pub metric_name: String,
+}
+
+impl NodeBookmark {
+    pub fn matches(&self, node_name: &str, metric_name: &str) -> bool {
+        self.metric_name == metric_name && glob_match(&self.node_name_pattern, node_name)
+    }
+}
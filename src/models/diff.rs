@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+use std::ptr;
+
+use crate::models::execution_plan::{ExecutionPlanWithStats, MetricValues};
+use crate::utils::parse_metric_value;
+
+/// How the compared value relates to the baseline
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetricDirection {
+    Better,
+    Worse,
+    /// Changed, but the metric has no preferred direction (e.g. row counts)
+    Neutral,
+}
+
+/// A metric observed in two plans: `left` is the baseline, `right` the compared plan
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiffMetric {
+    pub left: String,
+    pub right: String,
+    pub direction: MetricDirection,
+}
+
+/// Metrics where lower values are better: elapsed time and bytes
+fn lower_is_better(name: &str) -> bool {
+    name.contains("time") || name.contains("elapsed") || name.contains("bytes")
+}
+
+impl DiffMetric {
+    /// Compare two raw values of the metric `name`, `None` if they are equal
+    pub fn new(name: &str, left: &str, right: &str) -> Option<Self> {
+        if left == right {
+            return None;
+        }
+        let direction = match (parse_metric_value(left), parse_metric_value(right)) {
+            (Some(l), Some(r)) if l == r => return None,
+            (Some(l), Some(r)) if lower_is_better(name) => {
+                if r < l {
+                    MetricDirection::Better
+                } else {
+                    MetricDirection::Worse
+                }
+            }
+            _ => MetricDirection::Neutral,
+        };
+        Some(Self {
+            left: left.to_string(),
+            right: right.to_string(),
+            direction,
+        })
+    }
+
+    /// Relative change of `right` against `left`, e.g. 0.25 for +25%
+    pub fn relative_change(&self) -> Option<f64> {
+        let left = parse_metric_value(&self.left)?;
+        let right = parse_metric_value(&self.right)?;
+        (left != 0.0).then(|| (right - left) / left)
+    }
+}
+
+/// The node of the other plan a node is compared against
+#[derive(Clone)]
+pub struct CompareNode {
+    pub plan_id: String,
+    /// Path of `node` in its own plan, e.g. "0.1"
+    pub node_path: String,
+    pub node: ExecutionPlanWithStats,
+}
+
+impl CompareNode {
+    /// Counterparts of `children`: each child is aligned with the first unused child
+    /// of this node that has the same name
+    pub fn align_children(&self, children: &[ExecutionPlanWithStats]) -> Vec<Option<CompareNode>> {
+        let mut used = vec![false; self.node.children.len()];
+        children
+            .iter()
+            .map(|child| {
+                let index = self
+                    .node
+                    .children
+                    .iter()
+                    .enumerate()
+                    .position(|(index, other)| !used[index] && other.name == child.name)?;
+                used[index] = true;
+                Some(CompareNode {
+                    plan_id: self.plan_id.clone(),
+                    node_path: format!("{}.{index}", self.node_path),
+                    node: self.node.children[index].clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A plan node without its children, one side of an [`AlignedTree`] match
+#[derive(Clone)]
+pub struct DiffNode {
+    pub name: String,
+    pub metrics: Vec<MetricValues>,
+}
+
+impl From<&ExecutionPlanWithStats> for DiffNode {
+    fn from(node: &ExecutionPlanWithStats) -> Self {
+        Self {
+            name: node.name.clone(),
+            metrics: node.metrics.clone(),
+        }
+    }
+}
+
+/// Two plan trees paired node by node, `left` is the baseline
+#[derive(Clone)]
+pub enum AlignedTree {
+    /// Node at the same position of both plans, the operator may have been replaced
+    Matched {
+        left: DiffNode,
+        right: DiffNode,
+        children: Vec<AlignedTree>,
+    },
+    /// Subtree only in the left plan
+    Removed(ExecutionPlanWithStats),
+    /// Subtree only in the right plan
+    Added(ExecutionPlanWithStats),
+}
+
+/// Align two plans with the cheapest top-down tree edit: the roots are paired, replacing an
+/// operator costs 1 and children are aligned in order, where removing or adding a child costs
+/// the size of its subtree.
+pub fn align_trees(a: &ExecutionPlanWithStats, b: &ExecutionPlanWithStats) -> AlignedTree {
+    TreeAligner::default().align(a, b)
+}
+
+type NodeKey = *const ExecutionPlanWithStats;
+
+/// Subtree sizes and pair distances computed once per alignment, keyed by node address
+/// since both trees stay borrowed until the alignment is done
+#[derive(Default)]
+struct TreeAligner {
+    sizes: HashMap<NodeKey, usize>,
+    distances: HashMap<(NodeKey, NodeKey), usize>,
+}
+
+impl TreeAligner {
+    fn align(&mut self, a: &ExecutionPlanWithStats, b: &ExecutionPlanWithStats) -> AlignedTree {
+        AlignedTree::Matched {
+            left: DiffNode::from(a),
+            right: DiffNode::from(b),
+            children: self.align_children(&a.children, &b.children),
+        }
+    }
+
+    fn subtree_size(&mut self, node: &ExecutionPlanWithStats) -> usize {
+        if let Some(&size) = self.sizes.get(&ptr::from_ref(node)) {
+            return size;
+        }
+        let size = 1 + node
+            .children
+            .iter()
+            .map(|child| self.subtree_size(child))
+            .sum::<usize>();
+        self.sizes.insert(ptr::from_ref(node), size);
+        size
+    }
+
+    /// Edit distance between the trees rooted at `a` and `b`
+    fn tree_distance(&mut self, a: &ExecutionPlanWithStats, b: &ExecutionPlanWithStats) -> usize {
+        let key = (ptr::from_ref(a), ptr::from_ref(b));
+        if let Some(&distance) = self.distances.get(&key) {
+            return distance;
+        }
+        let table = self.children_table(&a.children, &b.children);
+        let distance = usize::from(a.name != b.name) + table[a.children.len()][b.children.len()];
+        self.distances.insert(key, distance);
+        distance
+    }
+
+    /// `table[i][j]` is the cost of aligning the first `i` children of `a` with the first `j`
+    /// of `b`
+    fn children_table(
+        &mut self,
+        a: &[ExecutionPlanWithStats],
+        b: &[ExecutionPlanWithStats],
+    ) -> Vec<Vec<usize>> {
+        let mut table = vec![vec![0; b.len() + 1]; a.len() + 1];
+        for i in 1..=a.len() {
+            table[i][0] = table[i - 1][0] + self.subtree_size(&a[i - 1]);
+        }
+        for j in 1..=b.len() {
+            table[0][j] = table[0][j - 1] + self.subtree_size(&b[j - 1]);
+        }
+        for i in 1..=a.len() {
+            for j in 1..=b.len() {
+                table[i][j] = (table[i - 1][j] + self.subtree_size(&a[i - 1]))
+                    .min(table[i][j - 1] + self.subtree_size(&b[j - 1]))
+                    .min(table[i - 1][j - 1] + self.tree_distance(&a[i - 1], &b[j - 1]));
+            }
+        }
+        table
+    }
+
+    fn align_children(
+        &mut self,
+        a: &[ExecutionPlanWithStats],
+        b: &[ExecutionPlanWithStats],
+    ) -> Vec<AlignedTree> {
+        let table = self.children_table(a, b);
+        let (mut i, mut j) = (a.len(), b.len());
+        let mut aligned = Vec::new();
+        // walk back through the table, preferring pairs over removals over additions
+        while i > 0 || j > 0 {
+            if i > 0
+                && j > 0
+                && table[i][j] == table[i - 1][j - 1] + self.tree_distance(&a[i - 1], &b[j - 1])
+            {
+                aligned.push(self.align(&a[i - 1], &b[j - 1]));
+                i -= 1;
+                j -= 1;
+            } else if i > 0 && table[i][j] == table[i - 1][j] + self.subtree_size(&a[i - 1]) {
+                aligned.push(AlignedTree::Removed(a[i - 1].clone()));
+                i -= 1;
+            } else {
+                aligned.push(AlignedTree::Added(b[j - 1].clone()));
+                j -= 1;
+            }
+        }
+        aligned.reverse();
+        aligned
+    }
+}
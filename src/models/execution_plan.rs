@@ -1,7 +1,10 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::utils::parse_metric_value;
 
 /// Parameters for the set_execution_stats endpoint
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ExecutionStats {
     /// Plan ID for the execution plan
     #[allow(dead_code)]
@@ -16,10 +19,22 @@ pub struct ExecutionStats {
     pub execution_time_ms: u64,
     /// User SQL for the execution plan
     pub user_sql: String,
+    /// Hash of the normalized SQL, shared by runs of the same query with different parameters
+    #[serde(default)]
+    pub query_fingerprint: Option<String>,
+}
+
+impl ExecutionStats {
+    /// First 8 characters of the query fingerprint, enough to tell queries apart
+    pub fn short_fingerprint(&self) -> Option<&str> {
+        self.query_fingerprint
+            .as_deref()
+            .map(|fingerprint| fingerprint.get(..8).unwrap_or(fingerprint))
+    }
 }
 
 /// Execution stats with plan
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct ExecutionStatsWithPlan {
     /// Execution stats
     pub execution_stats: ExecutionStats,
@@ -27,8 +42,25 @@ pub struct ExecutionStatsWithPlan {
     pub plans: Vec<PlanInfo>,
 }
 
+impl ExecutionStatsWithPlan {
+    /// `output_bytes` of the root of the latest plan divided by `network_traffic_bytes`.
+    /// Above 1 the cache delivered more data than was transmitted. Summing every node would
+    /// count the same rows once per operator.
+    pub fn network_efficiency(&self) -> Option<f64> {
+        let output_bytes = self
+            .plans
+            .iter()
+            .max_by_key(|plan| plan.created_at)?
+            .plan
+            .metric_value("output_bytes")?;
+        let network_traffic_bytes = self.execution_stats.network_traffic_bytes;
+        (output_bytes > 0.0 && network_traffic_bytes > 0)
+            .then(|| output_bytes / network_traffic_bytes as f64)
+    }
+}
+
 /// Schema field
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct SchemaField {
     /// Field name
     pub name: String,
@@ -37,7 +69,7 @@ pub struct SchemaField {
 }
 
 /// Column statistics
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ColumnStatistics {
     /// Column name
     pub name: String,
@@ -54,7 +86,7 @@ pub struct ColumnStatistics {
 }
 
 /// Statistics
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Statistics {
     /// Number of rows
     pub num_rows: String,
@@ -65,7 +97,7 @@ pub struct Statistics {
 }
 
 /// Metric
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct MetricValues {
     /// Metric name
     pub name: String,
@@ -74,7 +106,8 @@ pub struct MetricValues {
 }
 
 /// Execution plan with stats
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(from = "RawExecutionPlanWithStats")]
 pub struct ExecutionPlanWithStats {
     /// Execution plan name
     pub name: String,
@@ -86,10 +119,345 @@ pub struct ExecutionPlanWithStats {
     pub metrics: Vec<MetricValues>,
     /// Children
     pub children: Vec<ExecutionPlanWithStats>,
+    /// Number of times the operator executed, parsed from the `count` metric
+    #[serde(skip_serializing)]
+    pub execution_count: Option<u32>,
+}
+
+impl ExecutionPlanWithStats {
+    /// Numeric value of the metric called `name`, durations are in nanoseconds
+    pub fn metric_value(&self, name: &str) -> Option<f64> {
+        self.metrics
+            .iter()
+            .find(|metric| metric.name == name)
+            .and_then(|metric| parse_metric_value(&metric.value))
+    }
+
+    /// All nodes of the tree in depth-first order, with their paths (e.g. "0.1.0")
+    pub fn nodes_with_paths(&self) -> Vec<(String, &ExecutionPlanWithStats)> {
+        fn visit<'a>(
+            node: &'a ExecutionPlanWithStats,
+            path: String,
+            nodes: &mut Vec<(String, &'a ExecutionPlanWithStats)>,
+        ) {
+            nodes.push((path.clone(), node));
+            for (index, child) in node.children.iter().enumerate() {
+                visit(child, format!("{path}.{index}"), nodes);
+            }
+        }
+        let mut nodes = Vec::new();
+        visit(self, "0".to_string(), &mut nodes);
+        nodes
+    }
+
+    /// The node at `path` (e.g. "0.1.0") of the tree rooted at this node
+    pub fn node_at_path(&self, path: &str) -> Option<&ExecutionPlanWithStats> {
+        let mut indices = path.split('.');
+        if indices.next()? != "0" {
+            return None;
+        }
+        indices.try_fold(self, |node, index| {
+            node.children.get(index.parse::<usize>().ok()?)
+        })
+    }
+
+    /// `cache_hits / (cache_hits + cache_misses)`, None without cache lookups
+    pub fn cache_hit_rate(&self) -> Option<f64> {
+        let hits = self.metric_value("cache_hits")?;
+        let lookups = hits + self.metric_value("cache_misses").unwrap_or(0.0);
+        (lookups > 0.0).then(|| hits / lookups)
+    }
+
+    /// Bytes read from storage, `bytes_scanned` or `bytes_read`
+    pub fn bytes_read(&self) -> Option<f64> {
+        self.metric_value("bytes_scanned")
+            .or_else(|| self.metric_value("bytes_read"))
+    }
+
+    /// Per-partition `elapsed_compute`, reported as `elapsed_compute_partition_<N>` metrics
+    pub fn partition_times(&self) -> Vec<f64> {
+        self.metrics
+            .iter()
+            .filter(|metric| metric.name.starts_with(PARTITION_TIME_PREFIX))
+            .filter_map(|metric| parse_metric_value(&metric.value))
+            .collect()
+    }
+
+    /// `max_partition_time / avg_partition_time`, None with fewer than two partitions
+    pub fn skew_ratio(&self) -> Option<f64> {
+        let times = self.partition_times();
+        if times.len() < 2 {
+            return None;
+        }
+        let avg = times.iter().sum::<f64>() / times.len() as f64;
+        let max = times.iter().copied().fold(0.0, f64::max);
+        (avg > 0.0).then(|| max / avg)
+    }
+
+    /// Rows produced per input row: `output_rows / max(child output_rows)`
+    pub fn fan_out(&self) -> Option<f64> {
+        let output_rows = self.metric_value("output_rows")?;
+        let max_child_rows = self
+            .children
+            .iter()
+            .filter_map(|child| child.metric_value("output_rows"))
+            .reduce(f64::max)?;
+        (max_child_rows > 0.0).then(|| output_rows / max_child_rows)
+    }
+
+    /// Sum of the metric called `name` over all nodes of the tree
+    pub fn total_metric(&self, name: &str) -> f64 {
+        self.metric_value(name).unwrap_or(0.0)
+            + self
+                .children
+                .iter()
+                .map(|child| child.total_metric(name))
+                .sum::<f64>()
+    }
+
+    /// Operator names of all nodes of the tree, sorted
+    pub fn operator_types(&self) -> Vec<&str> {
+        let mut names = self
+            .nodes_with_paths()
+            .into_iter()
+            .map(|(_, node)| node.name.as_str())
+            .collect::<Vec<_>>();
+        names.sort_unstable();
+        names
+    }
+}
+
+/// A child produced far more rows than its limiting parent lets through,
+/// usually because the limit was not pushed down
+#[derive(Clone, Debug, PartialEq)]
+pub struct CardinalityWarning {
+    /// Maximum rows the parent allows
+    pub parent_limit: u64,
+    /// Rows the child actually produced
+    pub child_rows: u64,
+}
+
+impl CardinalityWarning {
+    pub fn message(&self) -> String {
+        format!(
+            "Child output {} rows but parent allows only {}; the limit may not be pushed down",
+            self.child_rows, self.parent_limit
+        )
+    }
+}
+
+/// Child rows may exceed the parent limit by this factor before warning, operators
+/// stop at batch granularity so a small overshoot is expected
+const CARDINALITY_SLACK: f64 = 10.0;
+
+/// Row limit enforced by a limit operator: `fetch=N` in its name, otherwise its `output_rows`
+fn row_limit(node: &ExecutionPlanWithStats) -> Option<f64> {
+    if !node.name.contains("Limit") {
+        return None;
+    }
+    node.name
+        .split_once("fetch=")
+        .and_then(|(_, rest)| {
+            rest.split(|c: char| !c.is_ascii_digit())
+                .next()?
+                .parse::<f64>()
+                .ok()
+        })
+        .or_else(|| node.metric_value("output_rows"))
+}
+
+/// Warn when `child` outputs more rows than its limiting `parent` allows
+pub fn check_cardinality_consistency(
+    parent: &ExecutionPlanWithStats,
+    child: &ExecutionPlanWithStats,
+) -> Option<CardinalityWarning> {
+    let parent_limit = row_limit(parent)?;
+    let child_rows = child.metric_value("output_rows")?;
+    (child_rows > parent_limit.max(1.0) * CARDINALITY_SLACK).then_some(CardinalityWarning {
+        parent_limit: parent_limit as u64,
+        child_rows: child_rows as u64,
+    })
+}
+
+/// Arrow types grouped into families a union can coerce between
+fn type_family(data_type: &str) -> &str {
+    match data_type {
+        "Utf8" | "LargeUtf8" | "Utf8View" => "string",
+        "Binary" | "LargeBinary" | "BinaryView" => "binary",
+        "Int8" | "Int16" | "Int32" | "Int64" | "UInt8" | "UInt16" | "UInt32" | "UInt64" => {
+            "integer"
+        }
+        "Float16" | "Float32" | "Float64" => "float",
+        other => other,
+    }
+}
+
+/// Columns of the two union inputs that don't line up: a different column count or,
+/// position by position, types from different families (e.g. Utf8 vs Int64)
+pub fn check_union_compatibility(
+    left: &ExecutionPlanWithStats,
+    right: &ExecutionPlanWithStats,
+) -> Vec<String> {
+    let mut problems = Vec::new();
+    if left.schema.len() != right.schema.len() {
+        problems.push(format!(
+            "{} has {} columns but {} has {}",
+            left.name,
+            left.schema.len(),
+            right.name,
+            right.schema.len()
+        ));
+    }
+    for (index, (left_field, right_field)) in left.schema.iter().zip(&right.schema).enumerate() {
+        if type_family(&left_field.data_type) != type_family(&right_field.data_type) {
+            problems.push(format!(
+                "column {index}: {} ({}) vs {} ({})",
+                left_field.name, left_field.data_type, right_field.name, right_field.data_type
+            ));
+        }
+    }
+    problems
+}
+
+/// Whether the operator reads through LiquidCache, e.g. "LiquidParquetExec"
+pub fn is_cache_operator(name: &str) -> bool {
+    ["Liquid", "Cache", "Parquet"]
+        .iter()
+        .any(|keyword| name.contains(keyword))
+}
+
+const PARTITION_TIME_PREFIX: &str = "elapsed_compute_partition_";
+
+/// Partitioned operators whose execution skew is worth showing
+pub fn is_partitioned_operator(name: &str) -> bool {
+    name.starts_with("RepartitionExec") || name.contains("AggregateExec")
+}
+
+/// Execution plan with stats as sent by the server
+#[derive(Deserialize)]
+struct RawExecutionPlanWithStats {
+    name: String,
+    schema: Vec<SchemaField>,
+    statistics: Statistics,
+    metrics: Vec<MetricValues>,
+    children: Vec<ExecutionPlanWithStats>,
+}
+
+impl From<RawExecutionPlanWithStats> for ExecutionPlanWithStats {
+    fn from(raw: RawExecutionPlanWithStats) -> Self {
+        let execution_count = raw
+            .metrics
+            .iter()
+            .find(|metric| metric.name == "count")
+            .and_then(|metric| metric.value.parse::<u32>().ok());
+        Self {
+            name: raw.name,
+            schema: raw.schema,
+            statistics: raw.statistics,
+            metrics: raw.metrics,
+            children: raw.children,
+            execution_count,
+        }
+    }
+}
+
+/// Share of the tree's total `elapsed_compute` spent in each node, keyed by node path.
+/// Nodes without the metric are omitted.
+pub fn compute_relative_times(root: &ExecutionPlanWithStats) -> HashMap<String, f64> {
+    let times: Vec<(String, f64)> = root
+        .nodes_with_paths()
+        .into_iter()
+        .filter_map(|(path, node)| Some((path, node.metric_value("elapsed_compute")?)))
+        .collect();
+    let total: f64 = times.iter().map(|(_, time)| time).sum();
+    if total <= 0.0 {
+        return HashMap::new();
+    }
+    times
+        .into_iter()
+        .map(|(path, time)| (path, time / total))
+        .collect()
+}
+
+/// Own `elapsed_compute` of every node reporting it, in nanoseconds, keyed by node path
+/// in depth-first order. Together the entries make up the tree's total time.
+pub fn aggregate_node_times(root: &ExecutionPlanWithStats) -> Vec<(String, u64)> {
+    root.nodes_with_paths()
+        .into_iter()
+        .filter_map(|(path, node)| {
+            let nanos = node.metric_value("elapsed_compute")? as u64;
+            (nanos > 0).then_some((path, nanos))
+        })
+        .collect()
+}
+
+/// Identifier of a node in critical paths: name and path, e.g. "FilterExec#0.1"
+pub fn node_id(name: &str, node_path: &str) -> String {
+    format!("{name}#{node_path}")
+}
+
+/// IDs of the root-to-leaf path with the largest cumulative `elapsed_compute`,
+/// empty if no node reports elapsed time
+pub fn find_critical_path(root: &ExecutionPlanWithStats) -> Vec<String> {
+    // (cumulative time, path ids from this node down)
+    fn visit(node: &ExecutionPlanWithStats, node_path: &str) -> (f64, Vec<String>) {
+        let (child_time, mut path) = node
+            .children
+            .iter()
+            .enumerate()
+            .map(|(index, child)| visit(child, &format!("{node_path}.{index}")))
+            .max_by(|a, b| a.0.total_cmp(&b.0))
+            .unwrap_or_default();
+        path.insert(0, node_id(&node.name, node_path));
+        let time = node.metric_value("elapsed_compute").unwrap_or(0.0);
+        (time + child_time, path)
+    }
+
+    let (total_time, path) = visit(root, "0");
+    if total_time > 0.0 {
+        path
+    } else {
+        Vec::new()
+    }
+}
+
+/// Percentage of nodes named `node_name` across all loaded plans whose `metric` is at most `value`
+pub fn percentile_in_class(
+    all_plans: &[ExecutionStatsWithPlan],
+    node_name: &str,
+    metric: &str,
+    value: f64,
+) -> f64 {
+    let samples = all_plans
+        .iter()
+        .flat_map(|stats| &stats.plans)
+        .flat_map(|plan| plan.plan.nodes_with_paths())
+        .filter(|(_, node)| node.name == node_name)
+        .filter_map(|(_, node)| node.metric_value(metric))
+        .collect::<Vec<_>>();
+    if samples.is_empty() {
+        return 100.0;
+    }
+    let at_most = samples.iter().filter(|sample| **sample <= value).count();
+    at_most as f64 / samples.len() as f64 * 100.0
+}
+
+/// (created_at, schema field count) of the node at `node_path` in each plan version, oldest first
+pub fn schema_width_history(plans: &[PlanInfo], node_path: &str) -> Vec<(u64, usize)> {
+    let mut history: Vec<(u64, usize)> = plans
+        .iter()
+        .filter_map(|plan| {
+            plan.plan
+                .node_at_path(node_path)
+                .map(|node| (plan.created_at, node.schema.len()))
+        })
+        .collect();
+    history.sort_by_key(|(created_at, _)| *created_at);
+    history
 }
 
 /// Plan info
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct PlanInfo {
     /// Created at
     pub created_at: u64,
@@ -0,0 +1,170 @@
+use leptos::prelude::*;
+use std::collections::HashMap;
+
+use crate::models::execution_plan::{ExecutionPlanWithStats, ExecutionStatsWithPlan};
+use crate::utils::{compute_zscore, parse_metric_value};
+
+/// Metric values more than this many standard deviations from the mean are anomalies
+pub const ANOMALY_ZSCORE: f64 = 2.0;
+/// Plan versions kept per plan for playback, the oldest are dropped first
+const MAX_PLAN_VERSIONS: usize = 50;
+/// Raw observations kept per node/metric pair for significance tests, the oldest are dropped first
+const MAX_SAMPLES: usize = 200;
+
+/// A plan tree as observed by one refresh
+#[derive(Clone)]
+pub struct PlanVersion {
+    /// Unique within the session, stays valid when older versions are dropped
+    pub id: u64,
+    /// Unix timestamp in seconds of the refresh
+    pub recorded_at: u64,
+    pub plan: ExecutionPlanWithStats,
+}
+
+/// Session-level history of numeric metric values,
+/// keyed by `plan_id::node_path::metric_name`
+#[derive(Clone, Copy)]
+pub struct MetricHistory {
+    values: RwSignal<HashMap<String, Vec<f64>>>,
+    /// Every observed value, repeats included, same keys as `values`
+    samples: RwSignal<HashMap<String, Vec<f64>>>,
+    /// Plan trees whose metrics changed since the previous refresh, oldest first, keyed by plan id
+    versions: RwSignal<HashMap<String, Vec<PlanVersion>>>,
+    next_version_id: StoredValue<u64>,
+}
+
+impl Default for MetricHistory {
+    fn default() -> Self {
+        Self {
+            values: RwSignal::new(HashMap::new()),
+            samples: RwSignal::new(HashMap::new()),
+            versions: RwSignal::new(HashMap::new()),
+            next_version_id: StoredValue::new(0),
+        }
+    }
+}
+
+impl MetricHistory {
+    /// Record the metrics of every plan, the history skips values identical to the last
+    /// observation while the raw samples keep them. Plans with any changed value are also kept
+    /// as a new version.
+    pub fn record(&self, execution_stats: &[ExecutionStatsWithPlan]) {
+        let recorded_at = (js_sys::Date::now() / 1000.0) as u64;
+        let mut changed_plans = Vec::new();
+        self.values.update(|values| {
+            self.samples.update(|samples| {
+                for stats in execution_stats {
+                    for plan in &stats.plans {
+                        if record_node(values, samples, &plan.id, "0", &plan.plan) {
+                            changed_plans.push(plan);
+                        }
+                    }
+                }
+            })
+        });
+        if changed_plans.is_empty() {
+            return;
+        }
+        self.versions.update(|versions| {
+            for plan in changed_plans {
+                let plan_versions = versions.entry(plan.id.clone()).or_default();
+                let id = self.next_version_id.get_value();
+                self.next_version_id.set_value(id + 1);
+                plan_versions.push(PlanVersion {
+                    id,
+                    recorded_at,
+                    plan: plan.plan.clone(),
+                });
+                if plan_versions.len() > MAX_PLAN_VERSIONS {
+                    plan_versions.remove(0);
+                }
+            }
+        });
+    }
+
+    /// Recorded versions of one plan, oldest first
+    pub fn plan_versions(&self, plan_id: &str) -> Vec<PlanVersion> {
+        self.versions
+            .with(|versions| versions.get(plan_id).cloned().unwrap_or_default())
+    }
+
+    /// Raw observations of the metric of one node, repeats included
+    pub fn samples(&self, plan_id: &str, node_path: &str, metric_name: &str) -> Vec<f64> {
+        self.samples.with(|samples| {
+            samples
+                .get(&history_key(plan_id, node_path, metric_name))
+                .cloned()
+                .unwrap_or_default()
+        })
+    }
+
+    /// Z-score of `current` against the history of the same node/metric pair
+    /// across all `plan_ids`. Returns `None` if there is not enough history.
+    pub fn zscore(
+        &self,
+        plan_ids: &[String],
+        plan_id: &str,
+        node_path: &str,
+        metric_name: &str,
+        current: f64,
+    ) -> Option<f64> {
+        self.values.with(|values| {
+            let mut history = Vec::new();
+            for id in plan_ids {
+                if let Some(observed) = values.get(&history_key(id, node_path, metric_name)) {
+                    if id == plan_id {
+                        // the last observation of the current plan is the current value
+                        history.extend_from_slice(&observed[..observed.len().saturating_sub(1)]);
+                    } else {
+                        history.extend_from_slice(observed);
+                    }
+                }
+            }
+            (history.len() >= 2).then(|| compute_zscore(&history, current))
+        })
+    }
+}
+
+fn history_key(plan_id: &str, node_path: &str, metric_name: &str) -> String {
+    format!("{plan_id}::{node_path}::{metric_name}")
+}
+
+/// Whether any value of the subtree differs from its last observation
+fn record_node(
+    values: &mut HashMap<String, Vec<f64>>,
+    samples: &mut HashMap<String, Vec<f64>>,
+    plan_id: &str,
+    node_path: &str,
+    node: &ExecutionPlanWithStats,
+) -> bool {
+    let mut changed = false;
+    for metric in &node.metrics {
+        if let Some(value) = parse_metric_value(&metric.value) {
+            let key = history_key(plan_id, node_path, &metric.name);
+            let raw = samples.entry(key.clone()).or_default();
+            raw.push(value);
+            if raw.len() > MAX_SAMPLES {
+                raw.remove(0);
+            }
+            let observed = values.entry(key).or_default();
+            if observed.last() != Some(&value) {
+                observed.push(value);
+                changed = true;
+            }
+        }
+    }
+    for (index, child) in node.children.iter().enumerate() {
+        changed |= record_node(
+            values,
+            samples,
+            plan_id,
+            &format!("{node_path}.{index}"),
+            child,
+        );
+    }
+    changed
+}
+
+pub fn use_metric_history() -> MetricHistory {
+    use_context::<MetricHistory>().expect("MetricHistory must be provided")
+}
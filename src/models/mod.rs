@@ -1 +1,8 @@
+pub mod bookmark;
+pub mod diff;
 pub mod execution_plan;
+pub mod metric_history;
+pub mod presets;
+pub mod profile;
+pub mod server_info;
+pub mod traffic_history;
@@ -0,0 +1,67 @@
+const GB: u64 = 1024 * 1024 * 1024;
+
+/// Predefined cache configuration for a deployment size
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CachePreset {
+    /// Preset name
+    pub name: &'static str,
+    /// Memory tier capacity in bytes
+    pub max_cache_bytes: u64,
+    /// Disk tier capacity in bytes
+    pub max_disk_bytes: u64,
+    /// Rows per cached Arrow batch
+    pub batch_size: usize,
+}
+
+impl CachePreset {
+    /// Human readable label, e.g. "Small (1 GB RAM / 10 GB disk)"
+    pub fn label(&self) -> String {
+        format!(
+            "{} ({} RAM / {} disk)",
+            self.name,
+            format_capacity(self.max_cache_bytes),
+            format_capacity(self.max_disk_bytes)
+        )
+    }
+
+    /// Share of the total capacity held by the memory tier
+    pub fn memory_tier_ratio(&self) -> f64 {
+        self.max_cache_bytes as f64 / (self.max_cache_bytes + self.max_disk_bytes) as f64
+    }
+
+    /// Share of the total capacity held by the disk tier
+    pub fn disk_tier_ratio(&self) -> f64 {
+        1.0 - self.memory_tier_ratio()
+    }
+}
+
+fn format_capacity(bytes: u64) -> String {
+    if bytes >= 1024 * GB {
+        format!("{} TB", bytes / (1024 * GB))
+    } else {
+        format!("{} GB", bytes / GB)
+    }
+}
+
+pub const SMALL: CachePreset = CachePreset {
+    name: "Small",
+    max_cache_bytes: GB,
+    max_disk_bytes: 10 * GB,
+    batch_size: 4096,
+};
+
+pub const MEDIUM: CachePreset = CachePreset {
+    name: "Medium",
+    max_cache_bytes: 8 * GB,
+    max_disk_bytes: 100 * GB,
+    batch_size: 8192,
+};
+
+pub const LARGE: CachePreset = CachePreset {
+    name: "Large",
+    max_cache_bytes: 64 * GB,
+    max_disk_bytes: 1024 * GB,
+    batch_size: 16384,
+};
+
+pub const PRESETS: [CachePreset; 3] = [SMALL, MEDIUM, LARGE];
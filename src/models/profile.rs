@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::storage;
+
+const PROFILES_KEY: &str = "liquid_cache_admin.connection_profiles";
+
+/// A saved server the dashboard can switch to
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ConnectionProfile {
+    /// Display name, e.g. "staging"
+    pub name: String,
+    /// Server address, e.g. "http://localhost:53703"
+    pub address: String,
+}
+
+impl ConnectionProfile {
+    /// Profiles saved in localStorage, empty if none were saved
+    pub fn load_all() -> Vec<ConnectionProfile> {
+        storage::get(PROFILES_KEY).unwrap_or_default()
+    }
+
+    pub fn save_all(profiles: &[ConnectionProfile]) {
+        storage::set(PROFILES_KEY, &profiles);
+    }
+}
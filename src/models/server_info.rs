@@ -0,0 +1,34 @@
+use serde::Deserialize;
+
+/// Response of `/parquet_cache_usage`
+#[derive(Deserialize, Clone)]
+pub struct ParquetCacheUsage {
+    pub directory: String,
+    pub file_count: usize,
+    pub total_size_bytes: u64,
+}
+
+/// Response of `/system_info`
+#[derive(Deserialize, Clone)]
+pub struct SystemInfo {
+    pub total_memory_bytes: u64,
+    pub used_memory_bytes: u64,
+    pub name: String,
+    pub kernel: String,
+    pub os: String,
+    pub host_name: String,
+    pub cpu_cores: usize,
+    pub server_resident_memory_bytes: u64,
+    pub server_virtual_memory_bytes: u64,
+    /// Sum of the sizes of all memory-mapped files of the server
+    #[serde(default)]
+    pub mmap_bytes: u64,
+    #[serde(default)]
+    pub open_file_handles: u32,
+    /// Open file limit of the server process (`ulimit -n`), 0 if unknown
+    #[serde(default)]
+    pub max_file_handles: u32,
+    /// Aggregate CPU utilization of all cores, if the server reports it
+    #[serde(default)]
+    pub cpu_usage_pct: Option<f64>,
+}
@@ -0,0 +1,53 @@
+use leptos::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+use crate::models::execution_plan::ExecutionStatsWithPlan;
+
+/// Refreshes of network traffic kept per plan
+const TRAFFIC_HISTORY_LEN: usize = 20;
+
+/// `network_traffic_bytes` of each plan over the last refreshes, keyed by display name
+#[derive(Clone, Copy)]
+pub struct TrafficHistory {
+    values: RwSignal<HashMap<String, VecDeque<u64>>>,
+}
+
+impl Default for TrafficHistory {
+    fn default() -> Self {
+        Self {
+            values: RwSignal::new(HashMap::new()),
+        }
+    }
+}
+
+impl TrafficHistory {
+    /// Record the traffic of every plan of one refresh, dropping the oldest beyond the limit
+    pub fn record(&self, execution_stats: &[ExecutionStatsWithPlan]) {
+        self.values.update(|values| {
+            for stats in execution_stats {
+                let history = values
+                    .entry(stats.execution_stats.display_name.clone())
+                    .or_default();
+                history.push_back(stats.execution_stats.network_traffic_bytes);
+                if history.len() > TRAFFIC_HISTORY_LEN {
+                    history.pop_front();
+                }
+            }
+        });
+    }
+
+    /// Recorded traffic of one plan, oldest first
+    pub fn samples(&self, display_name: &str) -> Vec<u64> {
+        self.values.with(|values| {
+            values
+                .get(display_name)
+                .map(|history| history.iter().copied().collect())
+                .unwrap_or_default()
+        })
+    }
+}
+
+/// Traffic history of the dashboard, not provided on pages without refreshes
+pub fn use_traffic_history() -> Option<TrafficHistory> {
+    use_context::<TrafficHistory>()
+}
@@ -0,0 +1,207 @@
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_query_map};
+use serde::Deserialize;
+
+use crate::components::execution_plans::{OneExecutionStat, PlanServerAddress};
+use crate::components::toast::use_toast;
+use crate::models::execution_plan::{ExecutionStats, ExecutionStatsWithPlan};
+use crate::models::metric_history::MetricHistory;
+use crate::utils::{fetch_api, format_bytes, format_timestamp, i18n::t};
+
+const HISTORY_LIMIT: usize = 50;
+
+/// A past execution as listed by the execution_history endpoint
+#[derive(Deserialize, Clone, Debug)]
+pub struct HistoryEntry {
+    /// ID used to fetch the full execution with its plans
+    pub id: String,
+    /// Unix timestamp of the execution
+    pub created_at: u64,
+    #[serde(flatten)]
+    pub stats: ExecutionStats,
+}
+
+/// Execution plan history page, reads the server address from the `host` query parameter
+#[component]
+pub fn History() -> impl IntoView {
+    let query_map = use_query_map();
+    let server_address = Memo::new(move |_| {
+        query_map
+            .read()
+            .get("host")
+            .unwrap_or_else(|| "http://localhost:53703".to_string())
+    });
+
+    provide_context(MetricHistory::default());
+
+    view! {
+        <div class="min-h-screen bg-gray-50 dark:bg-gray-950">
+            <div class="container mx-auto px-6 py-6 max-w-7xl">
+                <div class="flex justify-between items-center mb-6 border-b border-gray-200 dark:border-gray-700 pb-3">
+                    <h1 class="text-2xl font-medium text-gray-800 dark:text-gray-100">{move || t("Execution History")}</h1>
+                    <A
+                        href=move || format!("/?host={}", urlencoding::encode(&server_address.get()))
+                        attr:class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                    >
+                        "Dashboard"
+                    </A>
+                </div>
+                <QueryHistoryPanel server_address=server_address />
+            </div>
+        </div>
+    }
+}
+
+/// Table of the latest executions, clicking a row opens its plans in a drawer
+#[component]
+pub fn QueryHistoryPanel(#[prop(into)] server_address: Signal<String>) -> impl IntoView {
+    let toast = use_toast();
+    provide_context(PlanServerAddress(server_address));
+    let (entries, set_entries) = signal(None::<Vec<HistoryEntry>>);
+    let (selected, set_selected) = signal(None::<ExecutionStatsWithPlan>);
+
+    let fetch_history = {
+        let toast = toast.clone();
+        Action::new(move |_: &()| {
+            let address = server_address.get_untracked();
+            let toast = toast.clone();
+
+            async move {
+                match fetch_api::<Vec<HistoryEntry>>(&format!(
+                    "{address}/execution_history?limit={HISTORY_LIMIT}"
+                ))
+                .await
+                {
+                    Ok(response) => {
+                        set_entries.set(Some(response));
+                    }
+                    Err(e) => {
+                        toast.show_error(format!("Failed to fetch execution history: {e}"));
+                    }
+                }
+            }
+        })
+    };
+
+    let fetch_execution = Action::new(move |id: &String| {
+        let address = server_address.get_untracked();
+        let id = id.clone();
+        let toast = toast.clone();
+
+        async move {
+            match fetch_api::<ExecutionStatsWithPlan>(&format!(
+                "{address}/execution_history/{}",
+                urlencoding::encode(&id)
+            ))
+            .await
+            {
+                Ok(response) => {
+                    set_selected.set(Some(response));
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to fetch execution {id}: {e}"));
+                }
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        server_address.track();
+        fetch_history.dispatch(());
+    });
+
+    view! {
+        <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+            <div class="flex justify-between items-center mb-3">
+                <h2 class="text-base font-medium text-gray-700 dark:text-gray-200">
+                    {format!("Last {HISTORY_LIMIT} queries")}
+                </h2>
+                <button
+                    class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-50 dark:hover:bg-gray-800"
+                    on:click=move |_| {
+                        fetch_history.dispatch(());
+                    }
+                >
+                    {move || t("Refresh")}
+                </button>
+            </div>
+            {move || match entries.get() {
+                Some(entries) if entries.is_empty() => {
+                    view! { <div class="text-gray-400 text-xs italic">"No past executions"</div> }
+                        .into_any()
+                }
+                Some(entries) => {
+                    view! {
+                        <table class="w-full text-xs">
+                            <thead>
+                                <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                    <th class="py-1 font-normal">"Name"</th>
+                                    <th class="py-1 font-normal">"Execution time"</th>
+                                    <th class="py-1 font-normal">"Network traffic"</th>
+                                    <th class="py-1 font-normal">"Created at"</th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                {entries
+                                    .into_iter()
+                                    .map(|entry| {
+                                        let id = entry.id.clone();
+                                        view! {
+                                            <tr
+                                                class="border-b border-gray-50 dark:border-gray-800 cursor-pointer hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                on:click=move |_| {
+                                                    fetch_execution.dispatch(id.clone());
+                                                }
+                                            >
+                                                <td class="py-1 text-gray-800 dark:text-gray-100">
+                                                    {entry.stats.display_name.clone()}
+                                                </td>
+                                                <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                                    {format!("{} ms", entry.stats.execution_time_ms)}
+                                                </td>
+                                                <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                                    {format_bytes(entry.stats.network_traffic_bytes)}
+                                                </td>
+                                                <td class="py-1 font-mono text-gray-800 dark:text-gray-100">
+                                                    {format_timestamp(entry.created_at)}
+                                                </td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </tbody>
+                        </table>
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! { <div class="text-gray-400 text-xs italic">"Loading history…"</div> }
+                        .into_any()
+                }
+            }}
+            {move || {
+                selected
+                    .get()
+                    .map(|stats| {
+                        view! {
+                            <div
+                                class="fixed inset-0 z-40 bg-black/20"
+                                on:click=move |_| set_selected.set(None)
+                            ></div>
+                            <div class="fixed inset-y-0 right-0 z-50 w-3/4 max-w-5xl bg-gray-50 dark:bg-gray-800 border-l border-gray-200 dark:border-gray-700 shadow-lg overflow-y-auto p-4">
+                                <div class="flex justify-end mb-2">
+                                    <button
+                                        class="text-xs text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 px-2 py-1 rounded hover:bg-gray-100 dark:hover:bg-gray-700"
+                                        on:click=move |_| set_selected.set(None)
+                                    >
+                                        {move || t("Close")}
+                                    </button>
+                                </div>
+                                <OneExecutionStat stats=stats />
+                            </div>
+                        }
+                    })
+            }}
+        </div>
+    }
+}
@@ -1,17 +1,45 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::components::cache_info::{
-    CacheInfo as CacheInfoComponent, CacheInfo as CacheInfoData, ParquetCacheUsage,
-};
-use crate::components::execution_plans::ExecutionStats as ExecutionPlansComponent;
-use crate::components::system_info::{
-    SystemInfo as SystemInfoComponent, SystemInfo as SystemInfoData,
+use crate::components::active_queries::ActiveQueries;
+use crate::components::auto_refresh::AutoRefreshControl;
+use crate::components::cache_browser::CacheBrowser;
+use crate::components::cache_info::{CacheInfo as CacheInfoComponent, CacheInfo as CacheInfoData};
+use crate::components::cluster_summary::{ClusterSummary, ServerCacheStats};
+use crate::components::collapse::CollapsedPanel;
+use crate::components::dev_panel::{provide_dev_stats, DevPanel};
+use crate::components::event_log::EventLog;
+use crate::components::eviction_policy::EvictionPolicy;
+use crate::components::execution_plans::{
+    provide_snapshot_compare, ExecutionStats as ExecutionPlansComponent, PlanServerAddress,
 };
+use crate::components::nav_tabs::NavTabs;
+use crate::components::quota_chart::QuotaChart;
+use crate::components::request_headers::RequestHeadersEditor;
+use crate::components::server_selector::ServerSelector;
+use crate::components::settings::{use_settings, PanelLayout, SettingsPanel};
+use crate::components::shortcut_help::ShortcutHelp;
+use crate::components::sla_config::SlaConfig;
+use crate::components::system_info::SystemInfo as SystemInfoComponent;
 use crate::components::toast::use_toast;
+use crate::components::toast_history::ToastHistoryBell;
 use crate::models::execution_plan::ExecutionStatsWithPlan;
-use crate::utils::fetch_api;
+use crate::models::metric_history::MetricHistory;
+use crate::models::profile::ConnectionProfile;
+use crate::models::server_info::{ParquetCacheUsage, SystemInfo as SystemInfoData};
+use crate::models::traffic_history::TrafficHistory;
+use crate::utils::config::use_app_config;
+use crate::utils::i18n::{t, use_locale, Locale};
+use crate::utils::shortcuts::{
+    focus_element_by_id, focus_search, provide_close_modals, use_keyboard_shortcuts, ShortcutScope,
+    PLAN_SELECTOR_ID, SERVER_ADDRESS_ID,
+};
+use crate::utils::theme::use_theme;
+use crate::utils::{
+    fetch_api, ping_server, storage, ws_connect, ws_url, WsConnectionState, WsMessage,
+};
 use leptos::{logging, prelude::*};
-use leptos_router::{hooks::use_navigate, hooks::use_query_map};
+use leptos_router::{components::A, hooks::use_navigate, hooks::use_query_map};
 use serde::Deserialize;
 
 #[allow(dead_code)]
@@ -32,22 +60,176 @@ struct CacheStatsParams {
     path: String,
 }
 
-/// Default Home Page - LiquidCache Server Monitoring Dashboard
+const PING_INTERVAL_MS: u64 = 5_000;
+/// Fastest adaptive polling interval
+const MIN_POLL_INTERVAL_MS: u64 = 1_000;
+/// Relative change of memory usage between polls that counts as rapid
+const RAPID_CHANGE_RATIO: f64 = 0.1;
+/// Polls without rapid change before slowing down again
+const STABLE_POLLS_TO_BACK_OFF: u32 = 5;
+
+/// Auto-refresh interval adapted to how fast metrics are changing
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PollingState {
+    current_interval_ms: u64,
+    stable_count: u32,
+}
+
+impl PollingState {
+    fn new(interval_ms: u64) -> Self {
+        Self {
+            current_interval_ms: interval_ms,
+            stable_count: 0,
+        }
+    }
+
+    /// Halve the interval on rapid change, double it after enough stable polls,
+    /// staying between `MIN_POLL_INTERVAL_MS` and `max_interval_ms`
+    fn record_poll(&mut self, changed_rapidly: bool, max_interval_ms: u64) {
+        if changed_rapidly {
+            self.current_interval_ms = (self.current_interval_ms / 2).max(MIN_POLL_INTERVAL_MS);
+            self.stable_count = 0;
+        } else {
+            self.stable_count += 1;
+            if self.stable_count >= STABLE_POLLS_TO_BACK_OFF {
+                self.current_interval_ms = (self.current_interval_ms * 2).min(max_interval_ms);
+                self.stable_count = 0;
+            }
+        }
+    }
+}
+
+/// Server address of the last Connect, used when the URL has no `host` parameter
+const SERVER_ADDRESS_KEY: &str = "liquid_cache_admin.server_address";
+pub const DEFAULT_SERVER_ADDRESS: &str = "http://localhost:53703";
+/// Most server connections open side by side
+const MAX_SERVER_TABS: usize = 5;
+
+/// One server connection of the tab bar, its dashboard keeps its own data and refresh cycle
+#[derive(Clone, Copy)]
+struct ServerTab {
+    id: usize,
+    address: RwSignal<String>,
+    /// Round-trip time of the last successful health check
+    latency_ms: RwSignal<Option<u64>>,
+    ping_failed: RwSignal<bool>,
+    /// Notified by the "r" shortcut while the tab is active
+    refresh: Trigger,
+    cache_info: RwSignal<Option<CacheInfoData>>,
+    cache_usage: RwSignal<Option<ParquetCacheUsage>>,
+    /// Address of the last Connect, drives the websocket and the health check
+    connected_address: RwSignal<Option<String>>,
+}
+
+impl ServerTab {
+    fn new(id: usize, address: String) -> Self {
+        Self {
+            id,
+            address: RwSignal::new(address),
+            latency_ms: RwSignal::new(None),
+            ping_failed: RwSignal::new(false),
+            refresh: Trigger::new(),
+            cache_info: RwSignal::new(None),
+            cache_usage: RwSignal::new(None),
+            connected_address: RwSignal::new(None),
+        }
+    }
+
+    /// Host and port of the address, e.g. "localhost:53703"
+    fn host_name(&self) -> String {
+        self.address.with(|address| {
+            let without_scheme = address
+                .split_once("://")
+                .map_or(address.as_str(), |(_, rest)| rest);
+            without_scheme
+                .split('/')
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        })
+    }
+}
+
+/// Server tabs of the dashboard, provided above the router so they survive visiting other pages
+#[derive(Clone, Copy)]
+pub struct ServerTabs {
+    tabs: RwSignal<Vec<ServerTab>>,
+    next_tab_id: StoredValue<usize>,
+    active_tab_id: RwSignal<usize>,
+    /// Owner of the tabs' signals, outlives the Home page
+    owner: StoredValue<Owner>,
+}
+
+impl ServerTabs {
+    /// Append a tab for `address` and return its id
+    fn push(&self, address: String) -> usize {
+        let id = self.next_tab_id.get_value();
+        self.next_tab_id.set_value(id + 1);
+        let tab = self
+            .owner
+            .with_value(|owner| owner.with(|| ServerTab::new(id, address)));
+        self.tabs.update(|tabs| tabs.push(tab));
+        id
+    }
+}
+
+pub fn provide_server_tabs() -> ServerTabs {
+    let server_tabs = ServerTabs {
+        tabs: RwSignal::new(Vec::new()),
+        next_tab_id: StoredValue::new(0),
+        active_tab_id: RwSignal::new(0),
+        owner: StoredValue::new(Owner::current().unwrap_or_default()),
+    };
+    provide_context(server_tabs);
+    server_tabs
+}
+
+/// Color of the health dot for the last health check
+fn health_dot(latency_ms: Option<u64>, ping_failed: bool) -> &'static str {
+    match latency_ms {
+        Some(latency) if latency < 50 => "bg-green-500",
+        Some(latency) if latency < 200 => "bg-yellow-400",
+        Some(_) => "bg-red-500",
+        None if ping_failed => "bg-gray-400",
+        None => "bg-gray-200",
+    }
+}
+
+/// Connection controls and panels of one server tab
 #[component]
-pub fn Home() -> impl IntoView {
+fn ServerDashboard(
+    tab: ServerTab,
+    profiles: RwSignal<Vec<ConnectionProfile>>,
+    layout: PanelLayout,
+    /// Fetch everything right away, e.g. for the `host` parameter of the URL
+    #[prop(default = false)]
+    connect_on_mount: bool,
+    /// Display name of the plan to select once plans are loaded, e.g. the `plan` URL parameter
+    #[prop(default = None)]
+    initial_plan: Option<String>,
+) -> impl IntoView {
+    let initial_plan = StoredValue::new(initial_plan);
     let toast = use_toast();
+    let app_config = use_app_config();
+    let (token_input, set_token_input) =
+        signal(app_config.auth_token.get_untracked().unwrap_or_default());
 
-    // Read query parameters
-    let query_map = use_query_map();
-    let host_param = move || query_map.read().get("host");
-
-    let (server_address, set_server_address) = signal("http://localhost:53703".to_string());
-    let (cache_usage, set_cache_usage) = signal(None::<ParquetCacheUsage>);
-    let (cache_info, set_cache_info) = signal(None::<CacheInfoData>);
+    let server_address = tab.address.read_only();
+    let set_server_address = tab.address.write_only();
+    let (cache_usage, set_cache_usage) = tab.cache_usage.split();
+    let (cache_info, set_cache_info) = tab.cache_info.split();
     let (system_info, set_system_info) = signal(None);
 
     let (execution_stats, set_execution_stats) = signal(None::<Arc<Vec<ExecutionStatsWithPlan>>>);
 
+    let metric_history = MetricHistory::default();
+    provide_context(metric_history);
+    let traffic_history = TrafficHistory::default();
+    provide_context(traffic_history);
+    provide_context(PlanServerAddress(server_address.into()));
+    let shortcut_scope = ShortcutScope(tab.id);
+    provide_context(shortcut_scope);
+
     let fetch_cache_usage = {
         let toast = toast.clone();
         Action::new(move |_: &()| {
@@ -69,6 +251,10 @@ pub fn Home() -> impl IntoView {
         })
     };
 
+    // set once the actions exist, so their error toasts can offer a retry
+    let cache_info_action = StoredValue::new(None::<Action<(), ()>>);
+    let execution_plans_action = StoredValue::new(None::<Action<(), ()>>);
+
     let fetch_cache_info = {
         let toast = toast.clone();
         Action::new(move |_: &()| {
@@ -83,7 +269,14 @@ pub fn Home() -> impl IntoView {
                     }
                     Err(e) => {
                         logging::error!("Failed to fetch cache info: {}", e);
-                        toast.show_error(format!("Failed to fetch cache info: {e}"));
+                        toast.show_error_with_retry(
+                            format!("Failed to fetch cache info: {e}"),
+                            Arc::new(move || {
+                                if let Some(action) = cache_info_action.get_value() {
+                                    action.dispatch(());
+                                }
+                            }),
+                        );
                     }
                 }
             }
@@ -122,15 +315,97 @@ pub fn Home() -> impl IntoView {
                 .await
                 {
                     Ok(response) => {
+                        metric_history.record(&response);
+                        traffic_history.record(&response);
                         set_execution_stats.set(Some(Arc::new(response)));
                     }
                     Err(e) => {
-                        toast.show_error(format!("Failed to fetch execution plans: {e}"));
+                        toast.show_error_with_retry(
+                            format!("Failed to fetch execution plans: {e}"),
+                            Arc::new(move || {
+                                if let Some(action) = execution_plans_action.get_value() {
+                                    action.dispatch(());
+                                }
+                            }),
+                        );
+                    }
+                }
+            }
+        })
+    };
+
+    cache_info_action.set_value(Some(fetch_cache_info));
+    execution_plans_action.set_value(Some(fetch_execution_plans));
+    provide_snapshot_compare(fetch_execution_plans);
+
+    let (connected_address, set_connected_address) = tab.connected_address.split();
+
+    // Server push: the websocket mirrors the polled endpoints, manual refresh keeps working
+    let (ws_state, set_ws_state) = signal(WsConnectionState::Disconnected);
+    Effect::new(move |_| {
+        let Some(address) = connected_address.get() else {
+            return;
+        };
+        let ws_message = ws_connect(&ws_url(&address), set_ws_state);
+        Effect::new(move |_| match ws_message.get() {
+            Some(WsMessage::ParquetCacheUsage(usage)) => set_cache_usage.set(Some(usage)),
+            Some(WsMessage::SystemInfo(info)) => set_system_info.set(Some(info)),
+            Some(WsMessage::ExecutionPlans(plans)) => {
+                metric_history.record(&plans);
+                traffic_history.record(&plans);
+                set_execution_stats.set(Some(Arc::new(plans)));
+            }
+            None => {}
+        });
+    });
+
+    // Health check, independent of the data refresh
+    let ping = {
+        let toast = toast.clone();
+        Action::new(move |_: &()| {
+            let address = connected_address.get_untracked();
+            let toast = toast.clone();
+
+            async move {
+                let Some(address) = address else {
+                    return;
+                };
+                match ping_server(&address).await {
+                    Ok(latency) => {
+                        tab.latency_ms.set(Some(latency));
+                        tab.ping_failed.set(false);
+                    }
+                    Err(e) => {
+                        // only report the server going away, not every failed ping
+                        if !tab.ping_failed.get_untracked() {
+                            toast.show_error(format!("Server unreachable: {e}"));
+                        }
+                        tab.latency_ms.set(None);
+                        tab.ping_failed.set(true);
                     }
                 }
             }
         })
     };
+    let ping_timer = set_interval_with_handle(
+        move || {
+            if connected_address.get_untracked().is_some() && !ping.pending().get_untracked() {
+                ping.dispatch(());
+            }
+        },
+        std::time::Duration::from_millis(PING_INTERVAL_MS),
+    )
+    .ok();
+    on_cleanup(move || {
+        if let Some(handle) = ping_timer {
+            handle.clear();
+        }
+    });
+    Effect::new(move |_| {
+        if connected_address.get().is_some() {
+            ping.dispatch(());
+        }
+    });
 
     let navigate = use_navigate();
 
@@ -141,17 +416,50 @@ pub fn Home() -> impl IntoView {
         fetch_execution_plans.dispatch(());
     };
 
-    // Initialize server address from URL parameter on mount (runs only once)
-    let host = host_param();
-    if let Some(host) = host {
-        logging::log!("Found host parameter on initial load: {}", host);
-        set_server_address.set(host);
-        // Automatically fetch data when loading from URL parameter
+    let refresh_interval_ms = RwSignal::new(10_000u64);
+    let polling = RwSignal::new(PollingState::new(refresh_interval_ms.get_untracked()));
+    // the user-configured interval is the upper bound, start over when it changes
+    Effect::new(move |_| polling.set(PollingState::new(refresh_interval_ms.get())));
+    let last_memory_usage = StoredValue::new(None::<u64>);
+    Effect::new(move |_| {
+        let Some(memory_usage) =
+            cache_info.with(|info| info.as_ref().map(|info| info.memory_usage_bytes))
+        else {
+            return;
+        };
+        if let Some(previous) = last_memory_usage.get_value() {
+            let change = memory_usage.abs_diff(previous) as f64 / previous.max(1) as f64;
+            let max_interval_ms = refresh_interval_ms.get_untracked();
+            polling.update(|polling| {
+                polling.record_poll(change > RAPID_CHANGE_RATIO, max_interval_ms)
+            });
+        }
+        last_memory_usage.set_value(Some(memory_usage));
+    });
+    let any_fetch_pending = Signal::derive(move || {
+        fetch_cache_usage.pending().get()
+            || fetch_cache_info.pending().get()
+            || fetch_system_info.pending().get()
+            || fetch_execution_plans.pending().get()
+    });
+
+    if connect_on_mount {
+        set_connected_address.set(Some(server_address.get_untracked()));
+    }
+    // also reconnects a tab that was connected before leaving the page
+    if connected_address.get_untracked().is_some() {
         fetch_all_data(());
     }
+    Effect::new(move |previous: Option<()>| {
+        tab.refresh.track();
+        if previous.is_some() {
+            fetch_all_data(());
+        }
+    });
 
-    let connect_and_update_url = move |_| {
+    let connect_and_update_url = move || {
         let current_address = server_address.get();
+        storage::set(SERVER_ADDRESS_KEY, &current_address);
         // Update URL with the current server address (simple encoding)
         let encoded_address = current_address
             .replace("://", "%3A%2F%2F")
@@ -160,14 +468,363 @@ pub fn Home() -> impl IntoView {
         navigate(&query_string, Default::default());
         // Fetch data
         fetch_all_data(());
+        set_connected_address.set(Some(current_address));
+    };
+
+    view! {
+        // Connection section
+        <div class="mb-6">
+            <div class="flex items-center space-x-2 mb-4">
+                <ServerSelector
+                    profiles=profiles
+                    server_address=server_address
+                    on_select=Callback::new({
+                        let connect_and_update_url = connect_and_update_url.clone();
+                        move |address| {
+                            set_server_address.set(address);
+                            connect_and_update_url();
+                        }
+                    })
+                />
+                <input
+                    type="text"
+                    id=shortcut_scope.id(SERVER_ADDRESS_ID)
+                    placeholder=move || t("Server address")
+                    class="flex-1 px-3 py-2 border border-gray-200 dark:border-gray-700 rounded focus:outline-none focus:border-gray-400 text-sm text-gray-700 dark:text-gray-200"
+                    prop:value=server_address
+                    on:input=move |ev| {
+                        set_server_address.set(event_target_value(&ev));
+                    }
+                />
+                <button
+                    class="px-4 py-2 border border-gray-200 dark:border-gray-700 rounded text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors text-sm"
+                    on:click=move |_| connect_and_update_url()
+                >
+                    {move || t("Connect")}
+                </button>
+                {move || {
+                    if connected_address.get().is_none() {
+                        return ().into_any();
+                    }
+                    let latency_ms = tab.latency_ms.get();
+                    let ping_failed = tab.ping_failed.get();
+                    let dot = health_dot(latency_ms, ping_failed);
+                    let label = match latency_ms {
+                        Some(latency) => format!("{latency} ms"),
+                        None if ping_failed => "Disconnected".to_string(),
+                        None => "…".to_string(),
+                    };
+                    view! {
+                        <span
+                            class="flex items-center gap-1 px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs font-mono text-gray-600 dark:text-gray-300"
+                            title="Round-trip time of GET /health"
+                        >
+                            <span class=format!("w-2 h-2 rounded-full {dot}")></span>
+                            {label}
+                        </span>
+                    }
+                        .into_any()
+                }}
+                {move || {
+                    let (dot, label) = match ws_state.get() {
+                        WsConnectionState::Connected => ("bg-green-500", "Connected"),
+                        WsConnectionState::Reconnecting => {
+                            ("bg-yellow-400 animate-pulse", "Reconnecting")
+                        }
+                        WsConnectionState::Disconnected => ("bg-gray-300", "Disconnected"),
+                    };
+                    view! {
+                        <span
+                            class="flex items-center gap-1 text-xs text-gray-600 dark:text-gray-300"
+                            title="Live updates over websocket"
+                        >
+                            <span class=format!("w-2 h-2 rounded-full {dot}")></span>
+                            {label}
+                        </span>
+                    }
+                }}
+                <A
+                    href=move || {
+                        format!("/history?host={}", urlencoding::encode(&server_address.get()))
+                    }
+                    attr:class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                >
+                    {move || t("History")}
+                </A>
+                <AutoRefreshControl
+                    interval_ms=refresh_interval_ms
+                    effective_interval_ms=Memo::new(move |_| {
+                        polling.with(|polling| polling.current_interval_ms)
+                    })
+                    on_tick=Callback::new(move |_| fetch_all_data(()))
+                    pending=any_fetch_pending
+                />
+            </div>
+            <div class="flex items-center space-x-2">
+                <input
+                    type="password"
+                    placeholder="Auth token (optional)"
+                    autocomplete="off"
+                    class="w-72 px-3 py-1 border border-gray-200 dark:border-gray-700 rounded focus:outline-none focus:border-gray-400 text-xs text-gray-700 dark:text-gray-200"
+                    prop:value=token_input
+                    on:input=move |ev| set_token_input.set(event_target_value(&ev))
+                />
+                <button
+                    class="px-3 py-1 border border-gray-200 dark:border-gray-700 rounded text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors text-xs"
+                    on:click=move |_| {
+                        let token = token_input.get().trim().to_string();
+                        app_config.auth_token.set((!token.is_empty()).then_some(token));
+                    }
+                >
+                    {move || t("Save token")}
+                </button>
+                <span class="text-xs text-gray-400">
+                    {move || {
+                        if app_config.auth_token.with(Option::is_some) {
+                            "Token set for this tab"
+                        } else {
+                            "No token"
+                        }
+                    }}
+                </span>
+            </div>
+            <RequestHeadersEditor />
+        </div>
+
+        // Dashboard Grid Layout
+        <div class="space-y-4 mb-6">
+            // Top row - System Info and Cache Info
+            <div class="grid grid-cols-1 lg:grid-cols-2 gap-4">
+                <CollapsedPanel title="System" expanded=layout.system />
+                <div class:hidden=move || !layout.system.get()>
+                    <SystemInfoComponent
+                        system_info=system_info
+                        on_refresh=Box::new(move || {
+                            let _ = fetch_system_info.dispatch(());
+                        })
+                        loading=fetch_system_info.pending()
+                    />
+                </div>
+
+                <CollapsedPanel title="Cache" expanded=layout.cache />
+                <div class:hidden=move || !layout.cache.get()>
+                    <CacheInfoComponent
+                        cache_info=cache_info
+                        cache_usage=cache_usage
+                        server_address=server_address
+                        connected_address=connected_address
+                        on_refresh=Box::new(move || {
+                            fetch_cache_info.dispatch(());
+                            fetch_cache_usage.dispatch(());
+                        })
+                        loading=Signal::derive(move || {
+                            fetch_cache_info.pending().get() || fetch_cache_usage.pending().get()
+                        })
+                    />
+                </div>
+            </div>
+
+            <CollapsedPanel title="More panels" expanded=layout.secondary />
+            <div class="space-y-4" class:hidden=move || !layout.secondary.get()>
+                <div class="grid grid-cols-1 lg:grid-cols-2 gap-4">
+                    <CacheBrowser server_address=server_address />
+                    <QuotaChart server_address=server_address />
+                </div>
+
+                <div class="grid grid-cols-1 lg:grid-cols-2 gap-4">
+                    <EvictionPolicy connected_address=connected_address />
+                    <EventLog server_address=server_address />
+                </div>
+
+                <ActiveQueries connected_address=connected_address />
+
+                <SlaConfig execution_stats=execution_stats />
+            </div>
+
+            <CollapsedPanel title="Execution Plans" expanded=layout.execution_plans />
+            <div class:hidden=move || {
+                !layout.execution_plans.get()
+            }>
+                {move || {
+                    if let Some(plans) = execution_stats.get() {
+                        view! {
+                            <ExecutionPlansComponent
+                                execution_stats=plans
+                                initial_plan=initial_plan.try_update_value(Option::take).flatten()
+                                on_refresh=Box::new(move || {
+                                    fetch_execution_plans.dispatch(());
+                                })
+                                loading=fetch_execution_plans.pending()
+                            />
+                        }
+                            .into_any()
+                    } else {
+                        view! {
+                            <div class="text-gray-500 dark:text-gray-400">
+                                {move || t("No execution found")}
+                            </div>
+                        }
+                            .into_any()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
+
+/// Default Home Page - LiquidCache Server Monitoring Dashboard
+#[component]
+pub fn Home() -> impl IntoView {
+    let locale = use_locale();
+    let theme = use_theme();
+    let layout = PanelLayout::new(use_settings().layout_preset);
+    let (show_settings, set_show_settings) = signal(false);
+    let profiles = RwSignal::new(ConnectionProfile::load_all());
+    provide_dev_stats();
+
+    // The URL parameter wins over the last Connect and is connected right away
+    let query_map = use_query_map();
+    let host_param = query_map.read_untracked().get("host");
+    if let Some(host) = &host_param {
+        logging::log!("Found host parameter on initial load: {}", host);
+    }
+    let plan_param = query_map.read_untracked().get("plan");
+
+    // tabs are kept while other pages are shown, the URL parameter points the active one
+    // at its server
+    let server_tabs =
+        use_context::<ServerTabs>().expect("ServerTabs must be provided above the router");
+    let ServerTabs {
+        tabs,
+        active_tab_id,
+        ..
+    } = server_tabs;
+    if tabs.with_untracked(Vec::is_empty) {
+        let first_address = host_param
+            .clone()
+            .or_else(|| storage::get(SERVER_ADDRESS_KEY))
+            .unwrap_or_else(|| DEFAULT_SERVER_ADDRESS.to_string());
+        active_tab_id.set(server_tabs.push(first_address));
+    } else if let Some(host) = &host_param {
+        let active_tab_id = active_tab_id.get_untracked();
+        tabs.with_untracked(|tabs| {
+            if let Some(tab) = tabs.iter().find(|tab| tab.id == active_tab_id) {
+                tab.address.set(host.clone());
+            }
+        });
+    }
+    let connect_tab_id = host_param.is_some().then(|| active_tab_id.get_untracked());
+    let plan_tab_id = active_tab_id.get_untracked();
+
+    let dragged_tab_id = StoredValue::new(None::<usize>);
+    let active_tab = move || {
+        let active_tab_id = active_tab_id.get();
+        tabs.with(|tabs| tabs.iter().find(|tab| tab.id == active_tab_id).copied())
+    };
+    let active_profile_name = move || {
+        let address = active_tab()?.address.get();
+        profiles.with(|profiles| {
+            profiles
+                .iter()
+                .find(|profile| profile.address == address)
+                .map(|profile| profile.name.clone())
+        })
     };
 
+    let add_tab = move |_| {
+        if tabs.with(|tabs| tabs.len() >= MAX_SERVER_TABS) {
+            return;
+        }
+        active_tab_id.set(server_tabs.push(DEFAULT_SERVER_ADDRESS.to_string()));
+    };
+    let close_tab = move |id: usize| {
+        tabs.update(|tabs| {
+            let Some(index) = tabs.iter().position(|tab| tab.id == id) else {
+                return;
+            };
+            tabs.remove(index);
+            // the neighbour takes over when the active tab is closed
+            if active_tab_id.get_untracked() == id {
+                if let Some(neighbour) = tabs.get(index.min(tabs.len().saturating_sub(1))) {
+                    active_tab_id.set(neighbour.id);
+                }
+            }
+        });
+    };
+    // cache figures of every tab that has fetched its cache info
+    let cluster_servers = Signal::derive(move || {
+        tabs.get()
+            .into_iter()
+            .filter_map(|tab| {
+                let info = tab.cache_info.get()?;
+                let usage = tab.cache_usage.get();
+                Some(ServerCacheStats::new(
+                    tab.host_name(),
+                    &info,
+                    usage.as_ref(),
+                ))
+            })
+            .collect::<Vec<_>>()
+    });
+    let move_tab = move |from_id: usize, to_id: usize| {
+        tabs.update(|tabs| {
+            let from = tabs.iter().position(|tab| tab.id == from_id);
+            let to = tabs.iter().position(|tab| tab.id == to_id);
+            if let (Some(from), Some(to)) = (from, to) {
+                let tab = tabs.remove(from);
+                tabs.insert(to, tab);
+            }
+        });
+    };
+
+    let close_modals = provide_close_modals();
+    let show_shortcut_help = RwSignal::new(false);
+    use_keyboard_shortcuts(HashMap::from([
+        (
+            "r",
+            Box::new(move || {
+                if let Some(tab) = active_tab() {
+                    tab.refresh.notify();
+                }
+            }) as Box<dyn Fn()>,
+        ),
+        (
+            "e",
+            Box::new(move || {
+                focus_element_by_id(
+                    &ShortcutScope(active_tab_id.get_untracked()).id(PLAN_SELECTOR_ID),
+                )
+            }),
+        ),
+        (
+            "c",
+            Box::new(move || {
+                focus_element_by_id(
+                    &ShortcutScope(active_tab_id.get_untracked()).id(SERVER_ADDRESS_ID),
+                )
+            }),
+        ),
+        ("/", Box::new(focus_search)),
+        (
+            "Escape",
+            Box::new(move || {
+                show_shortcut_help.set(false);
+                set_show_settings.set(false);
+                close_modals.notify();
+            }),
+        ),
+        (
+            "?",
+            Box::new(move || show_shortcut_help.update(|s| *s = !*s)),
+        ),
+    ]));
+
     view! {
-        <div class="min-h-screen bg-gray-50">
+        <div class="min-h-screen bg-gray-50 dark:bg-gray-950">
             <ErrorBoundary fallback=|errors| {
                 view! {
-                    <h1 class="text-2xl text-gray-700 mb-4">"Something went wrong"</h1>
-                    <ul class="text-sm text-gray-600">
+                    <h1 class="text-2xl text-gray-700 dark:text-gray-200 mb-4">{move || t("Something went wrong")}</h1>
+                    <ul class="text-sm text-gray-600 dark:text-gray-300">
                         {move || {
                             errors
                                 .get()
@@ -179,73 +836,138 @@ pub fn Home() -> impl IntoView {
                 }
             }>
                 <div class="container mx-auto px-6 py-6 max-w-7xl">
-                    <h1 class="text-2xl font-medium text-gray-800 mb-6 border-b border-gray-200 pb-3">
-                        "LiquidCache Monitor"
-                    </h1>
-
-                    // Connection section
-                    <div class="mb-6">
-                        <div class="flex items-center space-x-2 mb-4">
-                            <input
-                                type="text"
-                                placeholder="Server address"
-                                class="flex-1 px-3 py-2 border border-gray-200 rounded focus:outline-none focus:border-gray-400 text-sm text-gray-700"
-                                prop:value=server_address
-                                on:input=move |ev| {
-                                    set_server_address.set(event_target_value(&ev));
-                                }
-                            />
+                    <div class="flex justify-between items-center mb-6 border-b border-gray-200 dark:border-gray-700 pb-3">
+                        <div class="flex items-baseline gap-2">
+                            <h1 class="text-2xl font-medium text-gray-800 dark:text-gray-100">
+                                {move || t("LiquidCache Monitor")}
+                            </h1>
+                            {move || {
+                                active_profile_name()
+                                    .map(|name| {
+                                        view! { <span class="text-sm text-gray-500 dark:text-gray-400">{name}</span> }
+                                    })
+                            }}
+                            <NavTabs host=Signal::derive(move || {
+                                active_tab().map(|tab| tab.address.get()).unwrap_or_default()
+                            }) />
+                        </div>
+                        <div class="flex items-center gap-2">
                             <button
-                                class="px-4 py-2 border border-gray-200 rounded text-gray-700 hover:bg-gray-100 transition-colors text-sm"
-                                on:click=connect_and_update_url
+                                class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                                on:click=move |_| set_show_settings.update(|s| *s = !*s)
                             >
-                                "Connect"
+                                {move || t("Settings")}
                             </button>
+                            <ToastHistoryBell />
+                            <button
+                                class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                                title=move || format!("Theme: {}", theme.get().label())
+                                on:click=move |_| theme.update(|theme| *theme = theme.next())
+                            >
+                                {move || theme.get().icon()}
+                            </button>
+                            <select
+                                class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 bg-white dark:bg-gray-900 focus:outline-none"
+                                prop:value=move || locale.get().code()
+                                on:change=move |ev| {
+                                    if let Some(selected) = Locale::from_code(&event_target_value(&ev)) {
+                                        locale.set(selected);
+                                    }
+                                }
+                            >
+                                {Locale::ALL
+                                    .into_iter()
+                                    .map(|l| view! { <option value=l.code()>{l.native_name()}</option> })
+                                    .collect_view()}
+                            </select>
                         </div>
                     </div>
 
-                    // Dashboard Grid Layout
-                    <div class="space-y-4 mb-6">
-                        // Top row - System Info and Cache Info
-                        <div class="grid grid-cols-1 lg:grid-cols-2 gap-4">
-                            <SystemInfoComponent
-                                system_info=system_info
-                                on_refresh=Box::new(move || {
-                                    let _ = fetch_system_info.dispatch(());
-                                })
-                            />
-
-                            <CacheInfoComponent
-                                cache_info=cache_info
-                                cache_usage=cache_usage
-                                server_address=server_address
-                                on_refresh=Box::new(move || {
-                                    fetch_cache_info.dispatch(());
-                                    fetch_cache_usage.dispatch(());
-                                })
-                            />
-                        </div>
+                    <Show when=move || show_settings.get()>
+                        <SettingsPanel />
+                    </Show>
+                    <ShortcutHelp show=show_shortcut_help />
 
-                        {move || {
-                            if let Some(plans) = execution_stats.get() {
-                                view! {
-                                    <ExecutionPlansComponent
-                                        execution_stats=plans
-                                        on_refresh=Box::new(move || {
-                                            fetch_execution_plans.dispatch(());
-                                        })
-                                    />
+                    <div class="flex items-end gap-1 mb-4 border-b border-gray-200 dark:border-gray-700">
+                        <For each=move || tabs.get() key=|tab| tab.id let:tab>
+                            <div
+                                draggable="true"
+                                class=move || {
+                                    if active_tab_id.get() == tab.id {
+                                        "flex items-center gap-2 px-3 py-1.5 -mb-px border border-gray-200 dark:border-gray-700 border-b-gray-50 dark:border-b-gray-950 rounded-t bg-gray-50 dark:bg-gray-950 text-sm text-gray-800 dark:text-gray-100 cursor-pointer select-none"
+                                    } else {
+                                        "flex items-center gap-2 px-3 py-1.5 border border-transparent rounded-t text-sm text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 cursor-pointer select-none"
+                                    }
                                 }
-                                    .into_any()
-                            } else {
-                                view! { <div class="text-gray-500">"No execution found"</div> }
-                                    .into_any()
-                            }
-                        }}
+                                title=move || tab.address.get()
+                                on:click=move |_| active_tab_id.set(tab.id)
+                                on:dragstart=move |ev| {
+                                    dragged_tab_id.set_value(Some(tab.id));
+                                    // Firefox only starts dragging with data attached
+                                    if let Some(data) = ev.data_transfer() {
+                                        let _ = data.set_data("text/plain", &tab.id.to_string());
+                                    }
+                                }
+                                on:dragover=move |ev| ev.prevent_default()
+                                on:drop=move |ev| {
+                                    ev.prevent_default();
+                                    if let Some(from_id) = dragged_tab_id.get_value() {
+                                        move_tab(from_id, tab.id);
+                                    }
+                                    dragged_tab_id.set_value(None);
+                                }
+                            >
+                                <span class=move || {
+                                    format!(
+                                        "w-2 h-2 rounded-full {}",
+                                        health_dot(tab.latency_ms.get(), tab.ping_failed.get()),
+                                    )
+                                }></span>
+                                {move || tab.host_name()}
+                                <Show when=move || tabs.with(|tabs| tabs.len() > 1)>
+                                    <button
+                                        class="text-gray-400 hover:text-gray-700 dark:hover:text-gray-200"
+                                        title="Close tab"
+                                        on:click=move |ev| {
+                                            ev.stop_propagation();
+                                            close_tab(tab.id);
+                                        }
+                                    >
+                                        "×"
+                                    </button>
+                                </Show>
+                            </div>
+                        </For>
+                        <button
+                            class="px-2 py-1.5 text-sm text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 disabled:opacity-50 disabled:cursor-default"
+                            title=format!("Add a server connection, up to {MAX_SERVER_TABS}")
+                            disabled=move || tabs.with(|tabs| tabs.len() >= MAX_SERVER_TABS)
+                            on:click=add_tab
+                        >
+                            "+"
+                        </button>
                     </div>
 
+                    <Show when=move || tabs.with(|tabs| tabs.len() > 1)>
+                        <ClusterSummary servers=cluster_servers />
+                    </Show>
+
+                    <For each=move || tabs.get() key=|tab| tab.id let:tab>
+                        <div class:hidden=move || { active_tab_id.get() != tab.id }>
+                            <ServerDashboard
+                                tab=tab
+                                profiles=profiles
+                                layout=layout
+                                connect_on_mount=connect_tab_id == Some(tab.id)
+                                initial_plan=(tab.id == plan_tab_id)
+                                    .then(|| plan_param.clone())
+                                    .flatten()
+                            />
+                        </div>
+                    </For>
                 </div>
             </ErrorBoundary>
+            <DevPanel />
         </div>
     }
 }
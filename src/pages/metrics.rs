@@ -0,0 +1,232 @@
+use leptos::prelude::*;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use std::cmp::Ordering;
+
+use crate::components::nav_tabs::NavTabs;
+use crate::components::toast::use_toast;
+use crate::models::execution_plan::ExecutionStatsWithPlan;
+use crate::pages::home::DEFAULT_SERVER_ADDRESS;
+use crate::utils::{fetch_api, format_bytes, format_timestamp, i18n::t};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SortColumn {
+    DisplayName,
+    ExecutionTime,
+    NetworkTraffic,
+    PlanCount,
+    CreatedAt,
+}
+
+impl SortColumn {
+    const ALL: [SortColumn; 5] = [
+        SortColumn::DisplayName,
+        SortColumn::ExecutionTime,
+        SortColumn::NetworkTraffic,
+        SortColumn::PlanCount,
+        SortColumn::CreatedAt,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            SortColumn::DisplayName => "Name",
+            SortColumn::ExecutionTime => "Execution time",
+            SortColumn::NetworkTraffic => "Network traffic",
+            SortColumn::PlanCount => "Plans",
+            SortColumn::CreatedAt => "Created at",
+        }
+    }
+
+    fn compare(&self, a: &ExecutionStatsWithPlan, b: &ExecutionStatsWithPlan) -> Ordering {
+        match self {
+            SortColumn::DisplayName => a
+                .execution_stats
+                .display_name
+                .cmp(&b.execution_stats.display_name),
+            SortColumn::ExecutionTime => a
+                .execution_stats
+                .execution_time_ms
+                .cmp(&b.execution_stats.execution_time_ms),
+            SortColumn::NetworkTraffic => a
+                .execution_stats
+                .network_traffic_bytes
+                .cmp(&b.execution_stats.network_traffic_bytes),
+            SortColumn::PlanCount => a.plans.len().cmp(&b.plans.len()),
+            SortColumn::CreatedAt => created_at(a).cmp(&created_at(b)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// Creation time of the first plan of the execution
+fn created_at(stats: &ExecutionStatsWithPlan) -> Option<u64> {
+    stats.plans.first().map(|plan| plan.created_at)
+}
+
+/// All executions of the server in one sortable table, reads the server address from the
+/// `host` query parameter. Clicking a row opens the execution on the dashboard.
+#[component]
+pub fn Metrics() -> impl IntoView {
+    let toast = use_toast();
+    let navigate = use_navigate();
+    let query_map = use_query_map();
+    let server_address = Memo::new(move |_| {
+        query_map
+            .read()
+            .get("host")
+            .unwrap_or_else(|| DEFAULT_SERVER_ADDRESS.to_string())
+    });
+    let (executions, set_executions) = signal(None::<Vec<ExecutionStatsWithPlan>>);
+    let (sort_col, set_sort_col) = signal(SortColumn::ExecutionTime);
+    let (sort_dir, set_sort_dir) = signal(SortDirection::Descending);
+
+    let fetch_executions = Action::new(move |_: &()| {
+        let address = server_address.get_untracked();
+        let toast = toast.clone();
+
+        async move {
+            match fetch_api::<Vec<ExecutionStatsWithPlan>>(&format!("{address}/execution_plans"))
+                .await
+            {
+                Ok(response) => {
+                    set_executions.set(Some(response));
+                }
+                Err(e) => {
+                    toast.show_error(format!("Failed to fetch execution plans: {e}"));
+                }
+            }
+        }
+    });
+
+    Effect::new(move |_| {
+        server_address.track();
+        fetch_executions.dispatch(());
+    });
+
+    let sort_by = move |column: SortColumn| {
+        if sort_col.get_untracked() == column {
+            set_sort_dir.update(|direction| {
+                *direction = match direction {
+                    SortDirection::Ascending => SortDirection::Descending,
+                    SortDirection::Descending => SortDirection::Ascending,
+                }
+            });
+        } else {
+            set_sort_col.set(column);
+            set_sort_dir.set(SortDirection::Ascending);
+        }
+    };
+    let sorted_executions = move || {
+        let mut executions = executions.get()?;
+        let column = sort_col.get();
+        let direction = sort_dir.get();
+        executions.sort_by(|a, b| match direction {
+            SortDirection::Ascending => column.compare(a, b),
+            SortDirection::Descending => column.compare(b, a),
+        });
+        Some(executions)
+    };
+
+    view! {
+        <div class="min-h-screen bg-gray-50 dark:bg-gray-950">
+            <div class="container mx-auto px-6 py-6 max-w-7xl">
+                <div class="flex justify-between items-center mb-6 border-b border-gray-200 dark:border-gray-700 pb-3">
+                    <div class="flex items-center gap-4">
+                        <h1 class="text-2xl font-medium text-gray-800 dark:text-gray-100">{move || t("Metrics")}</h1>
+                        <NavTabs host=server_address />
+                    </div>
+                    <button
+                        class="px-2 py-1 border border-gray-200 dark:border-gray-700 rounded text-xs text-gray-600 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-800 transition-colors"
+                        on:click=move |_| {
+                            fetch_executions.dispatch(());
+                        }
+                    >
+                        {move || t("Refresh")}
+                    </button>
+                </div>
+                <div class="border border-gray-200 dark:border-gray-700 rounded-lg bg-white dark:bg-gray-900 p-4">
+                    {move || match sorted_executions() {
+                        Some(executions) if executions.is_empty() => {
+                            view! { <div class="text-gray-400 text-xs italic">"No execution found"</div> }
+                                .into_any()
+                        }
+                        Some(executions) => {
+                            let navigate = navigate.clone();
+                            view! {
+                                <table class="w-full text-xs">
+                                    <thead>
+                                        <tr class="text-left text-gray-500 dark:text-gray-400 border-b border-gray-100 dark:border-gray-800">
+                                            {SortColumn::ALL
+                                                .map(|column| {
+                                                    view! {
+                                                        <th
+                                                            class="py-1 font-normal cursor-pointer select-none hover:text-gray-700 dark:hover:text-gray-200"
+                                                            on:click=move |_| sort_by(column)
+                                                        >
+                                                            {column.label()}
+                                                            {move || {
+                                                                (sort_col.get() == column)
+                                                                    .then(|| match sort_dir.get() {
+                                                                        SortDirection::Ascending => " ▲",
+                                                                        SortDirection::Descending => " ▼",
+                                                                    })
+                                                            }}
+                                                        </th>
+                                                    }
+                                                })}
+                                        </tr>
+                                    </thead>
+                                    <tbody class="text-gray-800 dark:text-gray-100">
+                                        {executions
+                                            .into_iter()
+                                            .map(|stats| {
+                                                let navigate = navigate.clone();
+                                                let display_name = stats.execution_stats.display_name.clone();
+                                                view! {
+                                                    <tr
+                                                        class="border-b border-gray-50 dark:border-gray-800 cursor-pointer hover:bg-gray-50 dark:hover:bg-gray-800"
+                                                        on:click=move |_| {
+                                                            navigate(
+                                                                &format!(
+                                                                    "/?host={}&plan={}",
+                                                                    urlencoding::encode(&server_address.get_untracked()),
+                                                                    urlencoding::encode(&display_name),
+                                                                ),
+                                                                Default::default(),
+                                                            )
+                                                        }
+                                                    >
+                                                        <td class="py-1">{stats.execution_stats.display_name.clone()}</td>
+                                                        <td class="py-1 font-mono">
+                                                            {format!("{} ms", stats.execution_stats.execution_time_ms)}
+                                                        </td>
+                                                        <td class="py-1 font-mono">
+                                                            {format_bytes(stats.execution_stats.network_traffic_bytes)}
+                                                        </td>
+                                                        <td class="py-1 font-mono">{stats.plans.len()}</td>
+                                                        <td class="py-1 font-mono">
+                                                            {created_at(&stats).map_or("-".to_string(), format_timestamp)}
+                                                        </td>
+                                                    </tr>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </tbody>
+                                </table>
+                            }
+                                .into_any()
+                        }
+                        None => {
+                            view! { <div class="text-gray-400 text-xs italic">"Loading executions…"</div> }
+                                .into_any()
+                        }
+                    }}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -1,2 +1,4 @@
+pub mod history;
 pub mod home;
+pub mod metrics;
 pub mod not_found;
@@ -0,0 +1,96 @@
+use gloo_storage::{SessionStorage, Storage};
+use leptos::{logging, prelude::*};
+
+const AUTH_TOKEN_KEY: &str = "liquid_cache_admin.auth_token";
+const REQUEST_HEADERS_KEY: &str = "liquid_cache_admin.request_headers";
+
+/// Connection configuration shared by all requests
+#[derive(Clone, Copy)]
+pub struct AppConfig {
+    /// Bearer token sent with every request, kept in sessionStorage only
+    pub auth_token: RwSignal<Option<String>>,
+    /// Notified whenever the server rejects the auth token (401)
+    pub unauthorized: Trigger,
+}
+
+pub fn provide_app_config() -> AppConfig {
+    let config = AppConfig {
+        auth_token: RwSignal::new(SessionStorage::get(AUTH_TOKEN_KEY).ok()),
+        unauthorized: Trigger::new(),
+    };
+
+    Effect::new(move || match config.auth_token.get() {
+        Some(token) => {
+            if let Err(e) = SessionStorage::set(AUTH_TOKEN_KEY, token) {
+                logging::error!("Failed to write auth token to sessionStorage: {}", e);
+            }
+        }
+        None => SessionStorage::delete(AUTH_TOKEN_KEY),
+    });
+
+    provide_context(config);
+    config
+}
+
+pub fn use_app_config() -> AppConfig {
+    use_context::<AppConfig>().expect("AppConfig must be provided")
+}
+
+/// Current auth token, None outside of the app or when no token is set
+pub fn current_auth_token() -> Option<String> {
+    use_context::<AppConfig>().and_then(|config| config.auth_token.get_untracked())
+}
+
+/// Extra headers sent with every request, e.g. `X-Api-Key`
+#[derive(Clone, Copy)]
+pub struct RequestConfig {
+    /// (name, value) pairs, kept in sessionStorage only as they may hold secrets
+    pub headers: RwSignal<Vec<(String, String)>>,
+}
+
+pub fn provide_request_config() -> RequestConfig {
+    let config = RequestConfig {
+        headers: RwSignal::new(SessionStorage::get(REQUEST_HEADERS_KEY).unwrap_or_default()),
+    };
+
+    Effect::new(move || {
+        config.headers.with(|headers| {
+            if let Err(e) = SessionStorage::set(REQUEST_HEADERS_KEY, headers) {
+                logging::error!("Failed to write request headers to sessionStorage: {}", e);
+            }
+        })
+    });
+
+    provide_context(config);
+    config
+}
+
+pub fn use_request_config() -> RequestConfig {
+    use_context::<RequestConfig>().expect("RequestConfig must be provided")
+}
+
+/// Whether the browser accepts the header: the name is an HTTP token and the value holds
+/// no control characters
+pub fn is_valid_header(name: &str, value: &str) -> bool {
+    let name = name.trim();
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+        && value.chars().all(|c| c == '\t' || (' '..='~').contains(&c))
+}
+
+/// Custom headers that are valid, see `is_valid_header`, empty outside of the app
+pub fn current_request_headers() -> Vec<(String, String)> {
+    use_context::<RequestConfig>()
+        .map(|config| {
+            config.headers.with_untracked(|headers| {
+                headers
+                    .iter()
+                    .filter(|(name, value)| is_valid_header(name, value))
+                    .map(|(name, value)| (name.trim().to_string(), value.clone()))
+                    .collect()
+            })
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,96 @@
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// SQL keywords colored by `highlight_sql`, matched case-insensitively
+const KEYWORDS: [&str; 44] = [
+    "SELECT", "FROM", "WHERE", "GROUP", "BY", "HAVING", "ORDER", "LIMIT", "OFFSET", "AS", "AND",
+    "OR", "NOT", "IN", "IS", "NULL", "LIKE", "ILIKE", "BETWEEN", "JOIN", "INNER", "LEFT", "RIGHT",
+    "FULL", "OUTER", "CROSS", "ON", "USING", "UNION", "ALL", "DISTINCT", "CASE", "WHEN", "THEN",
+    "ELSE", "END", "WITH", "ASC", "DESC", "EXISTS", "CAST", "INTERVAL", "TRUE", "FALSE",
+];
+
+const KEYWORD_CLASS: &str = "kw text-blue-700 dark:text-blue-400 font-semibold";
+const STRING_CLASS: &str = "str text-green-700 dark:text-green-400";
+const NUMBER_CLASS: &str = "num text-amber-700 dark:text-amber-400";
+const COMMENT_CLASS: &str = "cm text-gray-400 italic";
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Consume characters while `predicate` holds, returns the end of the consumed text
+fn take_while(
+    chars: &mut Peekable<CharIndices>,
+    mut end: usize,
+    predicate: impl Fn(char) -> bool,
+) -> usize {
+    while let Some(&(pos, next)) = chars.peek() {
+        if !predicate(next) {
+            break;
+        }
+        end = pos + next.len_utf8();
+        chars.next();
+    }
+    end
+}
+
+fn span(class: &str, text: &str) -> String {
+    format!("<span class=\"{class}\">{}</span>", escape_html(text))
+}
+
+/// HTML markup of `sql` with keywords, string literals, numbers and comments wrapped in
+/// `<span class="kw ...">` style spans. All other text is HTML-escaped. Any input is accepted,
+/// an unterminated string literal runs to the end.
+pub fn highlight_sql(sql: &str) -> String {
+    let mut html = String::with_capacity(sql.len() * 2);
+    let mut chars = sql.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        let after = start + c.len_utf8();
+        if c == '-' && sql[start..].starts_with("--") {
+            let end = take_while(&mut chars, after, |next| next != '\n');
+            html.push_str(&span(COMMENT_CLASS, &sql[start..end]));
+        } else if c == '\'' {
+            // closing quote; a doubled '' is consumed as two strings, which renders the same
+            let mut end = sql.len();
+            for (pos, next) in chars.by_ref() {
+                if next == '\'' {
+                    end = pos + 1;
+                    break;
+                }
+            }
+            html.push_str(&span(STRING_CLASS, &sql[start..end]));
+        } else if c.is_ascii_digit() {
+            let end = take_while(&mut chars, after, |next| {
+                next.is_ascii_digit() || next == '.'
+            });
+            html.push_str(&span(NUMBER_CLASS, &sql[start..end]));
+        } else if c.is_alphabetic() || c == '_' {
+            let end = take_while(&mut chars, after, |next| {
+                next.is_alphanumeric() || next == '_'
+            });
+            let word = &sql[start..end];
+            if KEYWORDS
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(word))
+            {
+                html.push_str(&span(KEYWORD_CLASS, word));
+            } else {
+                html.push_str(&escape_html(word));
+            }
+        } else {
+            html.push_str(&escape_html(&sql[start..after]));
+        }
+    }
+    html
+}
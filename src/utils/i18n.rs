@@ -0,0 +1,243 @@
+use leptos::prelude::*;
+
+/// Supported UI languages
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    EnUs,
+    JaJp,
+    ZhCn,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 3] = [Locale::EnUs, Locale::JaJp, Locale::ZhCn];
+
+    /// Locale code, e.g. "en-US"
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "en-US",
+            Locale::JaJp => "ja-JP",
+            Locale::ZhCn => "zh-CN",
+        }
+    }
+
+    /// Name of the language in that language
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Locale::EnUs => "English",
+            Locale::JaJp => "日本語",
+            Locale::ZhCn => "中文",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Locale::EnUs => 0,
+            Locale::JaJp => 1,
+            Locale::ZhCn => 2,
+        }
+    }
+}
+
+/// Translation table keyed by the English text: [en-US, ja-JP, zh-CN]
+const TRANSLATIONS: &[(&str, [&str; 3])] = &[
+    // Headers
+    (
+        "LiquidCache Monitor",
+        [
+            "LiquidCache Monitor",
+            "LiquidCache モニター",
+            "LiquidCache 监控",
+        ],
+    ),
+    ("System", ["System", "システム", "系统"]),
+    ("Cache", ["Cache", "キャッシュ", "缓存"]),
+    (
+        "Execution Plans",
+        ["Execution Plans", "実行計画", "执行计划"],
+    ),
+    ("Execution Plan", ["Execution Plan", "実行計画", "执行计划"]),
+    ("Schema", ["Schema", "スキーマ", "模式"]),
+    ("Statistics", ["Statistics", "統計", "统计"]),
+    ("Settings", ["Settings", "設定", "设置"]),
+    ("Notifications", ["Notifications", "通知", "通知"]),
+    (
+        "Keyboard shortcuts",
+        [
+            "Keyboard shortcuts",
+            "キーボードショートカット",
+            "键盘快捷键",
+        ],
+    ),
+    ("Home", ["Home", "ホーム", "首页"]),
+    ("Metrics", ["Metrics", "メトリクス", "指标"]),
+    ("History", ["History", "履歴", "历史"]),
+    (
+        "Execution History",
+        ["Execution History", "実行履歴", "执行历史"],
+    ),
+    ("Event Log", ["Event Log", "イベントログ", "事件日志"]),
+    (
+        "SLA Compliance",
+        ["SLA Compliance", "SLA 準拠状況", "SLA 合规"],
+    ),
+    (
+        "Active queries",
+        ["Active queries", "実行中のクエリ", "活动查询"],
+    ),
+    (
+        "All servers",
+        ["All servers", "すべてのサーバー", "所有服务器"],
+    ),
+    (
+        "Cache Files",
+        ["Cache Files", "キャッシュファイル", "缓存文件"],
+    ),
+    (
+        "Eviction policy",
+        ["Eviction policy", "退避ポリシー", "淘汰策略"],
+    ),
+    (
+        "Quota by prefix",
+        ["Quota by prefix", "プレフィックス別クォータ", "按前缀配额"],
+    ),
+    ("Add server", ["Add server", "サーバーを追加", "添加服务器"]),
+    ("More panels", ["More panels", "その他のパネル", "更多面板"]),
+    (
+        "Something went wrong",
+        ["Something went wrong", "問題が発生しました", "出错了"],
+    ),
+    // Buttons
+    ("Connect", ["Connect", "接続", "连接"]),
+    ("Refresh", ["Refresh", "更新", "刷新"]),
+    ("Presets", ["Presets", "プリセット", "预设"]),
+    ("Clear", ["Clear", "クリア", "清除"]),
+    ("Compact", ["Compact", "コンパクト", "紧凑"]),
+    ("Expand", ["Expand", "展開", "展开"]),
+    (
+        "Reset Cache",
+        ["Reset Cache", "キャッシュをリセット", "重置缓存"],
+    ),
+    (
+        "Shutdown Server",
+        ["Shutdown Server", "サーバーを停止", "关闭服务器"],
+    ),
+    (
+        "Download SVG",
+        ["Download SVG", "SVG をダウンロード", "下载 SVG"],
+    ),
+    (
+        "Export column stats",
+        ["Export column stats", "列統計をエクスポート", "导出列统计"],
+    ),
+    ("Add", ["Add", "追加", "添加"]),
+    ("Add header", ["Add header", "ヘッダーを追加", "添加请求头"]),
+    ("Add rule", ["Add rule", "ルールを追加", "添加规则"]),
+    ("Save", ["Save", "保存", "保存"]),
+    ("Save token", ["Save token", "トークンを保存", "保存令牌"]),
+    ("Cancel", ["Cancel", "キャンセル", "取消"]),
+    ("Close", ["Close", "閉じる", "关闭"]),
+    (
+        "Clear history",
+        ["Clear history", "履歴をクリア", "清除历史"],
+    ),
+    (
+        "Clear plan cache",
+        [
+            "Clear plan cache",
+            "プランキャッシュをクリア",
+            "清除计划缓存",
+        ],
+    ),
+    (
+        "Download PNG",
+        ["Download PNG", "PNG をダウンロード", "下载 PNG"],
+    ),
+    (
+        "Export DOT",
+        ["Export DOT", "DOT をエクスポート", "导出 DOT"],
+    ),
+    (
+        "Export Mermaid",
+        ["Export Mermaid", "Mermaid をエクスポート", "导出 Mermaid"],
+    ),
+    (
+        "Export InfluxDB",
+        [
+            "Export InfluxDB",
+            "InfluxDB をエクスポート",
+            "导出 InfluxDB",
+        ],
+    ),
+    (
+        "Export JSON",
+        ["Export JSON", "JSON をエクスポート", "导出 JSON"],
+    ),
+    // Status messages
+    (
+        "Connect to view system information",
+        [
+            "Connect to view system information",
+            "接続してシステム情報を表示",
+            "连接以查看系统信息",
+        ],
+    ),
+    (
+        "Connect to view cache configuration",
+        [
+            "Connect to view cache configuration",
+            "接続してキャッシュ設定を表示",
+            "连接以查看缓存配置",
+        ],
+    ),
+    (
+        "Connect to view cache usage",
+        [
+            "Connect to view cache usage",
+            "接続してキャッシュ使用量を表示",
+            "连接以查看缓存使用情况",
+        ],
+    ),
+    (
+        "No execution found",
+        [
+            "No execution found",
+            "実行が見つかりません",
+            "未找到执行记录",
+        ],
+    ),
+    (
+        "No plan selected",
+        ["No plan selected", "計画が選択されていません", "未选择计划"],
+    ),
+    (
+        "Server address",
+        ["Server address", "サーバーアドレス", "服务器地址"],
+    ),
+];
+
+/// Look up `key` in the translation table, falling back to the key itself
+pub fn translate(locale: Locale, key: &'static str) -> &'static str {
+    TRANSLATIONS
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, translations)| translations[locale.index()])
+        .unwrap_or(key)
+}
+
+/// Translate `key` into the current locale.
+/// Tracks the locale signal, so call it inside a reactive closure.
+pub fn t(key: &'static str) -> &'static str {
+    let locale = use_context::<RwSignal<Locale>>()
+        .map(|locale| locale.get())
+        .unwrap_or_default();
+    translate(locale, key)
+}
+
+pub fn use_locale() -> RwSignal<Locale> {
+    use_context::<RwSignal<Locale>>().expect("Locale must be provided")
+}
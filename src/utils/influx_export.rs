@@ -0,0 +1,67 @@
+use crate::models::execution_plan::ExecutionStatsWithPlan;
+use crate::utils::parse_metric_value;
+
+const MEASUREMENT: &str = "liquid_cache_operator";
+
+/// Metrics of every plan node as InfluxDB line protocol, one line per node, e.g.
+///
+/// ```text
+/// liquid_cache_operator,server=http://localhost:53703,query=q1,plan_id=abc,node=0.1,operator=FilterExec output_rows=10i,elapsed_compute=3400000.0 1700000000000000000
+/// ```
+///
+/// Integer metrics get the `i` suffix, durations become nanoseconds and are written as floats,
+/// which line protocol recognizes by the decimal point. Non-numeric metrics are left out.
+pub fn to_influx_line_protocol(plan: &ExecutionStatsWithPlan, server_address: &str) -> String {
+    let query = escape_tag(&plan.execution_stats.display_name);
+    let server = escape_tag(server_address);
+    let mut lines = String::new();
+    for plan_info in &plan.plans {
+        let timestamp_ns = plan_info.created_at.saturating_mul(1_000_000_000);
+        for (path, node) in plan_info.plan.nodes_with_paths() {
+            let fields = node
+                .metrics
+                .iter()
+                .filter_map(|metric| {
+                    Some(format!(
+                        "{}={}",
+                        escape_tag(&metric.name),
+                        field_value(&metric.value)?
+                    ))
+                })
+                .collect::<Vec<_>>();
+            // a line needs at least one field
+            if fields.is_empty() {
+                continue;
+            }
+            lines.push_str(&format!(
+                "{MEASUREMENT},server={server},query={query},plan_id={},node={path},operator={} {} {timestamp_ns}\n",
+                escape_tag(&plan_info.id),
+                escape_tag(&node.name),
+                fields.join(","),
+            ));
+        }
+    }
+    lines
+}
+
+/// Typed field value, `None` for values that aren't numbers
+fn field_value(value: &str) -> Option<String> {
+    if let Ok(integer) = value.trim().parse::<i64>() {
+        return Some(format!("{integer}i"));
+    }
+    parse_metric_value(value)
+        .filter(|value| value.is_finite())
+        .map(|value| format!("{value:?}"))
+}
+
+/// Commas, equal signs and spaces are escaped in tag keys, tag values and field keys
+fn escape_tag(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, ',' | '=' | ' ') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
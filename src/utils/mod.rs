@@ -0,0 +1,619 @@
+pub mod config;
+pub mod highlight;
+pub mod i18n;
+pub mod influx_export;
+pub mod plan_export;
+pub mod plan_text;
+pub mod plan_traversal;
+pub mod shortcuts;
+pub mod sql_format;
+pub mod stats;
+pub mod storage;
+pub mod theme;
+
+use leptos::logging;
+use leptos::wasm_bindgen::{JsCast, JsValue};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::models::execution_plan::{ColumnStatistics, ExecutionStatsWithPlan, SchemaField};
+use crate::models::server_info::{ParquetCacheUsage, SystemInfo};
+
+// Helper function to format bytes to human-readable format
+pub fn format_bytes(bytes: u64) -> String {
+    format_bytes_with_precision(bytes, 2)
+}
+
+/// Like `format_bytes` with `decimal_places` fractional digits for KB and above
+pub fn format_bytes_with_precision(bytes: u64, decimal_places: u8) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.*} {}", decimal_places as usize, UNITS[unit])
+}
+
+// Helper function to format unix timestamp to local time
+pub fn format_timestamp(timestamp: u64) -> String {
+    let js_date = js_sys::Date::new(&(timestamp as f64 * 1000.0).into());
+    let hours = js_date.get_hours();
+    let minutes = js_date.get_minutes();
+    let seconds = js_date.get_seconds();
+    format!("{hours:02}:{minutes:02}:{seconds:02}")
+}
+
+/// Age of a unix timestamp, e.g. "just now", "2 minutes ago" or "1 hour ago"
+pub fn format_relative_time(timestamp_secs: u64) -> String {
+    let now_secs = (js_sys::Date::now() / 1000.0) as u64;
+    let elapsed = now_secs.saturating_sub(timestamp_secs);
+    let (count, unit) = match elapsed {
+        0..60 => return "just now".to_string(),
+        60..3_600 => (elapsed / 60, "minute"),
+        3_600..86_400 => (elapsed / 3_600, "hour"),
+        _ => (elapsed / 86_400, "day"),
+    };
+    let plural = if count == 1 { "" } else { "s" };
+    format!("{count} {unit}{plural} ago")
+}
+
+pub fn format_duration(duration_str: &str) -> String {
+    if duration_str.ends_with("ms") {
+        duration_str.to_string()
+    } else if duration_str.ends_with("ns") {
+        if let Ok(ns) = duration_str.trim_end_matches("ns").parse::<f64>() {
+            if ns >= 1_000_000_000.0 {
+                format!("{:.2}s", ns / 1_000_000_000.0)
+            } else if ns >= 1_000_000.0 {
+                format!("{:.2}ms", ns / 1_000_000.0)
+            } else if ns >= 1_000.0 {
+                format!("{:.2}μs", ns / 1_000.0)
+            } else {
+                format!("{}ns", ns as u64)
+            }
+        } else {
+            duration_str.to_string()
+        }
+    } else {
+        duration_str.to_string()
+    }
+}
+
+pub fn format_number(num_str: &str) -> String {
+    if let Ok(num) = num_str.parse::<u64>() {
+        if num >= 1_000_000_000 {
+            format!("{:.2}B", num as f64 / 1_000_000_000.0)
+        } else if num >= 1_000_000 {
+            format!("{:.2}M", num as f64 / 1_000_000.0)
+        } else if num >= 1_000 {
+            format!("{:.2}K", num as f64 / 1_000.0)
+        } else {
+            num.to_string()
+        }
+    } else {
+        num_str.to_string()
+    }
+}
+
+// Format a raw metric value based on the unit implied by the metric name
+pub fn format_metric_value(name: &str, value: &str) -> String {
+    if name.contains("time") || name.contains("elapsed") {
+        format_duration(value)
+    } else if name.contains("bytes") {
+        format_bytes(value.parse::<u64>().unwrap_or(0))
+    } else if name.contains("rows") {
+        format_number(value)
+    } else {
+        value.to_string()
+    }
+}
+
+// Divide a raw metric value by `divisor`, keeping its unit suffix
+pub fn scale_metric_value(value: &str, divisor: u32) -> String {
+    if divisor <= 1 {
+        return value.to_string();
+    }
+    let (number, suffix) = if let Some(number) = value.strip_suffix("ns") {
+        (number, "ns")
+    } else if let Some(number) = value.strip_suffix("ms") {
+        (number, "ms")
+    } else {
+        (value, "")
+    };
+    match number.trim().parse::<f64>() {
+        Ok(number) => format!("{}{suffix}", (number / divisor as f64).round() as u64),
+        Err(_) => value.to_string(),
+    }
+}
+
+/// Number inside a statistics value such as "Exact(Int64(42))", "Inexact(42)" or "42"
+pub fn parse_stat_number(value: &str) -> Option<f64> {
+    let start = value.rfind('(').map_or(0, |index| index + 1);
+    let end = value[start..]
+        .find(')')
+        .map_or(value.len(), |index| start + index);
+    value[start..end]
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|number| number.is_finite())
+}
+
+// Whether a string column has few enough distinct values to benefit from dictionary encoding
+pub fn suggest_dictionary_encoding(col: &ColumnStatistics, field: &SchemaField) -> bool {
+    const MAX_DISTINCT: f64 = 10.0;
+    let is_string = matches!(field.data_type.as_str(), "Utf8" | "LargeUtf8" | "Utf8View");
+    is_string
+        && col.name == field.name
+        && col
+            .distinct_count
+            .as_deref()
+            .and_then(parse_stat_number)
+            .is_some_and(|count| count < MAX_DISTINCT)
+}
+
+// Match `text` against a glob-style `pattern` where `*` matches any sequence
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let text = text.as_bytes();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+    while t < text.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if p < pattern.len() && (pattern[p] == b'?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == b'*')
+}
+
+// Parse a raw metric value into a number, durations are normalized to nanoseconds
+pub fn parse_metric_value(value: &str) -> Option<f64> {
+    if let Some(ns) = value.strip_suffix("ns") {
+        ns.trim().parse::<f64>().ok()
+    } else if let Some(ms) = value.strip_suffix("ms") {
+        ms.trim().parse::<f64>().ok().map(|ms| ms * 1_000_000.0)
+    } else {
+        value.trim().parse::<f64>().ok()
+    }
+}
+
+/// Plans whose display names share `prefix`
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlanGroup {
+    pub prefix: String,
+    pub plans: Vec<String>,
+}
+
+/// Name of the group of plans without a shared prefix
+pub const OTHER_PLAN_GROUP: &str = "Other";
+
+fn is_prefix_separator(c: char) -> bool {
+    matches!(c, '_' | '-' | '.' | ':' | '/')
+}
+
+/// Group names by their longest common prefix ending at a separator, e.g. "benchmark_".
+/// Names sharing no prefix with another name end up in the trailing "Other" group.
+pub fn group_by_prefix(names: &[String]) -> Vec<PlanGroup> {
+    // candidate groups keyed by the first segment, in order of first appearance
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    let mut other = Vec::new();
+    for name in names {
+        match name.find(is_prefix_separator) {
+            Some(end) => {
+                let key = &name[..=end];
+                match groups.iter_mut().find(|(k, _)| k == key) {
+                    Some((_, plans)) => plans.push(name.clone()),
+                    None => groups.push((key.to_string(), vec![name.clone()])),
+                }
+            }
+            None => other.push(name.clone()),
+        }
+    }
+
+    let mut result = Vec::new();
+    for (key, plans) in groups {
+        if plans.len() < 2 {
+            other.extend(plans);
+            continue;
+        }
+        let common = plans
+            .iter()
+            .skip(1)
+            .fold(plans[0].as_str(), |prefix, name| {
+                let len = prefix
+                    .char_indices()
+                    .zip(name.chars())
+                    .take_while(|((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((i, a), _)| i + a.len_utf8());
+                &prefix[..len]
+            });
+        // extend the first segment to the last separator of the common prefix
+        let prefix = common
+            .rfind(is_prefix_separator)
+            .map_or(key, |end| common[..=end].to_string());
+        result.push(PlanGroup { prefix, plans });
+    }
+    if !other.is_empty() {
+        result.push(PlanGroup {
+            prefix: OTHER_PLAN_GROUP.to_string(),
+            plans: other,
+        });
+    }
+    result
+}
+
+// Number of standard deviations `current` is away from the mean of `history`
+pub fn compute_zscore(history: &[f64], current: f64) -> f64 {
+    if history.is_empty() {
+        return 0.0;
+    }
+    let n = history.len() as f64;
+    let mean = history.iter().sum::<f64>() / n;
+    let variance = history.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    let std_dev = variance.sqrt();
+    if std_dev == 0.0 {
+        if current == mean {
+            0.0
+        } else {
+            (current - mean).signum() * f64::INFINITY
+        }
+    } else {
+        (current - mean) / std_dev
+    }
+}
+
+/// Timeout and retry policy of a request
+#[derive(Clone, Copy, Debug)]
+pub struct FetchOptions {
+    /// Per-attempt timeout, covering both the response headers and the body
+    pub timeout_ms: u64,
+    /// Retries after the first attempt, only GET and HEAD requests are retried
+    pub max_retries: u8,
+    /// Delay before the first retry, doubled for each further retry
+    pub initial_backoff_ms: u64,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            timeout_ms: 5_000,
+            max_retries: 3,
+            initial_backoff_ms: 200,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// FETCH_TIMEOUT: no attempt got a response within `timeout_ms`
+    Timeout { retries: u8 },
+    /// The request failed on every attempt
+    Network { error: gloo_net::Error, retries: u8 },
+    /// The server rejected the auth token, never retried
+    Unauthorized,
+    /// The response did not match the expected type, never retried
+    Deserialize(gloo_net::Error),
+}
+
+impl std::fmt::Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::Timeout { retries } => {
+                write!(f, "FETCH_TIMEOUT: request timed out ({retries} retries)")
+            }
+            FetchError::Network { error, retries } => write!(f, "{error} ({retries} retries)"),
+            FetchError::Unauthorized => write!(f, "unauthorized (401)"),
+            FetchError::Deserialize(error) => write!(f, "invalid response: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+pub fn fetch_api<T>(
+    path: &str,
+) -> impl std::future::Future<Output = Result<T, FetchError>> + Send + '_
+where
+    T: DeserializeOwned,
+{
+    fetch_api_with_method(gloo_net::http::Method::GET, path)
+}
+
+pub fn fetch_api_with_method<T>(
+    method: gloo_net::http::Method,
+    path: &str,
+) -> impl std::future::Future<Output = Result<T, FetchError>> + Send + '_
+where
+    T: DeserializeOwned,
+{
+    fetch_api_with_options(method, path, FetchOptions::default())
+}
+
+pub fn fetch_api_with_options<T>(
+    method: gloo_net::http::Method,
+    path: &str,
+    options: FetchOptions,
+) -> impl std::future::Future<Output = Result<T, FetchError>> + Send + '_
+where
+    T: DeserializeOwned,
+{
+    use futures::future::{select, Either};
+    use gloo_timers::future::TimeoutFuture;
+    use leptos::prelude::{on_cleanup, Notify};
+    use send_wrapper::SendWrapper;
+    use std::{cell::RefCell, rc::Rc};
+
+    let auth_token = config::current_auth_token();
+    let headers = config::current_request_headers();
+    let app_config = leptos::prelude::use_context::<config::AppConfig>();
+
+    SendWrapper::new(async move {
+        // controller of the in-flight attempt, None once cancelled
+        let abort_controller = Rc::new(RefCell::new(web_sys::AbortController::new().ok()));
+
+        // abort in-flight requests if, e.g., we've navigated away from this page
+        let cleanup_controller = SendWrapper::new(abort_controller.clone());
+        on_cleanup(move || {
+            if let Some(abort_controller) = cleanup_controller.take().borrow_mut().take() {
+                abort_controller.abort()
+            }
+        });
+
+        let mut backoff_ms = options.initial_backoff_ms;
+        let mut retries = 0;
+        loop {
+            logging::log!("Fetching data from {} {}", method, path);
+
+            let Some(abort_signal) = abort_controller.borrow().as_ref().map(|a| a.signal()) else {
+                break Err(FetchError::Network {
+                    error: gloo_net::Error::GlooError("request was cancelled".to_string()),
+                    retries,
+                });
+            };
+            let mut request = gloo_net::http::RequestBuilder::new(path)
+                .method(method.clone())
+                .abort_signal(Some(&abort_signal));
+            if let Some(token) = &auth_token {
+                request = request.header("Authorization", &format!("Bearer {token}"));
+            }
+            for (name, value) in &headers {
+                request = request.header(name, value);
+            }
+            let request = request.send();
+            let timeout = TimeoutFuture::new(options.timeout_ms as u32);
+            let timed_out = || {
+                if let Some(abort_controller) = abort_controller.borrow().as_ref() {
+                    abort_controller.abort();
+                }
+                FetchError::Timeout { retries }
+            };
+
+            let error = match select(Box::pin(request), timeout).await {
+                Either::Left((Ok(response), _)) if response.status() == 401 => {
+                    if let Some(app_config) = &app_config {
+                        app_config.unauthorized.notify();
+                    }
+                    break Err(FetchError::Unauthorized);
+                }
+                Either::Left((Ok(response), timeout)) => {
+                    match select(Box::pin(response.json()), timeout).await {
+                        Either::Left((result, _)) => break result.map_err(FetchError::Deserialize),
+                        Either::Right(_) => timed_out(),
+                    }
+                }
+                Either::Left((Err(error), _)) => FetchError::Network { error, retries },
+                Either::Right(_) => timed_out(),
+            };
+            // a mutating request may have reached the server, never send it twice
+            let idempotent = matches!(
+                method,
+                gloo_net::http::Method::GET | gloo_net::http::Method::HEAD
+            );
+            if retries == options.max_retries || !idempotent {
+                break Err(error);
+            }
+
+            TimeoutFuture::new(backoff_ms as u32).await;
+            backoff_ms *= 2;
+            retries += 1;
+            // each attempt needs a fresh controller, unless navigation cancelled the request
+            let mut current = abort_controller.borrow_mut();
+            if current.is_none() {
+                break Err(error);
+            }
+            *current = web_sys::AbortController::new().ok();
+        }
+    })
+}
+
+/// GET `{address}/health` and return the round-trip time in milliseconds
+pub fn ping_server(
+    address: &str,
+) -> impl std::future::Future<Output = Result<u64, gloo_net::Error>> + Send + '_ {
+    let auth_token = config::current_auth_token();
+    let headers = config::current_request_headers();
+    send_wrapper::SendWrapper::new(async move {
+        let started_at = js_sys::Date::now();
+        let mut request = gloo_net::http::Request::get(&format!("{address}/health"));
+        if let Some(token) = auth_token {
+            request = request.header("Authorization", &format!("Bearer {token}"));
+        }
+        for (name, value) in &headers {
+            request = request.header(name, value);
+        }
+        let response = request.send().await?;
+        if !response.ok() {
+            return Err(gloo_net::Error::GlooError(format!(
+                "health check returned {}",
+                response.status()
+            )));
+        }
+        Ok((js_sys::Date::now() - started_at).max(0.0) as u64)
+    })
+}
+
+/// Payload pushed by the server over `/ws`, tagged with the endpoint it mirrors
+#[derive(Deserialize, Clone)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum WsMessage {
+    ParquetCacheUsage(ParquetCacheUsage),
+    SystemInfo(SystemInfo),
+    ExecutionPlans(Vec<ExecutionStatsWithPlan>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WsConnectionState {
+    Connected,
+    Reconnecting,
+    Disconnected,
+}
+
+const WS_INITIAL_RETRY_MS: u32 = 1_000;
+const WS_MAX_RETRY_MS: u32 = 30_000;
+
+/// Convert an http(s) server address into its websocket endpoint, e.g. "ws://host:port/ws"
+pub fn ws_url(server_address: &str) -> String {
+    let address = server_address.trim_end_matches('/');
+    if let Some(rest) = address.strip_prefix("https://") {
+        format!("wss://{rest}/ws")
+    } else if let Some(rest) = address.strip_prefix("http://") {
+        format!("ws://{rest}/ws")
+    } else {
+        format!("ws://{address}/ws")
+    }
+}
+
+/// Open a websocket to `url` and expose the latest decoded message. The state turns Connected
+/// with the first message, as opening only starts the handshake.
+/// Reconnects with exponential backoff until the current reactive owner is cleaned up.
+pub fn ws_connect(
+    url: &str,
+    set_state: leptos::prelude::WriteSignal<WsConnectionState>,
+) -> leptos::prelude::ReadSignal<Option<WsMessage>> {
+    use futures::future::{select, Either};
+    use futures::StreamExt;
+    use gloo_net::websocket::{futures::WebSocket, Message};
+    use gloo_timers::future::TimeoutFuture;
+    use leptos::prelude::*;
+
+    let (message, set_message) = signal(None::<WsMessage>);
+    let (cancel_tx, mut cancel_rx) = futures::channel::oneshot::channel::<()>();
+    on_cleanup(move || {
+        let _ = cancel_tx.send(());
+    });
+
+    let decode = move |text: &str| match serde_json::from_str::<WsMessage>(text) {
+        Ok(decoded) => {
+            set_message.try_set(Some(decoded));
+        }
+        Err(e) => logging::warn!("Ignoring malformed ws message: {}", e),
+    };
+
+    let url = url.to_string();
+    leptos::task::spawn_local(async move {
+        let mut retry_delay_ms = WS_INITIAL_RETRY_MS;
+        loop {
+            match WebSocket::open(&url) {
+                Ok(mut ws) => {
+                    let mut received = false;
+                    loop {
+                        match select(ws.next(), &mut cancel_rx).await {
+                            // dropping the socket closes it
+                            Either::Right(_) => {
+                                set_state.set(WsConnectionState::Disconnected);
+                                return;
+                            }
+                            Either::Left((Some(Ok(message)), _)) if !received => {
+                                received = true;
+                                retry_delay_ms = WS_INITIAL_RETRY_MS;
+                                set_state.set(WsConnectionState::Connected);
+                                if let Message::Text(text) = message {
+                                    decode(&text);
+                                }
+                            }
+                            Either::Left((Some(Ok(Message::Text(text))), _)) => decode(&text),
+                            Either::Left((Some(Ok(Message::Bytes(_))), _)) => {}
+                            Either::Left((Some(Err(e)), _)) => {
+                                logging::error!("Websocket error on {}: {}", url, e);
+                                break;
+                            }
+                            Either::Left((None, _)) => break,
+                        }
+                    }
+                }
+                Err(e) => logging::error!("Failed to open websocket {}: {}", url, e),
+            }
+
+            set_state.set(WsConnectionState::Reconnecting);
+            match select(TimeoutFuture::new(retry_delay_ms), &mut cancel_rx).await {
+                Either::Left(_) => retry_delay_ms = (retry_delay_ms * 2).min(WS_MAX_RETRY_MS),
+                Either::Right(_) => {
+                    set_state.set(WsConnectionState::Disconnected);
+                    return;
+                }
+            }
+        }
+    });
+
+    message
+}
+
+// Trigger a browser download of `content` through a temporary object URL
+pub fn download_text_file(file_name: &str, content: &str, mime_type: &str) {
+    let parts = js_sys::Array::of1(&JsValue::from_str(content));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else {
+        logging::error!("Failed to create blob for {}", file_name);
+        return;
+    };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else {
+        logging::error!("Failed to create object URL for {}", file_name);
+        return;
+    };
+
+    if let Some(document) = web_sys::window().and_then(|window| window.document()) {
+        if let Ok(element) = document.create_element("a") {
+            let anchor = element.unchecked_into::<web_sys::HtmlAnchorElement>();
+            anchor.set_href(&url);
+            anchor.set_download(file_name);
+
+            if let Some(body) = document.body() {
+                let _ = body.append_child(&anchor);
+                anchor.click();
+                let _ = body.remove_child(&anchor);
+            }
+        }
+    }
+    let _ = web_sys::Url::revoke_object_url(&url);
+}
+
+// Write `text` to the system clipboard, resolving once it is copied
+pub async fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    let window = web_sys::window().ok_or("no window")?;
+    let promise = window.navigator().clipboard().write_text(text);
+    wasm_bindgen_futures::JsFuture::from(promise)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("{e:?}"))
+}
+
+#[derive(Deserialize, Clone)]
+pub struct ApiResponse {
+    pub message: String,
+}
@@ -0,0 +1,121 @@
+use crate::models::execution_plan::ExecutionPlanWithStats;
+
+/// Metrics shown in each node label
+const LABEL_METRICS: usize = 3;
+
+/// Render the plan as a Graphviz DOT digraph with parent → child edges, e.g.
+///
+/// ```text
+/// digraph plan {
+///   ProjectionExec_0 [label="ProjectionExec\noutput_rows=10"];
+///   FilterExec_1 [label="FilterExec\noutput_rows=10"];
+///   ProjectionExec_0 -> FilterExec_1;
+/// }
+/// ```
+pub fn render_plan_dot(root: &ExecutionPlanWithStats) -> String {
+    let mut dot = String::from("digraph plan {\n  node [shape=box, fontname=\"monospace\"];\n");
+    let mut next_index = 0;
+    write_dot_node(&mut dot, root, &mut next_index);
+    dot.push_str("}\n");
+    dot
+}
+
+/// Writes the vertex of `node` and its subtree, returns the vertex ID
+fn write_dot_node(
+    dot: &mut String,
+    node: &ExecutionPlanWithStats,
+    next_index: &mut usize,
+) -> String {
+    // operators can repeat in a plan, the pre-order index keeps the IDs unique
+    let id = format!("{}_{}", sanitize_dot_id(&node.name), next_index);
+    *next_index += 1;
+
+    let label = label_lines(node, "=")
+        .map(|line| escape_dot_string(&line))
+        .collect::<Vec<_>>()
+        .join("\\n");
+    dot.push_str(&format!("  {id} [label=\"{label}\"];\n"));
+
+    for child in &node.children {
+        let child_id = write_dot_node(dot, child, next_index);
+        dot.push_str(&format!("  {id} -> {child_id};\n"));
+    }
+    id
+}
+
+/// Render the plan as a Mermaid flowchart with parent → child edges, e.g.
+///
+/// ```text
+/// graph TD
+///   N0["ProjectionExec<br/>output_rows: 10"]
+///   N1["FilterExec<br/>output_rows: 10"]
+///   N0 --> N1
+/// ```
+pub fn render_plan_mermaid(root: &ExecutionPlanWithStats) -> String {
+    let mut mermaid = String::from("graph TD\n");
+    let mut next_index = 0;
+    write_mermaid_node(&mut mermaid, root, &mut next_index);
+    mermaid
+}
+
+/// Writes `node` and its subtree, returns the node ID
+fn write_mermaid_node(
+    mermaid: &mut String,
+    node: &ExecutionPlanWithStats,
+    next_index: &mut usize,
+) -> String {
+    let id = format!("N{next_index}");
+    *next_index += 1;
+
+    let label = label_lines(node, ": ")
+        .map(|line| escape_mermaid_string(&line))
+        .collect::<Vec<_>>()
+        .join("<br/>");
+    mermaid.push_str(&format!("  {id}[\"{label}\"]\n"));
+
+    for child in &node.children {
+        let child_id = write_mermaid_node(mermaid, child, next_index);
+        mermaid.push_str(&format!("  {id} --> {child_id}\n"));
+    }
+    id
+}
+
+/// The operator name followed by its first metrics as `name{separator}value`
+fn label_lines<'a>(
+    node: &'a ExecutionPlanWithStats,
+    separator: &'a str,
+) -> impl Iterator<Item = String> + 'a {
+    std::iter::once(node.name.clone()).chain(
+        node.metrics
+            .iter()
+            .take(LABEL_METRICS)
+            .map(move |metric| format!("{}{separator}{}", metric.name, metric.value)),
+    )
+}
+
+/// Letters, digits and underscores only, not starting with a digit
+fn sanitize_dot_id(name: &str) -> String {
+    let id = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if id.is_empty() || id.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("n_{id}")
+    } else {
+        id
+    }
+}
+
+fn escape_dot_string(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Mermaid entity codes for characters that would end the quoted label or start markup,
+/// line breaks become `<br/>`
+fn escape_mermaid_string(text: &str) -> String {
+    text.replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace("\r\n", "<br/>")
+        .replace('\n', "<br/>")
+}
@@ -0,0 +1,31 @@
+use crate::models::execution_plan::ExecutionPlanWithStats;
+
+/// Render the plan as indented text like DataFusion's `EXPLAIN ANALYZE`, e.g.
+///
+/// ```text
+/// ProjectionExec, metrics=[output_rows=10, elapsed_compute=1.2ms]
+///   FilterExec, metrics=[output_rows=10, elapsed_compute=3.4ms]
+/// ```
+pub fn render_plan_text(node: &ExecutionPlanWithStats, indent: usize) -> String {
+    let mut text = String::new();
+    write_node(&mut text, node, indent);
+    text
+}
+
+fn write_node(text: &mut String, node: &ExecutionPlanWithStats, indent: usize) {
+    text.push_str(&"  ".repeat(indent));
+    text.push_str(&node.name);
+    if !node.metrics.is_empty() {
+        let metrics = node
+            .metrics
+            .iter()
+            .map(|metric| format!("{}={}", metric.name, metric.value))
+            .collect::<Vec<_>>()
+            .join(", ");
+        text.push_str(&format!(", metrics=[{metrics}]"));
+    }
+    text.push('\n');
+    for child in &node.children {
+        write_node(text, child, indent + 1);
+    }
+}
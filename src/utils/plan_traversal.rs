@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::models::execution_plan::ExecutionPlanWithStats;
+
+/// Operators that work together as one logical step, e.g. the two sides of a hash join
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodeCluster {
+    /// Name of the step, e.g. "Hash join"
+    pub name: String,
+    /// Paths of the member nodes (e.g. "0.1.0"), the topmost first
+    pub node_paths: Vec<String>,
+}
+
+/// (parent prefix, child prefix, cluster name) of directly connected operator pairs
+const CLUSTER_RULES: [(&str, &str, &str); 4] = [
+    ("HashProbeExec", "HashBuildExec", "Hash join"),
+    ("SortPreservingMergeExec", "SortExec", "Sort"),
+    ("AggregateExec", "AggregateExec", "Two-phase aggregate"),
+    ("CoalescePartitionsExec", "RepartitionExec", "Repartition"),
+];
+
+/// Operators that may sit between the two phases of a cluster, e.g. the shuffle of an aggregate
+fn is_exchange(name: &str) -> bool {
+    name.starts_with("RepartitionExec") || name.starts_with("CoalesceBatchesExec")
+}
+
+/// Find pairs of tightly related operators. A node belongs to at most one cluster,
+/// clusters are matched from the root down.
+pub fn identify_clusters(root: &ExecutionPlanWithStats) -> Vec<NodeCluster> {
+    let mut clusters = Vec::new();
+    visit(root, "0".to_string(), &mut clusters);
+    clusters
+}
+
+fn is_clustered(clusters: &[NodeCluster], path: &str) -> bool {
+    clusters
+        .iter()
+        .any(|cluster| cluster.node_paths.iter().any(|member| member == path))
+}
+
+fn visit(node: &ExecutionPlanWithStats, path: String, clusters: &mut Vec<NodeCluster>) {
+    if !is_clustered(clusters, &path) {
+        if let Some(cluster) = match_rules(node, &path) {
+            clusters.push(cluster);
+        }
+    }
+    for (index, child) in node.children.iter().enumerate() {
+        visit(child, format!("{path}.{index}"), clusters);
+    }
+}
+
+fn match_rules(node: &ExecutionPlanWithStats, path: &str) -> Option<NodeCluster> {
+    CLUSTER_RULES
+        .iter()
+        .filter(|(parent, _, _)| node.name.starts_with(parent))
+        .find_map(|(_, child_prefix, name)| {
+            node.children.iter().enumerate().find_map(|(index, child)| {
+                let child_path = format!("{path}.{index}");
+                if child.name.starts_with(child_prefix) {
+                    return Some(NodeCluster {
+                        name: name.to_string(),
+                        node_paths: vec![path.to_string(), child_path],
+                    });
+                }
+                // look through one exchange operator, it belongs to the cluster too
+                if !is_exchange(&child.name) {
+                    return None;
+                }
+                child
+                    .children
+                    .iter()
+                    .position(|grandchild| grandchild.name.starts_with(child_prefix))
+                    .map(|grandchild_index| NodeCluster {
+                        name: name.to_string(),
+                        node_paths: vec![
+                            path.to_string(),
+                            child_path.clone(),
+                            format!("{child_path}.{grandchild_index}"),
+                        ],
+                    })
+            })
+        })
+}
+
+/// How often each `SchemaField.data_type` occurs across the schemas of all nodes
+pub fn collect_all_types(root: &ExecutionPlanWithStats) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for (_, node) in root.nodes_with_paths() {
+        for field in &node.schema {
+            *counts.entry(field.data_type.clone()).or_default() += 1;
+        }
+    }
+    counts
+}
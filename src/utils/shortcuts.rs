@@ -0,0 +1,122 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::JsCast;
+use send_wrapper::SendWrapper;
+use std::collections::HashMap;
+
+/// Shortcut keys and what they do, shown in the `?` help overlay
+pub const SHORTCUTS: [(&str, &str); 6] = [
+    ("r", "Refresh all data"),
+    ("e", "Focus the execution plan selector"),
+    ("c", "Focus the server address"),
+    ("/", "Focus search"),
+    ("Escape", "Close open dialogs"),
+    ("?", "Show keyboard shortcuts"),
+];
+
+/// Element ids targeted by the focus shortcuts, scoped with [`ShortcutScope`]
+pub const PLAN_SELECTOR_ID: &str = "execution-plan-selector";
+pub const SERVER_ADDRESS_ID: &str = "server-address";
+
+/// Server tab the elements below belong to, keeps their ids unique across tabs
+#[derive(Clone, Copy)]
+pub struct ShortcutScope(pub usize);
+
+impl ShortcutScope {
+    /// `id` unique to the scope, e.g. "server-address-1"
+    pub fn id(&self, id: &str) -> String {
+        format!("{id}-{}", self.0)
+    }
+}
+
+/// `id` scoped to the enclosing [`ShortcutScope`], unchanged outside of one
+pub fn scoped_id(id: &str) -> String {
+    use_context::<ShortcutScope>().map_or_else(|| id.to_string(), |scope| scope.id(id))
+}
+
+/// Focus the first visible element matching `selector`, inactive server tabs hold the same elements
+fn focus_first_visible(selector: &str) {
+    let Ok(elements) = document().query_selector_all(selector) else {
+        return;
+    };
+    if let Some(element) = (0..elements.length())
+        .filter_map(|index| elements.item(index))
+        .filter_map(|node| node.dyn_into::<web_sys::HtmlElement>().ok())
+        .find(|element| element.offset_parent().is_some())
+    {
+        let _ = element.focus();
+    }
+}
+
+/// Focus the element with `id`, if it is on the page
+pub fn focus_element_by_id(id: &str) {
+    if let Some(element) = document()
+        .get_element_by_id(id)
+        .and_then(|element| element.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        let _ = element.focus();
+    }
+}
+
+/// Focus the first search input on the page
+pub fn focus_search() {
+    focus_first_visible("input[type=search]");
+}
+
+/// Whether the user is typing into a form field, shortcuts other than Escape are ignored then
+fn is_editing(ev: &web_sys::KeyboardEvent) -> bool {
+    ev.target()
+        .and_then(|target| target.dyn_into::<web_sys::Element>().ok())
+        .is_some_and(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT")
+                || element.has_attribute("contenteditable")
+        })
+}
+
+/// Register a single window `keydown` listener dispatching keys (`KeyboardEvent.key`) to actions.
+/// Keys pressed with Ctrl, Alt or Meta are left to the browser.
+pub fn use_keyboard_shortcuts(shortcuts: HashMap<&'static str, Box<dyn Fn()>>) {
+    let shortcuts = SendWrapper::new(shortcuts);
+    let handle = window_event_listener(leptos::ev::keydown, move |ev| {
+        if ev.ctrl_key() || ev.alt_key() || ev.meta_key() {
+            return;
+        }
+        let key = ev.key();
+        if key != "Escape" && is_editing(&ev) {
+            return;
+        }
+        if let Some(action) = shortcuts.get(key.as_str()) {
+            ev.prevent_default();
+            action();
+        }
+    });
+    on_cleanup(move || handle.remove());
+}
+
+/// Notified by the Escape shortcut, open dialogs subscribe with [`on_close_modals`]
+#[derive(Clone, Copy)]
+pub struct CloseModals(Trigger);
+
+impl CloseModals {
+    pub fn notify(&self) {
+        self.0.notify();
+    }
+}
+
+pub fn provide_close_modals() -> CloseModals {
+    let close_modals = CloseModals(Trigger::new());
+    provide_context(close_modals);
+    close_modals
+}
+
+/// Run `close` whenever the Escape shortcut is pressed
+pub fn on_close_modals(close: impl Fn() + Send + Sync + 'static) {
+    if let Some(CloseModals(trigger)) = use_context::<CloseModals>() {
+        Effect::new(move |previous: Option<()>| {
+            trigger.track();
+            // skip the initial run, nothing has been pressed yet
+            if previous.is_some() {
+                close();
+            }
+        });
+    }
+}
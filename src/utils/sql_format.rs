@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+/// Top-level clauses of a SELECT statement, in the order they appear
+pub const CLAUSES: [&str; 7] = [
+    "SELECT", "FROM", "WHERE", "GROUP BY", "HAVING", "ORDER BY", "LIMIT",
+];
+
+/// Length of `clause` if the keyword(s) start at `pos`, allowing any whitespace in "GROUP BY"
+fn match_clause(sql: &str, pos: usize, clause: &str) -> Option<usize> {
+    let mut offset = pos;
+    for (i, word) in clause.split(' ').enumerate() {
+        if i > 0 {
+            let whitespace = sql[offset..].len() - sql[offset..].trim_start().len();
+            if whitespace == 0 {
+                return None;
+            }
+            offset += whitespace;
+        }
+        let candidate = sql.get(offset..offset + word.len())?;
+        if !candidate.eq_ignore_ascii_case(word) {
+            return None;
+        }
+        offset += word.len();
+    }
+    let ends_at_boundary = sql[offset..]
+        .chars()
+        .next()
+        .is_none_or(|c| !c.is_alphanumeric() && c != '_');
+    ends_at_boundary.then_some(offset - pos)
+}
+
+/// Split `sql` into its top-level clauses, keyed by the names in `CLAUSES`.
+/// Keywords inside parentheses (subqueries) or string literals are ignored.
+/// Empty when the statement doesn't start with a clause, e.g. `WITH` or `EXPLAIN`,
+/// as the preamble would be lost.
+pub fn extract_clauses(sql: &str) -> HashMap<&'static str, &str> {
+    // (clause, start of keyword, start of body)
+    let mut boundaries: Vec<(&'static str, usize, usize)> = Vec::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut previous = ' ';
+    for (pos, c) in sql.char_indices() {
+        match c {
+            '\'' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => depth = depth.saturating_sub(1),
+            _ if !in_string && depth == 0 && !(previous.is_alphanumeric() || previous == '_') => {
+                if let Some((clause, len)) = CLAUSES
+                    .iter()
+                    .find_map(|clause| Some((*clause, match_clause(sql, pos, clause)?)))
+                {
+                    // only the first occurrence of each clause, e.g. not the SELECT of a UNION
+                    if !boundaries.iter().any(|(c, _, _)| *c == clause) {
+                        boundaries.push((clause, pos, pos + len));
+                    }
+                }
+            }
+            _ => {}
+        }
+        previous = c;
+    }
+
+    let statement_start = sql.len() - sql.trim_start().len();
+    if boundaries
+        .first()
+        .is_none_or(|(_, keyword_start, _)| *keyword_start != statement_start)
+    {
+        return HashMap::new();
+    }
+
+    boundaries
+        .iter()
+        .enumerate()
+        .map(|(i, (clause, _, body_start))| {
+            let body_end = boundaries
+                .get(i + 1)
+                .map_or(sql.len(), |(_, next_start, _)| *next_start);
+            (*clause, sql[*body_start..body_end].trim())
+        })
+        .collect()
+}
@@ -0,0 +1,175 @@
+//! Statistical helpers for comparing metric samples
+
+fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    // sample variance with Bessel's correction
+    let variance = samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+// Lanczos approximation of ln(Γ(x))
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.120_865_097_386_617_9e-2,
+        -0.539_523_938_495_3e-5,
+    ];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let mut series = 1.000_000_000_190_015;
+    for (i, c) in COEFFICIENTS.iter().enumerate() {
+        series += c / (x + 1.0 + i as f64);
+    }
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+// Continued fraction for the incomplete beta function
+fn beta_continued_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 200;
+    const EPSILON: f64 = 3.0e-14;
+    const TINY: f64 = 1.0e-300;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+// Regularized incomplete beta function I_x(a, b)
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_continued_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_continued_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// Two-sided p-value of Welch's t-test, `None` if either sample has fewer than two values
+pub fn welch_p_value(a: &[f64], b: &[f64]) -> Option<f64> {
+    if a.len() < 2 || b.len() < 2 {
+        return None;
+    }
+    let (mean_a, var_a) = mean_and_variance(a);
+    let (mean_b, var_b) = mean_and_variance(b);
+    let se_a = var_a / a.len() as f64;
+    let se_b = var_b / b.len() as f64;
+    let se = se_a + se_b;
+    if se == 0.0 {
+        // both samples are constant
+        return Some(if mean_a == mean_b { 1.0 } else { 0.0 });
+    }
+    let t = (mean_a - mean_b) / se.sqrt();
+    let df = se.powi(2)
+        / (se_a.powi(2) / (a.len() as f64 - 1.0) + se_b.powi(2) / (b.len() as f64 - 1.0));
+    Some(incomplete_beta(df / 2.0, 0.5, df / (df + t * t)))
+}
+
+/// Whether the difference between samples `a` and `b` is significant at level `alpha`,
+/// `None` if either sample is too small to tell
+pub fn is_significant_change(a: &[f64], b: &[f64], alpha: f64) -> Option<bool> {
+    welch_p_value(a, b).map(|p| p < alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn incomplete_beta_matches_closed_forms() {
+        // I_x(1, 1) = x
+        assert_close(incomplete_beta(1.0, 1.0, 0.3), 0.3);
+        // symmetric around one half
+        assert_close(incomplete_beta(2.5, 2.5, 0.5), 0.5);
+        // binomial tail: sum of C(4, j) 0.3^j 0.7^(4-j) for j >= 2
+        assert_close(incomplete_beta(2.0, 3.0, 0.3), 0.3483);
+        assert_close(incomplete_beta(2.0, 3.0, 0.0), 0.0);
+        assert_close(incomplete_beta(2.0, 3.0, 1.0), 1.0);
+    }
+
+    #[test]
+    fn two_sided_t_p_values() {
+        let p = |t: f64, df: f64| incomplete_beta(df / 2.0, 0.5, df / (df + t * t));
+        // Cauchy: P(|T| > 1) = 1/2
+        assert_close(p(1.0, 1.0), 0.5);
+        // df = 2: 1 - t / sqrt(2 + t^2)
+        assert_close(p(2.0, 2.0), 1.0 - 2.0 / 6.0_f64.sqrt());
+        assert_close(p(0.0, 8.0), 1.0);
+    }
+
+    #[test]
+    fn welch_test_on_known_samples() {
+        // t = -5 with 8 degrees of freedom
+        let p = welch_p_value(&[1.0, 2.0, 3.0, 4.0, 5.0], &[6.0, 7.0, 8.0, 9.0, 10.0]).unwrap();
+        assert_close(p, 0.001_052_8);
+        assert_eq!(
+            is_significant_change(
+                &[1.0, 2.0, 3.0, 4.0, 5.0],
+                &[6.0, 7.0, 8.0, 9.0, 10.0],
+                0.05
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            is_significant_change(&[1.0, 2.0], &[1.5, 2.5], 0.05),
+            Some(false)
+        );
+        assert_eq!(welch_p_value(&[1.0], &[1.0, 2.0]), None);
+        assert_eq!(welch_p_value(&[3.0, 3.0], &[3.0, 3.0]), Some(1.0));
+    }
+}
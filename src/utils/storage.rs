@@ -0,0 +1,21 @@
+//! JSON values in localStorage, keys are prefixed with `liquid_cache_admin.` by convention
+
+use gloo_storage::{LocalStorage, Storage};
+use leptos::logging;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Read a JSON value, None if it is missing or doesn't parse
+pub fn get<T: DeserializeOwned>(key: &str) -> Option<T> {
+    LocalStorage::get(key).ok()
+}
+
+/// Write a JSON value, failures (e.g. quota exceeded) are logged
+pub fn set<T: Serialize>(key: &str, value: &T) {
+    if let Err(e) = LocalStorage::set(key, value) {
+        logging::error!("Failed to write {} to localStorage: {}", key, e);
+    }
+}
+
+pub fn remove(key: &str) {
+    LocalStorage::delete(key);
+}
@@ -0,0 +1,92 @@
+use leptos::prelude::*;
+use leptos::wasm_bindgen::{closure::Closure, JsCast};
+use send_wrapper::SendWrapper;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::storage;
+
+const THEME_KEY: &str = "liquid_cache_admin.theme";
+const PREFERS_DARK_QUERY: &str = "(prefers-color-scheme: dark)";
+
+/// Color scheme of the dashboard, `System` follows the OS preference
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThemeMode {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+impl ThemeMode {
+    /// Mode selected by the header toggle after this one
+    pub fn next(&self) -> Self {
+        match self {
+            ThemeMode::Light => ThemeMode::Dark,
+            ThemeMode::Dark => ThemeMode::System,
+            ThemeMode::System => ThemeMode::Light,
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "☀",
+            ThemeMode::Dark => "☾",
+            ThemeMode::System => "◐",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "Light",
+            ThemeMode::Dark => "Dark",
+            ThemeMode::System => "System",
+        }
+    }
+
+    /// Value of the `data-theme` attribute, "light" or "dark"
+    pub fn data_theme(&self, prefers_dark: bool) -> &'static str {
+        match self {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System if prefers_dark => "dark",
+            ThemeMode::System => "light",
+        }
+    }
+}
+
+/// Provide the theme mode and return the resulting `data-theme` value, which follows
+/// changes of the OS preference while the mode is `System`
+pub fn provide_theme() -> Signal<&'static str> {
+    let theme = RwSignal::new(storage::get::<ThemeMode>(THEME_KEY).unwrap_or_default());
+    let prefers_dark = RwSignal::new(false);
+
+    Effect::new(move || storage::set(THEME_KEY, &theme.get()));
+
+    if let Some(query) = window().match_media(PREFERS_DARK_QUERY).ok().flatten() {
+        prefers_dark.set(query.matches());
+        let on_change = Closure::<dyn Fn()>::new({
+            let query = query.clone();
+            move || prefers_dark.set(query.matches())
+        });
+        if query
+            .add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref())
+            .is_ok()
+        {
+            let listener = SendWrapper::new((query, on_change));
+            on_cleanup(move || {
+                let (query, on_change) = listener.take();
+                let _ = query.remove_event_listener_with_callback(
+                    "change",
+                    on_change.as_ref().unchecked_ref(),
+                );
+            });
+        }
+    }
+
+    provide_context(theme);
+    Signal::derive(move || theme.get().data_theme(prefers_dark.get()))
+}
+
+pub fn use_theme() -> RwSignal<ThemeMode> {
+    use_context::<RwSignal<ThemeMode>>().expect("ThemeMode must be provided")
+}
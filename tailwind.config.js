@@ -1,5 +1,7 @@
 /** @type {import('tailwindcss').Config} */
 module.exports = {
+	// dark: variants follow the data-theme attribute set on <html>
+	darkMode: ['selector', '[data-theme="dark"]'],
 	content: {
 		files: ["*.html", "./src/**/*.rs"],
 		transform: {